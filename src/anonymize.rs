@@ -0,0 +1,280 @@
+use crate::config::CanvasConfig;
+use hmac::{Hmac, Mac};
+use serde_json::{Map, Value};
+use sha2::Sha256;
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Fields scrubbed when `ANONYMIZATION_FIELDS` is not set
+pub const DEFAULT_FIELDS: &[&str] = &[
+    "name",
+    "display_name",
+    "sortable_name",
+    "short_name",
+    "full_name",
+    "email",
+    "login_id",
+    "sis_user_id",
+    "avatar_url",
+    "avatar_image_url",
+    "user_name",
+    "author_name",
+];
+
+/// Fields that mark an object as a Canvas user (as opposed to a course or
+/// assignment, which also have `id` and `name`)
+const USER_MARKERS: &[&str] = &[
+    "display_name",
+    "sortable_name",
+    "short_name",
+    "full_name",
+    "email",
+    "login_id",
+    "sis_user_id",
+];
+
+/// Avatar URLs, which often embed the student's photo or name; these are
+/// nulled rather than replaced with a pseudonym
+const AVATAR_FIELDS: &[&str] = &["avatar_url", "avatar_image_url"];
+
+/// Denormalized `<x>_id` / `<x>_name` pairs found on comments and discussion
+/// entries (discussion topics pair `user_name` with `author.id` instead)
+const NAME_PAIRS: &[(&str, &str)] = &[("user_id", "user_name"), ("author_id", "author_name")];
+
+/// Deterministic pseudonym for a Canvas user ID, e.g. `Student-3f9a1c2e`
+///
+/// Uses a keyed hash (HMAC-SHA256) of the ID so the same student maps to the
+/// same pseudonym across calls, while the mapping can't be reversed without
+/// the salt.
+pub fn pseudonym(user_id: &str, salt: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(salt.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(user_id.as_bytes());
+    let digest = mac.finalize().into_bytes();
+    format!("Student-{}", hex::encode(&digest[..4]))
+}
+
+/// Replace identifying fields on a single user object with its pseudonym,
+/// using the default field list
+pub fn anonymize_user(value: &mut Value, salt: &str) {
+    Anonymizer::new(salt, default_fields()).anonymize_user(value);
+}
+
+/// Anonymize every user object in a response, using the default field list
+pub fn anonymize_users(value: &mut Value, salt: &str) {
+    Anonymizer::new(salt, default_fields()).anonymize_users(value);
+}
+
+/// The default field list as owned strings
+pub fn default_fields() -> Vec<String> {
+    DEFAULT_FIELDS.iter().map(|f| f.to_string()).collect()
+}
+
+/// Replaces student identifiers in Canvas responses with pseudonyms
+///
+/// The Canvas `id` is always kept so the user can still be referenced in
+/// follow-up calls (e.g. grading); only the configured fields are scrubbed.
+#[derive(Debug)]
+pub struct Anonymizer {
+    salt: String,
+    fields: Vec<String>,
+    /// Pseudonym -> real user ID, only kept when explicitly enabled since it
+    /// lets anyone with access to the session undo the anonymization
+    reverse: Option<Mutex<HashMap<String, String>>>,
+}
+
+impl Anonymizer {
+    /// Create an anonymizer that scrubs `fields`, without a reverse map
+    pub fn new(salt: impl Into<String>, fields: Vec<String>) -> Self {
+        Self {
+            salt: salt.into(),
+            fields,
+            reverse: None,
+        }
+    }
+
+    /// Build from the anonymization settings in the configuration
+    pub fn from_config(config: &CanvasConfig) -> Self {
+        let anonymizer = Self::new(
+            config.anonymization_salt.clone(),
+            config.anonymization_fields.clone(),
+        );
+        if config.anonymization_reversible {
+            anonymizer.with_reverse_map()
+        } else {
+            anonymizer
+        }
+    }
+
+    /// Remember every pseudonym handed out so it can be looked up with `reverse`
+    pub fn with_reverse_map(mut self) -> Self {
+        self.reverse = Some(Mutex::new(HashMap::new()));
+        self
+    }
+
+    /// Pseudonym for a user ID, recording it in the reverse map if enabled
+    pub fn pseudonym(&self, user_id: &str) -> String {
+        let alias = pseudonym(user_id, &self.salt);
+        if let Some(reverse) = &self.reverse {
+            reverse
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .insert(alias.clone(), user_id.to_string());
+        }
+        alias
+    }
+
+    /// Real user ID behind a pseudonym seen earlier in this session
+    ///
+    /// Always `None` unless the reverse map was enabled.
+    pub fn reverse(&self, pseudonym: &str) -> Option<String> {
+        self.reverse
+            .as_ref()?
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(pseudonym.trim())
+            .cloned()
+    }
+
+    /// Whether the reverse map is enabled
+    pub fn is_reversible(&self) -> bool {
+        self.reverse.is_some()
+    }
+
+    /// Replace identifying fields on a single user object
+    pub fn anonymize_user(&self, value: &mut Value) {
+        if let Some(user) = value.as_object_mut() {
+            self.scrub_user(user);
+        }
+    }
+
+    /// Walk a Canvas response and anonymize every user object found in it,
+    /// including nested ones such as `user` on enrollments or `author` on comments
+    pub fn anonymize_users(&self, value: &mut Value) {
+        match value {
+            Value::Array(items) => {
+                for item in items {
+                    self.anonymize_users(item);
+                }
+            }
+            Value::Object(object) => {
+                if is_user(object) {
+                    self.scrub_user(object);
+                }
+                for (id_field, name_field) in NAME_PAIRS {
+                    if !self.scrubs(name_field) || !object.contains_key(*name_field) {
+                        continue;
+                    }
+                    // Discussion topics carry `user_name` with an `author`
+                    // object instead of a `user_id`
+                    let id = object.get(*id_field).and_then(id_string).or_else(|| {
+                        object
+                            .get("author")
+                            .and_then(|author| author.get("id"))
+                            .and_then(id_string)
+                    });
+                    // A name with no ID to derive a pseudonym from is dropped
+                    let alias = id.map_or(Value::Null, |id| Value::String(self.pseudonym(&id)));
+                    object.insert(name_field.to_string(), alias);
+                }
+                for child in object.values_mut() {
+                    self.anonymize_users(child);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn scrubs(&self, field: &str) -> bool {
+        self.fields.iter().any(|f| f == field)
+    }
+
+    fn scrub_user(&self, user: &mut Map<String, Value>) {
+        let Some(id) = user.get("id").and_then(id_string) else {
+            return;
+        };
+        let alias = self.pseudonym(&id);
+        for field in &self.fields {
+            if let Some(value) = user.get_mut(field) {
+                *value = if AVATAR_FIELDS.contains(&field.as_str()) {
+                    Value::Null
+                } else {
+                    Value::String(alias.clone())
+                };
+            }
+        }
+    }
+}
+
+fn is_user(object: &Map<String, Value>) -> bool {
+    object.contains_key("id") && USER_MARKERS.iter().any(|field| object.contains_key(*field))
+}
+
+/// Render a JSON ID (number or string) without quotes
+fn id_string(value: &Value) -> Option<String> {
+    match value {
+        Value::Number(n) => Some(n.to_string()),
+        Value::String(s) => Some(s.clone()),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_pseudonym_is_deterministic_and_salted() {
+        let a = pseudonym("42", "salt");
+        assert_eq!(a, pseudonym("42", "salt"));
+        assert!(a.starts_with("Student-"));
+        assert_eq!(a.len(), "Student-".len() + 8);
+        assert_ne!(a, pseudonym("43", "salt"));
+        assert_ne!(a, pseudonym("42", "pepper"));
+    }
+
+    #[test]
+    fn test_anonymize_users_scrubs_nested_users_only() {
+        let mut value = json!([{
+            "id": 1,
+            "course_id": 10,
+            "user": {"id": 42, "name": "Jane Doe", "sortable_name": "Doe, Jane",
+                     "login_id": "jdoe", "avatar_url": "https://example.com/jane.png"},
+            "submission_comments": [{"author_id": 42, "author_name": "Jane Doe"}]
+        }, {
+            "id": 101, "name": "Biology", "course_code": "BIOL101"
+        }]);
+        anonymize_users(&mut value, "salt");
+
+        let alias = pseudonym("42", "salt");
+        assert_eq!(value[0]["user"]["id"], 42);
+        assert_eq!(value[0]["user"]["name"], alias.as_str());
+        assert_eq!(value[0]["user"]["login_id"], alias.as_str());
+        assert!(value[0]["user"]["avatar_url"].is_null());
+        assert_eq!(
+            value[0]["submission_comments"][0]["author_name"],
+            alias.as_str()
+        );
+        assert_eq!(value[1]["name"], "Biology");
+    }
+
+    #[test]
+    fn test_configured_fields_and_reverse_map() {
+        let mut user = json!({"id": 42, "name": "Jane Doe", "email": "jane@example.edu"});
+
+        let anonymizer = Anonymizer::new("salt", vec!["email".to_string()]);
+        anonymizer.anonymize_users(&mut user);
+        assert_eq!(user["name"], "Jane Doe");
+        assert_eq!(user["email"], pseudonym("42", "salt").as_str());
+        assert_eq!(anonymizer.reverse(&pseudonym("42", "salt")), None);
+
+        let reversible = Anonymizer::new("salt", default_fields()).with_reverse_map();
+        reversible.anonymize_user(&mut user);
+        assert_eq!(
+            reversible.reverse(&pseudonym("42", "salt")).as_deref(),
+            Some("42")
+        );
+        assert_eq!(reversible.reverse("Student-00000000"), None);
+    }
+}
@@ -0,0 +1,199 @@
+use futures::future::BoxFuture;
+use reqwest::{Method, Request, Response, ResponseBuilderExt, StatusCode};
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+/// Transport that sends built requests on behalf of `CanvasClient`
+///
+/// Retries, rate limiting, token refresh, anonymization, and caching all
+/// happen in the client, so a backend only has to turn one request into one
+/// response. `reqwest::Client` is the real implementation; [`MockBackend`]
+/// serves canned responses for tests.
+pub trait HttpBackend: Send + Sync {
+    fn execute(&self, request: Request) -> BoxFuture<'_, reqwest::Result<Response>>;
+}
+
+impl HttpBackend for reqwest::Client {
+    fn execute(&self, request: Request) -> BoxFuture<'_, reqwest::Result<Response>> {
+        Box::pin(reqwest::Client::execute(self, request))
+    }
+}
+
+/// A canned response served by [`MockBackend`]
+#[derive(Debug, Clone)]
+pub struct MockResponse {
+    pub status: u16,
+    pub headers: Vec<(String, String)>,
+    pub body: String,
+}
+
+impl MockResponse {
+    /// A 200 response with a JSON body
+    pub fn json(body: impl Into<String>) -> Self {
+        Self::status(200, body)
+    }
+
+    /// A response with the given status and JSON body
+    pub fn status(status: u16, body: impl Into<String>) -> Self {
+        Self {
+            status,
+            headers: vec![("content-type".to_string(), "application/json".to_string())],
+            body: body.into(),
+        }
+    }
+
+    pub fn with_header(mut self, name: &str, value: &str) -> Self {
+        self.headers.push((name.to_string(), value.to_string()));
+        self
+    }
+}
+
+/// A request received by [`MockBackend`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct RecordedRequest {
+    pub method: Method,
+    /// Path below the API base with its query string, e.g. `/courses/1?per_page=100`
+    pub path: String,
+    /// Body as text, if the request had one (forms are still URL-encoded)
+    pub body: Option<String>,
+}
+
+/// In-memory backend returning canned responses keyed by method and path
+///
+/// Paths are relative to `/api/v1`. A route registered with a query string
+/// only matches that exact query; one registered without a query matches
+/// any. Unmatched requests get a Canvas-style 404. Every request is recorded
+/// so tests can assert on what was sent.
+#[derive(Debug, Default)]
+pub struct MockBackend {
+    routes: Mutex<HashMap<(Method, String), MockResponse>>,
+    requests: Mutex<Vec<RecordedRequest>>,
+}
+
+impl MockBackend {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Serve `response` for `method` requests to `path`
+    pub fn on(self, method: Method, path: &str, response: MockResponse) -> Self {
+        self.routes
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert((method, api_path(path)), response);
+        self
+    }
+
+    /// Every request received so far, in order
+    pub fn requests(&self) -> Vec<RecordedRequest> {
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .clone()
+    }
+
+    fn respond(&self, request: &Request) -> MockResponse {
+        let url = request.url();
+        let path = api_path(url.path());
+        let with_query = match url.query() {
+            Some(query) => format!("{}?{}", path, query),
+            None => path.clone(),
+        };
+
+        self.requests
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .push(RecordedRequest {
+                method: request.method().clone(),
+                path: with_query.clone(),
+                body: request
+                    .body()
+                    .and_then(|body| body.as_bytes())
+                    .map(|bytes| String::from_utf8_lossy(bytes).into_owned()),
+            });
+
+        let routes = self.routes.lock().unwrap_or_else(|e| e.into_inner());
+        [with_query, path]
+            .into_iter()
+            .find_map(|key| routes.get(&(request.method().clone(), key)).cloned())
+            .unwrap_or_else(|| {
+                MockResponse::status(
+                    404,
+                    r#"{"errors": [{"message": "The specified resource does not exist."}]}"#,
+                )
+            })
+    }
+}
+
+impl HttpBackend for MockBackend {
+    fn execute(&self, request: Request) -> BoxFuture<'_, reqwest::Result<Response>> {
+        let canned = self.respond(&request);
+        let url = request.url().clone();
+        Box::pin(async move {
+            let mut builder = http::Response::builder()
+                .status(StatusCode::from_u16(canned.status).unwrap_or(StatusCode::OK))
+                .url(url);
+            for (name, value) in &canned.headers {
+                builder = builder.header(name.as_str(), value.as_str());
+            }
+            let response = builder
+                .body(canned.body)
+                .expect("mock response parts are valid");
+            Ok(Response::from(response))
+        })
+    }
+}
+
+/// Strip the `/api/v1` prefix so routes can be written as Canvas documents them
+fn api_path(path: &str) -> String {
+    normalize(path.strip_prefix("/api/v1").unwrap_or(path))
+}
+
+fn normalize(path: &str) -> String {
+    format!("/{}", path.trim_start_matches('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::client::CanvasClient;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_mock_backend_serves_routes_and_records_requests() {
+        let backend = Arc::new(
+            MockBackend::new()
+                .on(
+                    Method::GET,
+                    "/courses/1",
+                    MockResponse::json(r#"{"id": 1, "name": "Biology"}"#),
+                )
+                .on(
+                    Method::PUT,
+                    "/courses/1",
+                    MockResponse::json(r#"{"id": 1}"#),
+                ),
+        );
+        let config = Arc::new(CanvasConfig::new(
+            "token".to_string(),
+            "https://canvas.test".to_string(),
+        ));
+        let client = CanvasClient::with_backend(config, backend.clone()).unwrap();
+
+        let course = client.get_course(&1.into()).await.unwrap();
+        assert_eq!(course.name, "Biology");
+        let _: serde_json::Value = client
+            .put_form("/courses/1", &[("course[name]", "Bio")])
+            .await
+            .unwrap();
+        let missing = client.get_course(&2.into()).await.unwrap_err();
+        assert!(matches!(missing, crate::error::CanvasError::NotFound(_)));
+
+        let requests = backend.requests();
+        assert_eq!(requests.len(), 3);
+        assert_eq!(requests[0].path, "/courses/1");
+        assert_eq!(requests[1].method, Method::PUT);
+        assert_eq!(requests[1].body.as_deref(), Some("course%5Bname%5D=Bio"));
+    }
+}
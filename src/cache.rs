@@ -0,0 +1,185 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// A cached GET response: the raw body, the next-page link, and the
+/// `ETag` Canvas sent, if any
+#[derive(Debug, Clone, PartialEq)]
+pub struct CachedPage {
+    pub body: String,
+    pub next: Option<String>,
+    pub etag: Option<String>,
+}
+
+#[derive(Debug)]
+struct Entry {
+    page: CachedPage,
+    stored_at: Instant,
+    last_used: Instant,
+}
+
+/// In-memory LRU cache of Canvas GET responses, keyed on the full request URL
+///
+/// Bodies are stored before anonymization, which is applied again on every
+/// read. Entries expire after `ttl`; expired entries with an `ETag` are kept
+/// so they can be revalidated with `If-None-Match`. Writes invalidate any entry whose
+/// path is a prefix of, or prefixed by, the written path.
+#[derive(Debug)]
+pub struct ResponseCache {
+    ttl: Duration,
+    capacity: usize,
+    entries: Mutex<HashMap<String, Entry>>,
+}
+
+impl ResponseCache {
+    pub fn new(ttl: Duration, capacity: usize) -> Self {
+        Self {
+            ttl,
+            capacity: capacity.max(1),
+            entries: Mutex::new(HashMap::new()),
+        }
+    }
+
+    /// Look up a fresh response for `url`
+    pub fn get(&self, url: &str) -> Option<CachedPage> {
+        let mut entries = self.lock();
+        let entry = entries.get_mut(url)?;
+        if entry.stored_at.elapsed() >= self.ttl {
+            if entry.page.etag.is_none() {
+                entries.remove(url);
+            }
+            return None;
+        }
+        entry.last_used = Instant::now();
+        Some(entry.page.clone())
+    }
+
+    /// Look up a response for `url` that can be revalidated, fresh or not
+    pub fn get_revalidatable(&self, url: &str) -> Option<CachedPage> {
+        self.lock()
+            .get(url)
+            .filter(|entry| entry.page.etag.is_some())
+            .map(|entry| entry.page.clone())
+    }
+
+    /// Store a response, evicting the least recently used entry when full
+    pub fn insert(&self, url: String, page: CachedPage) {
+        let mut entries = self.lock();
+        if !entries.contains_key(&url) && entries.len() >= self.capacity {
+            let ttl = self.ttl;
+            entries.retain(|_, entry| entry.stored_at.elapsed() < ttl);
+            if entries.len() >= self.capacity {
+                if let Some(oldest) = entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.last_used)
+                    .map(|(key, _)| key.clone())
+                {
+                    entries.remove(&oldest);
+                }
+            }
+        }
+        let now = Instant::now();
+        entries.insert(
+            url,
+            Entry {
+                page,
+                stored_at: now,
+                last_used: now,
+            },
+        );
+    }
+
+    /// Drop every entry for the resource at `url` and the collections
+    /// above or below it, e.g. a PUT to `/courses/1/assignments/7` drops
+    /// `/courses/1/assignments?...` and `/courses/1/assignments/7/submissions`
+    pub fn invalidate(&self, url: &str) {
+        let written = resource_path(url);
+        self.lock()
+            .retain(|key, _| !is_related(resource_path(key), written));
+    }
+
+    /// Number of entries currently held, including expired ones not yet evicted
+    pub fn len(&self) -> usize {
+        self.lock().len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, HashMap<String, Entry>> {
+        self.entries.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// A URL without its query string or fragment
+fn resource_path(url: &str) -> &str {
+    let end = url.find(['?', '#']).unwrap_or(url.len());
+    url[..end].trim_end_matches('/')
+}
+
+/// Whether one path is the other or an ancestor of it, on segment boundaries
+fn is_related(a: &str, b: &str) -> bool {
+    let (shorter, longer) = if a.len() <= b.len() { (a, b) } else { (b, a) };
+    longer
+        .strip_prefix(shorter)
+        .is_some_and(|rest| rest.is_empty() || rest.starts_with('/'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn page(body: &str) -> CachedPage {
+        CachedPage {
+            body: body.to_string(),
+            next: None,
+            etag: None,
+        }
+    }
+
+    #[test]
+    fn test_expiry_and_lru_eviction() {
+        let cache = ResponseCache::new(Duration::from_secs(60), 2);
+        cache.insert("https://c/api/v1/courses".to_string(), page("a"));
+        cache.insert("https://c/api/v1/users/self".to_string(), page("b"));
+        assert_eq!(cache.get("https://c/api/v1/courses"), Some(page("a")));
+
+        // users/self is now least recently used
+        cache.insert("https://c/api/v1/courses/1".to_string(), page("c"));
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("https://c/api/v1/users/self").is_none());
+
+        let expired = ResponseCache::new(Duration::ZERO, 2);
+        expired.insert("https://c/api/v1/courses".to_string(), page("a"));
+        assert!(expired.get("https://c/api/v1/courses").is_none());
+    }
+
+    #[test]
+    fn test_invalidate_related_paths() {
+        let cache = ResponseCache::new(Duration::from_secs(60), 10);
+        for url in [
+            "https://c/api/v1/courses/1/assignments?per_page=100",
+            "https://c/api/v1/courses/1/assignments/7",
+            "https://c/api/v1/courses/1/assignments/70",
+            "https://c/api/v1/courses/2/assignments?per_page=100",
+        ] {
+            cache.insert(url.to_string(), page("x"));
+        }
+
+        cache.invalidate("https://c/api/v1/courses/1/assignments/7?as_user_id=5");
+
+        assert!(cache
+            .get("https://c/api/v1/courses/1/assignments?per_page=100")
+            .is_none());
+        assert!(cache
+            .get("https://c/api/v1/courses/1/assignments/7")
+            .is_none());
+        assert!(cache
+            .get("https://c/api/v1/courses/1/assignments/70")
+            .is_some());
+        assert!(cache
+            .get("https://c/api/v1/courses/2/assignments?per_page=100")
+            .is_some());
+    }
+}
@@ -1,5 +1,139 @@
+use crate::anonymize;
 use crate::error::{CanvasError, Result};
+use crate::rate_limit::RateLimiterConfig;
+use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::time::Duration;
+use tracing::Level;
+
+/// Default upper bound on pages followed by `CanvasClient::get_all`
+pub const DEFAULT_MAX_PAGES: usize = 1000;
+
+/// Default number of retries for transient failures
+pub const DEFAULT_MAX_RETRIES: u32 = 3;
+
+/// Default base delay for exponential backoff, in milliseconds
+pub const DEFAULT_BASE_BACKOFF_MS: u64 = 500;
+
+/// Default cap on Canvas requests in flight at once
+pub const DEFAULT_MAX_CONCURRENT_REQUESTS: usize = 8;
+
+/// Default number of GET responses held by the response cache
+pub const DEFAULT_CACHE_MAX_ENTRIES: usize = 500;
+
+/// Default number of idle connections kept open to the Canvas host
+pub const DEFAULT_POOL_MAX_IDLE_PER_HOST: usize = 10;
+
+/// Default time an idle pooled connection is kept, in seconds
+pub const DEFAULT_POOL_IDLE_TIMEOUT_SECS: u64 = 90;
+
+/// Default overall timeout for an API request, in seconds
+pub const DEFAULT_REQUEST_TIMEOUT_SECS: u64 = 30;
+
+/// Default timeout for establishing a connection, in seconds
+pub const DEFAULT_CONNECT_TIMEOUT_SECS: u64 = 10;
+
+/// Config file looked for in the working directory when `CANVAS_CONFIG_FILE`
+/// is not set
+pub const DEFAULT_CONFIG_FILE: &str = "canvas-mcp.toml";
+
+/// Settings that may come from a TOML config file
+///
+/// Keys match the `CanvasConfig` field names; anything unset falls back to
+/// environment variables and then defaults. Named `[profiles.<name>]`
+/// sections accept the same keys and override the top-level values when
+/// selected.
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct FileConfig {
+    api_token: Option<String>,
+    api_url: Option<String>,
+    institution_name: Option<String>,
+    timezone: Option<String>,
+    enable_anonymization: Option<bool>,
+    debug: Option<bool>,
+    refresh_token: Option<String>,
+    client_id: Option<String>,
+    client_secret: Option<String>,
+    #[serde(default)]
+    profiles: BTreeMap<String, FileConfig>,
+    /// Environment variables ignored because the selected profile sets them
+    #[serde(skip)]
+    pinned: Vec<&'static str>,
+}
+
+impl FileConfig {
+    /// Overlay the named profile (if any) on the top-level values
+    ///
+    /// Values the profile sets are pinned: they win over the environment, so
+    /// selecting a staging profile can't pick up a production token left in
+    /// `CANVAS_API_TOKEN`.
+    fn select(mut self, profile: Option<&str>) -> Result<Self> {
+        let Some(name) = profile else {
+            return Ok(self);
+        };
+        let Some(selected) = self.profiles.remove(name) else {
+            let available: Vec<&str> = self.profiles.keys().map(String::as_str).collect();
+            return Err(CanvasError::config(format!(
+                "Unknown profile \"{}\" (available: {})",
+                name,
+                if available.is_empty() {
+                    "none".to_string()
+                } else {
+                    available.join(", ")
+                }
+            )));
+        };
+        let pinned = [
+            (selected.api_token.is_some(), "CANVAS_API_TOKEN"),
+            (selected.api_url.is_some(), "CANVAS_API_URL"),
+            (selected.institution_name.is_some(), "INSTITUTION_NAME"),
+            (selected.timezone.is_some(), "TIMEZONE"),
+            (
+                selected.enable_anonymization.is_some(),
+                "ENABLE_DATA_ANONYMIZATION",
+            ),
+            (selected.debug.is_some(), "DEBUG"),
+            (selected.refresh_token.is_some(), "CANVAS_REFRESH_TOKEN"),
+            (selected.client_id.is_some(), "CANVAS_CLIENT_ID"),
+            (selected.client_secret.is_some(), "CANVAS_CLIENT_SECRET"),
+        ]
+        .into_iter()
+        .filter_map(|(set, key)| set.then_some(key))
+        .collect();
+        Ok(Self {
+            api_token: selected.api_token.or(self.api_token),
+            api_url: selected.api_url.or(self.api_url),
+            institution_name: selected.institution_name.or(self.institution_name),
+            timezone: selected.timezone.or(self.timezone),
+            enable_anonymization: selected.enable_anonymization.or(self.enable_anonymization),
+            debug: selected.debug.or(self.debug),
+            refresh_token: selected.refresh_token.or(self.refresh_token),
+            client_id: selected.client_id.or(self.client_id),
+            client_secret: selected.client_secret.or(self.client_secret),
+            profiles: BTreeMap::new(),
+            pinned,
+        })
+    }
+
+    fn read(path: &Path) -> Result<Self> {
+        let path = expand_tilde(path);
+        let text = fs::read_to_string(&path).map_err(|e| {
+            CanvasError::config(format!(
+                "Failed to read config file {}: {}",
+                path.display(),
+                e
+            ))
+        })?;
+        toml::from_str(&text).map_err(|e| {
+            CanvasError::config(format!("Invalid config file {}: {}", path.display(), e))
+        })
+    }
+}
 
 /// Canvas MCP Server Configuration
 #[derive(Debug, Clone)]
@@ -7,6 +141,16 @@ pub struct CanvasConfig {
     /// Canvas API access token
     pub api_token: String,
 
+    /// OAuth2 refresh token used to obtain a new access token when the
+    /// current one expires (requires `client_id` and `client_secret`)
+    pub refresh_token: Option<String>,
+
+    /// OAuth2 developer key ID
+    pub client_id: Option<String>,
+
+    /// OAuth2 developer key secret
+    pub client_secret: Option<String>,
+
     /// Canvas API base URL (e.g., https://institution.instructure.com/api/v1)
     pub api_url: String,
 
@@ -19,22 +163,200 @@ pub struct CanvasConfig {
     /// Enable data anonymization for student information
     pub enable_anonymization: bool,
 
+    /// Key for the hash used to derive student pseudonyms. Set a fixed value
+    /// to keep pseudonyms stable across server restarts; otherwise a random
+    /// salt is generated per process.
+    pub anonymization_salt: String,
+
+    /// Fields replaced with pseudonyms (or nulled, for avatars) on user objects
+    pub anonymization_fields: Vec<String>,
+
+    /// Keep an in-memory pseudonym -> user ID map for the session so an admin
+    /// can de-anonymize a specific student. Weakens the privacy guarantee, so
+    /// it is off by default.
+    pub anonymization_reversible: bool,
+
     /// Debug mode
     pub debug: bool,
+
+    /// Most verbose level written to the log file
+    pub log_level: Level,
+
+    /// Maximum number of pages `get_all` will follow before giving up
+    pub max_pages: usize,
+
+    /// Maximum number of retries for rate-limited or transient 5xx responses
+    pub max_retries: u32,
+
+    /// Base delay for exponential backoff between retries, in milliseconds
+    pub base_backoff_ms: u64,
+
+    /// Retry POST and PUT requests as well as GETs (off by default, since
+    /// writes are not guaranteed to be idempotent)
+    pub retry_writes: bool,
+
+    /// Client-side throttling driven by Canvas's `X-Rate-Limit-Remaining`
+    pub rate_limiter: RateLimiterConfig,
+
+    /// Maximum number of requests in flight at once, shared by every clone
+    /// of the client; tools that fan out wait for a free slot
+    pub max_concurrent_requests: usize,
+
+    /// How long GET responses are cached, in seconds; 0 (the default)
+    /// disables the cache
+    pub cache_ttl_secs: u64,
+
+    /// Maximum number of cached responses before the least recently used
+    /// is evicted
+    pub cache_max_entries: usize,
+
+    /// Idle connections kept open to the Canvas host for reuse
+    pub pool_max_idle_per_host: usize,
+
+    /// How long an idle pooled connection is kept, in seconds
+    pub pool_idle_timeout_secs: u64,
+
+    /// Overall timeout for an API request, in seconds (downloads and uploads
+    /// use their own, longer timeouts)
+    pub request_timeout_secs: u64,
+
+    /// Timeout for establishing a connection, in seconds
+    pub connect_timeout_secs: u64,
+
+    /// Make every request on behalf of this user via Canvas's `as_user_id`
+    /// parameter (requires the "Become other users" admin permission)
+    pub masquerade_as: Option<String>,
+
+    /// Account searched by admin tools such as `search_account_users`
+    /// (often `1`, the root account)
+    pub account_id: Option<String>,
+
+    /// Allow the `raw_api_request` tool to call arbitrary Canvas endpoints
+    pub enable_raw_api: bool,
+
+    /// Log writes (POST/PUT/PATCH/DELETE) instead of sending them, returning
+    /// a synthetic success
+    pub dry_run: bool,
+
+    /// PEM file of extra root certificates to trust, for Canvas instances
+    /// behind an internal CA
+    pub ca_bundle: Option<PathBuf>,
+
+    /// Skip TLS certificate verification entirely. Only for testing against
+    /// a self-signed instance; a warning is logged whenever a client is built.
+    pub danger_accept_invalid_certs: bool,
+
+    /// Proxy for plain-HTTP requests; when either proxy is set, the standard
+    /// `HTTP_PROXY`/`HTTPS_PROXY` variables are ignored
+    pub http_proxy: Option<String>,
+
+    /// Proxy for HTTPS requests (which includes every Canvas API call)
+    pub https_proxy: Option<String>,
+
+    /// Basic-auth credentials for the proxies above; credentials may also be
+    /// embedded in the proxy URL
+    pub proxy_username: Option<String>,
+
+    /// Password for proxy_username
+    pub proxy_password: Option<String>,
+
+    /// Appended to the `User-Agent` header, e.g. an institution identifier
+    pub user_agent_suffix: Option<String>,
+
+    /// Tools hidden from `list_tools` and refused when called
+    pub disabled_tools: Vec<String>,
+
+    /// Disable every tool that writes to Canvas or to disk (see
+    /// `tools::WRITE_TOOLS`)
+    pub read_only: bool,
+
+    /// Directory `download_file` may save into; `target_path` is resolved
+    /// inside it. Saving to disk is refused when unset.
+    pub download_dir: Option<PathBuf>,
 }
 
 impl CanvasConfig {
     /// Load configuration from environment variables
+    ///
+    /// Settings from a TOML file are used as a base when one is found: the
+    /// path in `CANVAS_CONFIG_FILE`, or `./canvas-mcp.toml` if it exists.
+    /// `CANVAS_PROFILE` selects a `[profiles.<name>]` section from that file.
+    /// Environment variables take precedence over file values, except those
+    /// set by the selected profile.
     pub fn from_env() -> Result<Self> {
         // Load .env file if it exists
         dotenvy::dotenv().ok();
 
-        let api_token = env::var("CANVAS_API_TOKEN").map_err(|_| {
-            CanvasError::config("CANVAS_API_TOKEN environment variable is required")
-        })?;
+        let profile = selected_profile();
+        let file =
+            match env::var("CANVAS_CONFIG_FILE") {
+                Ok(path) if !path.is_empty() => FileConfig::read(Path::new(&path))?,
+                _ if Path::new(DEFAULT_CONFIG_FILE).exists() => {
+                    FileConfig::read(Path::new(DEFAULT_CONFIG_FILE))?
+                }
+                _ if profile.is_some() => return Err(CanvasError::config(
+                    "CANVAS_PROFILE is set but no config file was found (set CANVAS_CONFIG_FILE)",
+                )),
+                _ => FileConfig::default(),
+            };
+
+        Self::from_sources(file.select(profile.as_deref())?, |key| env::var(key).ok())
+    }
+
+    /// Load configuration from a TOML file, with environment variables
+    /// overriding its top-level values
+    ///
+    /// A leading `~` in the path is expanded to the home directory. The
+    /// profile named by `CANVAS_PROFILE`, if set, is applied, and its values
+    /// win over the environment.
+    pub fn from_file(path: &Path) -> Result<Self> {
+        dotenvy::dotenv().ok();
+
+        let file = FileConfig::read(path)?.select(selected_profile().as_deref())?;
+        Self::from_sources(file, |key| env::var(key).ok())
+    }
+
+    /// Load configuration from a named `[profiles.<name>]` section of a TOML
+    /// file
+    ///
+    /// Values the profile sets win over the environment; environment
+    /// variables still override the file's top-level values.
+    pub fn from_profile(path: &Path, name: &str) -> Result<Self> {
+        dotenvy::dotenv().ok();
+
+        let file = FileConfig::read(path)?.select(Some(name))?;
+        Self::from_sources(file, |key| env::var(key).ok())
+    }
 
-        let api_url = env::var("CANVAS_API_URL")
-            .map_err(|_| CanvasError::config("CANVAS_API_URL environment variable is required"))?;
+    /// Names of the profiles defined in a TOML config file, sorted
+    pub fn profile_names(path: &Path) -> Result<Vec<String>> {
+        Ok(FileConfig::read(path)?.profiles.into_keys().collect())
+    }
+
+    /// Build a configuration from file values and an environment lookup
+    fn from_sources(mut file: FileConfig, var: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let pinned = std::mem::take(&mut file.pinned);
+        let var = |key: &str| {
+            if pinned.contains(&key) {
+                None
+            } else {
+                var(key)
+            }
+        };
+
+        let api_token = var("CANVAS_API_TOKEN")
+            .or(file.api_token)
+            .ok_or_else(|| {
+                CanvasError::config(
+                    "CANVAS_API_TOKEN environment variable (or api_token in the config file) is required",
+                )
+            })?;
+
+        let api_url = var("CANVAS_API_URL").or(file.api_url).ok_or_else(|| {
+            CanvasError::config(
+                "CANVAS_API_URL environment variable (or api_url in the config file) is required",
+            )
+        })?;
 
         // Validate API URL
         if !api_url.starts_with("http://") && !api_url.starts_with("https://") {
@@ -43,57 +365,321 @@ impl CanvasConfig {
             ));
         }
 
-        // Ensure API URL ends with /api/v1
-        let api_url = if api_url.ends_with("/api/v1") {
-            api_url
-        } else if api_url.ends_with('/') {
-            format!("{}api/v1", api_url)
-        } else {
-            format!("{}/api/v1", api_url)
-        };
+        let api_url = normalize_api_url(&api_url);
 
-        let institution_name = env::var("INSTITUTION_NAME").ok();
-        let timezone = env::var("TIMEZONE").ok();
+        let refresh_token = var("CANVAS_REFRESH_TOKEN").or(file.refresh_token);
+        let client_id = var("CANVAS_CLIENT_ID").or(file.client_id);
+        let client_secret = var("CANVAS_CLIENT_SECRET").or(file.client_secret);
 
-        let enable_anonymization = env::var("ENABLE_DATA_ANONYMIZATION")
-            .unwrap_or_else(|_| "false".to_string())
-            .parse::<bool>()
-            .unwrap_or(false);
+        let institution_name = var("INSTITUTION_NAME").or(file.institution_name);
+        let masquerade_as = var("CANVAS_MASQUERADE_AS").filter(|v| !v.is_empty());
+        let account_id = var("CANVAS_ACCOUNT_ID").filter(|v| !v.is_empty());
+        let timezone = var("TIMEZONE").or(file.timezone);
 
-        let debug = env::var("DEBUG")
-            .unwrap_or_else(|_| "false".to_string())
-            .parse::<bool>()
-            .unwrap_or(false);
+        let enable_anonymization = parse_or(
+            var("ENABLE_DATA_ANONYMIZATION"),
+            file.enable_anonymization.unwrap_or(false),
+        );
 
-        Ok(Self {
+        let anonymization_salt = var("ANONYMIZATION_SALT")
+            .filter(|v| !v.is_empty())
+            .unwrap_or_else(random_salt);
+
+        let anonymization_fields = var("ANONYMIZATION_FIELDS")
+            .map(|v| {
+                v.split(',')
+                    .map(|f| f.trim().to_string())
+                    .filter(|f| !f.is_empty())
+                    .collect::<Vec<_>>()
+            })
+            .filter(|fields| !fields.is_empty())
+            .unwrap_or_else(anonymize::default_fields);
+        let anonymization_reversible = parse_or(var("ANONYMIZATION_REVERSIBLE"), false);
+
+        let debug = parse_or(var("DEBUG"), file.debug.unwrap_or(false));
+
+        let log_level = parse_or(var("CANVAS_LOG_LEVEL"), Level::INFO);
+
+        let max_pages = parse_or(var("CANVAS_MAX_PAGES"), DEFAULT_MAX_PAGES);
+        let max_retries = parse_or(var("CANVAS_MAX_RETRIES"), DEFAULT_MAX_RETRIES);
+        let base_backoff_ms = parse_or(var("CANVAS_BASE_BACKOFF_MS"), DEFAULT_BASE_BACKOFF_MS);
+        let retry_writes = parse_or(var("CANVAS_RETRY_WRITES"), false);
+
+        let max_concurrent_requests = parse_or(
+            var("CANVAS_MAX_CONCURRENT_REQUESTS"),
+            DEFAULT_MAX_CONCURRENT_REQUESTS,
+        )
+        .max(1);
+
+        let cache_ttl_secs = parse_or(var("CANVAS_CACHE_TTL_SECS"), 0);
+        let cache_max_entries =
+            parse_or(var("CANVAS_CACHE_MAX_ENTRIES"), DEFAULT_CACHE_MAX_ENTRIES);
+
+        let pool_max_idle_per_host = parse_or(
+            var("CANVAS_POOL_MAX_IDLE_PER_HOST"),
+            DEFAULT_POOL_MAX_IDLE_PER_HOST,
+        );
+        let pool_idle_timeout_secs = parse_or(
+            var("CANVAS_POOL_IDLE_TIMEOUT_SECS"),
+            DEFAULT_POOL_IDLE_TIMEOUT_SECS,
+        );
+        let request_timeout_secs = parse_or(
+            var("CANVAS_REQUEST_TIMEOUT_SECS"),
+            DEFAULT_REQUEST_TIMEOUT_SECS,
+        );
+        let connect_timeout_secs = parse_or(
+            var("CANVAS_CONNECT_TIMEOUT_SECS"),
+            DEFAULT_CONNECT_TIMEOUT_SECS,
+        );
+
+        let enable_raw_api = parse_or(var("CANVAS_ENABLE_RAW_API"), false);
+        let dry_run = parse_or(var("CANVAS_DRY_RUN"), false);
+
+        let ca_bundle = var("CANVAS_CA_BUNDLE")
+            .filter(|v| !v.is_empty())
+            .map(|v| expand_tilde(Path::new(&v)));
+        let danger_accept_invalid_certs =
+            parse_or(var("CANVAS_DANGER_ACCEPT_INVALID_CERTS"), false);
+        let download_dir = var("CANVAS_DOWNLOAD_DIR")
+            .filter(|v| !v.is_empty())
+            .map(|v| expand_tilde(Path::new(&v)));
+
+        let http_proxy = var("CANVAS_HTTP_PROXY").filter(|v| !v.is_empty());
+        let https_proxy = var("CANVAS_HTTPS_PROXY").filter(|v| !v.is_empty());
+        let proxy_username = var("CANVAS_PROXY_USERNAME").filter(|v| !v.is_empty());
+        let proxy_password = var("CANVAS_PROXY_PASSWORD");
+
+        let user_agent_suffix = var("CANVAS_USER_AGENT_SUFFIX").filter(|v| !v.trim().is_empty());
+
+        let disabled_tools = var("CANVAS_DISABLED_TOOLS")
+            .map(|v| {
+                v.split(',')
+                    .map(|t| t.trim().to_string())
+                    .filter(|t| !t.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default();
+        let read_only = parse_or(var("CANVAS_READ_ONLY"), false);
+
+        let defaults = RateLimiterConfig::default();
+        let rate_limiter = RateLimiterConfig {
+            low_water_mark: parse_or(var("CANVAS_RATE_LIMIT_LOW_WATER"), defaults.low_water_mark),
+            max_delay: Duration::from_millis(parse_or(
+                var("CANVAS_RATE_LIMIT_MAX_DELAY_MS"),
+                defaults.max_delay.as_millis() as u64,
+            )),
+            exponent: parse_or(var("CANVAS_RATE_LIMIT_EXPONENT"), defaults.exponent),
+        };
+
+        let config = Self {
             api_token,
+            refresh_token,
+            client_id,
+            client_secret,
             api_url,
             institution_name,
             timezone,
             enable_anonymization,
+            anonymization_salt,
+            anonymization_fields,
+            anonymization_reversible,
             debug,
-        })
+            log_level,
+            max_pages,
+            max_retries,
+            base_backoff_ms,
+            retry_writes,
+            rate_limiter,
+            max_concurrent_requests,
+            cache_ttl_secs,
+            cache_max_entries,
+            pool_max_idle_per_host,
+            pool_idle_timeout_secs,
+            request_timeout_secs,
+            connect_timeout_secs,
+            masquerade_as,
+            account_id,
+            enable_raw_api,
+            dry_run,
+            ca_bundle,
+            danger_accept_invalid_certs,
+            http_proxy,
+            https_proxy,
+            proxy_username,
+            proxy_password,
+            user_agent_suffix,
+            disabled_tools,
+            read_only,
+            download_dir,
+        };
+        config.log_api_url_warning();
+        Ok(config)
+    }
+
+    /// A heads-up when `api_url` may not point at a Canvas API, if anything
+    /// looks off
+    ///
+    /// Flags a pasted page URL such as the dashboard or a course page, and
+    /// hosts outside `instructure.com`. Institutions hosting Canvas on their
+    /// own domain will see the latter, so this is only ever logged.
+    pub fn api_url_warning(&self) -> Option<String> {
+        let url = url::Url::parse(&self.api_url).ok()?;
+        let host = url.host_str()?;
+        let prefix = url.path().trim_end_matches("/api/v1");
+        let page = prefix
+            .split('/')
+            .find(|segment| CANVAS_PAGE_SEGMENTS.contains(&segment.to_ascii_lowercase().as_str()));
+
+        if let Some(page) = page {
+            Some(format!(
+                "CANVAS_API_URL contains \"/{}\", which looks like a Canvas page rather than the API; use just the site address, e.g. {}://{}",
+                page,
+                url.scheme(),
+                host
+            ))
+        } else if !host.ends_with(".instructure.com")
+            && host != "localhost"
+            && url
+                .host()
+                .is_some_and(|h| matches!(h, url::Host::Domain(_)))
+        {
+            Some(format!(
+                "CANVAS_API_URL host {} is not an instructure.com domain; check it for typos unless your institution hosts Canvas on its own domain",
+                host
+            ))
+        } else {
+            None
+        }
+    }
+
+    /// Log `api_url_warning`, if any
+    pub(crate) fn log_api_url_warning(&self) {
+        if let Some(warning) = self.api_url_warning() {
+            tracing::warn!("{}", warning);
+        }
+    }
+
+    /// Whether the tool called `name` is available, given `disabled_tools`,
+    /// `read_only`, and `enable_raw_api`
+    pub fn tool_enabled(&self, name: &str) -> bool {
+        let disabled = self.disabled_tools.iter().any(|t| t == name)
+            || (self.read_only && crate::tools::WRITE_TOOLS.contains(&name))
+            || (name == "raw_api_request" && !self.enable_raw_api);
+        !disabled
+    }
+
+    /// Whether an expired access token can be refreshed via OAuth2
+    pub fn can_refresh_token(&self) -> bool {
+        self.refresh_token.is_some() && self.client_id.is_some() && self.client_secret.is_some()
     }
 
     /// Create a new configuration with the given values
     pub fn new(api_token: String, api_url: String) -> Self {
-        // Normalize API URL to ensure it ends with /api/v1
-        let api_url = if api_url.ends_with("/api/v1") {
-            api_url
-        } else if api_url.ends_with('/') {
-            format!("{}api/v1", api_url)
-        } else {
-            format!("{}/api/v1", api_url)
-        };
+        let api_url = normalize_api_url(&api_url);
 
-        Self {
+        let config = Self {
             api_token,
+            refresh_token: None,
+            client_id: None,
+            client_secret: None,
             api_url,
             institution_name: None,
             timezone: None,
             enable_anonymization: false,
+            anonymization_salt: random_salt(),
+            anonymization_fields: anonymize::default_fields(),
+            anonymization_reversible: false,
             debug: false,
-        }
+            log_level: Level::INFO,
+            max_pages: DEFAULT_MAX_PAGES,
+            max_retries: DEFAULT_MAX_RETRIES,
+            base_backoff_ms: DEFAULT_BASE_BACKOFF_MS,
+            retry_writes: false,
+            rate_limiter: RateLimiterConfig::default(),
+            max_concurrent_requests: DEFAULT_MAX_CONCURRENT_REQUESTS,
+            cache_ttl_secs: 0,
+            cache_max_entries: DEFAULT_CACHE_MAX_ENTRIES,
+            pool_max_idle_per_host: DEFAULT_POOL_MAX_IDLE_PER_HOST,
+            pool_idle_timeout_secs: DEFAULT_POOL_IDLE_TIMEOUT_SECS,
+            request_timeout_secs: DEFAULT_REQUEST_TIMEOUT_SECS,
+            connect_timeout_secs: DEFAULT_CONNECT_TIMEOUT_SECS,
+            masquerade_as: None,
+            account_id: None,
+            enable_raw_api: false,
+            dry_run: false,
+            ca_bundle: None,
+            danger_accept_invalid_certs: false,
+            http_proxy: None,
+            https_proxy: None,
+            proxy_username: None,
+            proxy_password: None,
+            user_agent_suffix: None,
+            disabled_tools: Vec::new(),
+            read_only: false,
+            download_dir: None,
+        };
+        config.log_api_url_warning();
+        config
+    }
+}
+
+/// Reduce an API URL to the base ending in `/api/v1`
+///
+/// Anything after `/api/v1` is dropped, so a full endpoint such as
+/// `https://school.instructure.com/api/v1/courses` doesn't produce
+/// `.../api/v1/courses/api/v1`. Query strings, fragments, and trailing
+/// slashes are dropped too. A URL without `/api/v1` gets it appended, which
+/// keeps Canvas instances served under a path prefix working.
+fn normalize_api_url(api_url: &str) -> String {
+    let url = api_url.trim();
+    let url = url.split(['?', '#']).next().unwrap_or(url);
+    let url = url.trim_end_matches('/');
+    let base = url
+        .match_indices("/api/v1")
+        .map(|(index, _)| index)
+        .find(|&index| {
+            matches!(
+                url[index + "/api/v1".len()..].chars().next(),
+                None | Some('/')
+            )
+        })
+        .map_or(url, |index| &url[..index]);
+    format!("{}/api/v1", base)
+}
+
+/// First path segments of Canvas web pages, which show up when a browser URL
+/// is pasted as `CANVAS_API_URL`
+const CANVAS_PAGE_SEGMENTS: &[&str] = &[
+    "accounts",
+    "calendar",
+    "conversations",
+    "courses",
+    "dashboard",
+    "login",
+    "profile",
+];
+
+/// Generate a per-process salt for anonymization
+fn random_salt() -> String {
+    uuid::Uuid::new_v4().to_string()
+}
+
+/// Profile requested via `CANVAS_PROFILE`, if any
+fn selected_profile() -> Option<String> {
+    env::var("CANVAS_PROFILE").ok().filter(|v| !v.is_empty())
+}
+
+/// Parse an optional setting, falling back to `default` when it is unset or
+/// invalid
+fn parse_or<T: FromStr>(value: Option<String>, default: T) -> T {
+    value.and_then(|v| v.parse().ok()).unwrap_or(default)
+}
+
+/// Expand a leading `~` to the user's home directory
+fn expand_tilde(path: &Path) -> PathBuf {
+    match (path.strip_prefix("~"), env::var_os("HOME")) {
+        (Ok(rest), Some(home)) => PathBuf::from(home).join(rest),
+        _ => path.to_path_buf(),
     }
 }
 
@@ -121,4 +707,176 @@ mod tests {
         );
         assert!(config3.api_url.ends_with("/api/v1"));
     }
+
+    #[test]
+    fn test_api_url_warning() {
+        let warning =
+            |url: &str| CanvasConfig::new("token".to_string(), url.to_string()).api_url_warning();
+
+        assert_eq!(warning("https://school.instructure.com"), None);
+        assert_eq!(warning("http://127.0.0.1:8080"), None);
+        assert!(warning("https://school.instructure.com/courses/123")
+            .unwrap()
+            .contains("\"/courses\", which looks like a Canvas page"));
+        assert!(warning("https://school.instrucure.com")
+            .unwrap()
+            .contains("not an instructure.com domain"));
+    }
+
+    #[test]
+    fn test_api_url_with_endpoint_path_is_reduced_to_base() {
+        for url in [
+            "https://x.instructure.com/api/v1/courses",
+            "https://x.instructure.com/api/v1/courses/",
+            "https://x.instructure.com/api/v1/",
+            "https://x.instructure.com/api/v1/courses?per_page=10",
+            "https://x.instructure.com//",
+        ] {
+            let config = CanvasConfig::new("token".to_string(), url.to_string());
+            assert_eq!(
+                config.api_url, "https://x.instructure.com/api/v1",
+                "{}",
+                url
+            );
+        }
+
+        let config = CanvasConfig::new(
+            "token".to_string(),
+            "https://lms.example.edu/canvas/".to_string(),
+        );
+        assert_eq!(config.api_url, "https://lms.example.edu/canvas/api/v1");
+    }
+
+    #[test]
+    fn test_file_values_with_env_override() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("canvas-mcp.toml");
+        fs::write(
+            &path,
+            r#"
+api_token = "file-token"
+api_url = "https://file.instructure.com"
+institution_name = "File University"
+timezone = "America/Denver"
+debug = true
+"#,
+        )
+        .unwrap();
+
+        let file = FileConfig::read(&path).unwrap();
+        let config = CanvasConfig::from_sources(file, |key| {
+            (key == "CANVAS_API_TOKEN").then(|| "env-token".to_string())
+        })
+        .unwrap();
+
+        assert_eq!(config.api_token, "env-token");
+        assert_eq!(config.api_url, "https://file.instructure.com/api/v1");
+        assert_eq!(config.institution_name.as_deref(), Some("File University"));
+        assert_eq!(config.timezone.as_deref(), Some("America/Denver"));
+        assert!(config.debug);
+        assert!(!config.enable_anonymization);
+    }
+
+    #[test]
+    fn test_connection_tuning_defaults_and_overrides() {
+        let config = CanvasConfig::from_sources(FileConfig::default(), |key| match key {
+            "CANVAS_API_TOKEN" => Some("token".to_string()),
+            "CANVAS_API_URL" => Some("https://example.instructure.com".to_string()),
+            "CANVAS_POOL_MAX_IDLE_PER_HOST" => Some("64".to_string()),
+            "CANVAS_REQUEST_TIMEOUT_SECS" => Some("not a number".to_string()),
+            _ => None,
+        })
+        .unwrap();
+
+        assert_eq!(config.pool_max_idle_per_host, 64);
+        assert_eq!(
+            config.pool_idle_timeout_secs,
+            DEFAULT_POOL_IDLE_TIMEOUT_SECS
+        );
+        assert_eq!(config.request_timeout_secs, DEFAULT_REQUEST_TIMEOUT_SECS);
+        assert_eq!(config.connect_timeout_secs, DEFAULT_CONNECT_TIMEOUT_SECS);
+    }
+
+    #[test]
+    fn test_log_level() {
+        let log_level = |level: Option<&str>| {
+            CanvasConfig::from_sources(FileConfig::default(), |key| match key {
+                "CANVAS_API_TOKEN" => Some("token".to_string()),
+                "CANVAS_API_URL" => Some("https://example.instructure.com".to_string()),
+                "CANVAS_LOG_LEVEL" => level.map(str::to_string),
+                _ => None,
+            })
+            .unwrap()
+            .log_level
+        };
+
+        assert_eq!(log_level(None), Level::INFO);
+        assert_eq!(log_level(Some("debug")), Level::DEBUG);
+        assert_eq!(log_level(Some("WARN")), Level::WARN);
+        assert_eq!(log_level(Some("verbose")), Level::INFO);
+    }
+
+    #[test]
+    fn test_missing_token_is_reported() {
+        let error = CanvasConfig::from_sources(FileConfig::default(), |_| None).unwrap_err();
+        assert!(error.to_string().contains("CANVAS_API_TOKEN"));
+    }
+
+    #[test]
+    fn test_profiles() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("canvas-mcp.toml");
+        fs::write(
+            &path,
+            r#"
+timezone = "America/Denver"
+
+[profiles.sandbox]
+api_token = "sandbox-token"
+api_url = "https://school.test.instructure.com"
+
+[profiles.production]
+api_token = "prod-token"
+api_url = "https://school.instructure.com"
+timezone = "America/New_York"
+"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            CanvasConfig::profile_names(&path).unwrap(),
+            vec!["production", "sandbox"]
+        );
+
+        let file = FileConfig::read(&path)
+            .unwrap()
+            .select(Some("sandbox"))
+            .unwrap();
+        let config = CanvasConfig::from_sources(file, |_| None).unwrap();
+        assert_eq!(config.api_token, "sandbox-token");
+        assert_eq!(config.api_url, "https://school.test.instructure.com/api/v1");
+        assert_eq!(config.timezone.as_deref(), Some("America/Denver"));
+
+        // An explicitly selected profile wins over leftover environment values
+        let file = FileConfig::read(&path)
+            .unwrap()
+            .select(Some("sandbox"))
+            .unwrap();
+        let config = CanvasConfig::from_sources(file, |key| match key {
+            "CANVAS_API_TOKEN" => Some("prod-env-token".to_string()),
+            "CANVAS_API_URL" => Some("https://school.instructure.com".to_string()),
+            "TIMEZONE" => Some("Europe/Paris".to_string()),
+            _ => None,
+        })
+        .unwrap();
+        assert_eq!(config.api_token, "sandbox-token");
+        assert_eq!(config.api_url, "https://school.test.instructure.com/api/v1");
+        assert_eq!(config.timezone.as_deref(), Some("Europe/Paris"));
+
+        let error = FileConfig::read(&path)
+            .unwrap()
+            .select(Some("staging"))
+            .unwrap_err();
+        assert!(error.to_string().contains("production, sandbox"));
+    }
 }
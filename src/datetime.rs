@@ -0,0 +1,117 @@
+use crate::error::{CanvasError, Result};
+use chrono::{DateTime, NaiveDate, Utc};
+use chrono_tz::Tz;
+
+/// Convert a UTC timestamp into the given IANA timezone (e.g. "America/Denver")
+///
+/// Invalid timezone names fall back to UTC with a logged warning rather than
+/// failing the request.
+pub fn to_local(utc: DateTime<Utc>, tz: &str) -> DateTime<Tz> {
+    utc.with_timezone(&parse_timezone(tz))
+}
+
+/// Human-readable local time, e.g. "Fri, Oct 3 at 11:59 PM"
+pub fn format_local(utc: DateTime<Utc>, tz: &str) -> String {
+    to_local(utc, tz)
+        .format("%a, %b %-d at %-I:%M %p")
+        .to_string()
+}
+
+/// Local due date, e.g. "Fri, Oct 3 at 11:59 PM", or "No due date"
+pub fn format_due(due_at: Option<DateTime<Utc>>, tz: &str) -> String {
+    match due_at {
+        Some(due) => format_local(due, tz),
+        None => "No due date".to_string(),
+    }
+}
+
+/// Compact duration, e.g. "2d 3h 15m", "45m", or "under a minute"
+pub fn format_duration(duration: chrono::Duration) -> String {
+    let minutes = duration.num_minutes();
+    if minutes < 1 {
+        return "under a minute".to_string();
+    }
+    let (days, hours, minutes) = (minutes / 1440, minutes / 60 % 24, minutes % 60);
+    let parts: Vec<String> = [(days, "d"), (hours, "h"), (minutes, "m")]
+        .into_iter()
+        .filter(|(n, _)| *n > 0)
+        .map(|(n, unit)| format!("{}{}", n, unit))
+        .collect();
+    parts.join(" ")
+}
+
+/// Parse an IANA timezone name, falling back to UTC
+pub fn parse_timezone(tz: &str) -> Tz {
+    tz.parse().unwrap_or_else(|_| {
+        tracing::warn!(timezone = tz, "unknown timezone, falling back to UTC");
+        Tz::UTC
+    })
+}
+
+/// Parse an RFC 3339 timestamp from a tool parameter
+pub fn parse_datetime(field: &str, value: &str) -> Result<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(value.trim())
+        .map(|at| at.with_timezone(&Utc))
+        .map_err(|e| {
+            CanvasError::InvalidParameter(format!(
+                "{} must be an RFC 3339 timestamp like 2025-10-06T08:00:00Z ({})",
+                field, e
+            ))
+        })
+}
+
+/// Check that a tool parameter is a date (`2025-10-06`) or an RFC 3339
+/// timestamp, the two forms Canvas accepts for date filters
+pub fn check_date(field: &str, value: &str) -> Result<()> {
+    let value = value.trim();
+    if NaiveDate::parse_from_str(value, "%Y-%m-%d").is_ok()
+        || DateTime::parse_from_rfc3339(value).is_ok()
+    {
+        Ok(())
+    } else {
+        Err(CanvasError::InvalidParameter(format!(
+            "{} must be a date like 2025-10-06 or a timestamp like 2025-10-06T08:00:00Z (got {})",
+            field, value
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_local() {
+        let utc = "2025-10-04T06:59:00Z".parse::<DateTime<Utc>>().unwrap();
+
+        let denver = to_local(utc, "America/Denver");
+        assert_eq!(
+            denver.format("%Y-%m-%d %H:%M %Z").to_string(),
+            "2025-10-04 00:59 MDT"
+        );
+
+        let fallback = to_local(utc, "Mars/Olympus_Mons");
+        assert_eq!(fallback.format("%H:%M %Z").to_string(), "06:59 UTC");
+    }
+
+    #[test]
+    fn test_format_due() {
+        let utc = "2025-10-04T06:59:00Z".parse::<DateTime<Utc>>().unwrap();
+        assert_eq!(
+            format_due(Some(utc), "America/Los_Angeles"),
+            "Fri, Oct 3 at 11:59 PM"
+        );
+        assert_eq!(format_due(None, "America/Los_Angeles"), "No due date");
+    }
+
+    #[test]
+    fn test_format_duration() {
+        let minutes = chrono::Duration::minutes;
+        assert_eq!(format_duration(minutes(3 * 1440 + 2 * 60 + 5)), "3d 2h 5m");
+        assert_eq!(format_duration(minutes(60)), "1h");
+        assert_eq!(
+            format_duration(chrono::Duration::seconds(20)),
+            "under a minute"
+        );
+    }
+}
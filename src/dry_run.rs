@@ -0,0 +1,267 @@
+use reqwest::Request;
+use serde_json::{Map, Value};
+
+/// Header marking a response that was synthesized instead of sent
+pub const DRY_RUN_HEADER: &str = "x-canvas-mcp-dry-run";
+
+/// Whether a request only reads data and is sent even in dry-run mode:
+/// GETs, and GraphQL documents whose every operation is a query
+///
+/// Anything that can't be positively identified as a query (a mutation or
+/// subscription anywhere in the document, or a body that doesn't parse) is
+/// treated as a write, so an `operationName` can't select a hidden mutation.
+pub fn is_read_only(request: &Request) -> bool {
+    if request.method() == reqwest::Method::GET {
+        return true;
+    }
+    if !request.url().path().ends_with("/api/graphql") {
+        return false;
+    }
+    request
+        .body()
+        .and_then(|body| body.as_bytes())
+        .and_then(|bytes| serde_json::from_slice::<Value>(bytes).ok())
+        .and_then(|body| body.get("query")?.as_str().map(str::to_string))
+        .and_then(|query| definition_kinds(&query))
+        .is_some_and(|kinds| {
+            kinds.iter().any(|kind| kind == "query")
+                && kinds
+                    .iter()
+                    .all(|kind| kind == "query" || kind == "fragment")
+        })
+}
+
+/// Kind of each top-level definition in a GraphQL document (`query`,
+/// `mutation`, `subscription`, `fragment`, ...), with the `{ ... }` shorthand
+/// reported as `query`
+///
+/// Comments and string literals are skipped. Returns `None` when the braces
+/// don't balance.
+fn definition_kinds(document: &str) -> Option<Vec<String>> {
+    let mut kinds = Vec::new();
+    let mut depth = 0usize;
+    let mut expect_definition = true;
+    let mut chars = document.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '#' => while chars.next_if(|&c| c != '\n').is_some() {},
+            '"' => match (chars.next_if_eq(&'"'), chars.next_if_eq(&'"')) {
+                (Some(_), Some(_)) => skip_string(&mut chars, true)?,
+                // `""`, an empty string
+                (Some(_), None) => {}
+                _ => skip_string(&mut chars, false)?,
+            },
+            '{' | '(' | '[' => {
+                if depth == 0 && c == '{' && expect_definition {
+                    kinds.push("query".to_string());
+                    expect_definition = false;
+                }
+                depth += 1;
+            }
+            '}' | ')' | ']' => {
+                depth = depth.checked_sub(1)?;
+                if depth == 0 && c == '}' {
+                    expect_definition = true;
+                }
+            }
+            c if depth == 0 && expect_definition && (c.is_alphabetic() || c == '_') => {
+                let mut word = c.to_string();
+                while let Some(c) = chars.next_if(|c| c.is_alphanumeric() || *c == '_') {
+                    word.push(c);
+                }
+                kinds.push(word);
+                expect_definition = false;
+            }
+            _ => {}
+        }
+    }
+
+    (depth == 0).then_some(kinds)
+}
+
+/// Skip past the end of a string literal whose opening quote(s) were consumed
+fn skip_string(chars: &mut std::iter::Peekable<std::str::Chars>, block: bool) -> Option<()> {
+    let mut quotes = 0;
+    loop {
+        match chars.next()? {
+            '\\' if !block => {
+                chars.next()?;
+            }
+            '"' if !block => return Some(()),
+            '"' => {
+                quotes += 1;
+                if quotes == 3 {
+                    return Some(());
+                }
+                continue;
+            }
+            _ => {}
+        }
+        quotes = 0;
+    }
+}
+
+/// Build the body returned for a write that wasn't sent
+///
+/// The fields the request would have set are flattened onto the resource's
+/// current state (for a PUT, when it could be fetched), so
+/// `assignment[points_possible]=10` shows up as `"points_possible": 10`. The
+/// result is marked with `"dry_run": true`.
+pub fn synthetic_body(request: &Request, current: Option<Value>) -> Value {
+    let mut object = match current {
+        Some(Value::Object(object)) => object,
+        _ => Map::new(),
+    };
+    let mut fields = Map::new();
+    for (name, value) in request_fields(request) {
+        match (fields.get_mut(&name), value) {
+            (Some(Value::Array(items)), Value::Array(more)) => items.extend(more),
+            (_, value) => {
+                fields.insert(name, value);
+            }
+        }
+    }
+    object.extend(fields);
+    object.entry("id").or_insert(Value::from(0));
+    object.entry("url").or_insert_with(|| {
+        let segment = request
+            .url()
+            .path_segments()
+            .and_then(|mut s| s.next_back());
+        Value::from(segment.unwrap_or_default())
+    });
+    object
+        .entry("workflow_state")
+        .or_insert_with(|| Value::from("dry_run"));
+    object.insert("dry_run".to_string(), Value::Bool(true));
+    Value::Object(object)
+}
+
+/// Bodies to try deserializing a synthetic response as, most detailed first
+///
+/// Request fields are strings, so a typed model may reject them (e.g. a
+/// numeric-looking name); the bare marker object and a one-element array
+/// (for endpoints like `POST /conversations` that return a list) are the
+/// fallbacks.
+pub fn candidates(body: &Value) -> Vec<Value> {
+    let mut minimal = Map::new();
+    for key in ["id", "url", "workflow_state", "dry_run"] {
+        if let Some(value) = body.get(key) {
+            minimal.insert(key.to_string(), value.clone());
+        }
+    }
+    let minimal = Value::Object(minimal);
+    vec![
+        body.clone(),
+        minimal.clone(),
+        Value::Array(vec![body.clone()]),
+        Value::Array(vec![minimal]),
+    ]
+}
+
+/// Fields of a JSON or form-encoded request body, with bracketed form keys
+/// reduced to their last part (`comment[text_comment]` -> `text_comment`)
+fn request_fields(request: &Request) -> Vec<(String, Value)> {
+    let Some(bytes) = request.body().and_then(|body| body.as_bytes()) else {
+        return Vec::new();
+    };
+    if let Ok(Value::Object(json)) = serde_json::from_slice(bytes) {
+        return json.into_iter().collect();
+    }
+
+    url::form_urlencoded::parse(bytes)
+        .map(|(key, value)| {
+            let repeated = key.ends_with("[]");
+            let key = key.trim_end_matches("[]");
+            let name = key
+                .rsplit_once('[')
+                .map(|(_, last)| last.trim_end_matches(']'))
+                .unwrap_or(key);
+            let value = form_value(&value);
+            let value = if repeated {
+                Value::Array(vec![value])
+            } else {
+                value
+            };
+            (name.to_string(), value)
+        })
+        .collect()
+}
+
+/// Form values are strings; restore booleans and numbers so they read as
+/// Canvas would return them
+fn form_value(value: &str) -> Value {
+    match value {
+        "true" => Value::Bool(true),
+        "false" => Value::Bool(false),
+        _ => value
+            .parse::<f64>()
+            .ok()
+            .filter(|n| n.is_finite())
+            .and_then(serde_json::Number::from_f64)
+            .map(Value::Number)
+            .unwrap_or_else(|| Value::from(value)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn graphql(body: serde_json::Value) -> Request {
+        reqwest::Client::new()
+            .post("https://canvas.test/api/graphql")
+            .json(&body)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_only_pure_graphql_queries_are_read_only() {
+        let read_only = |query: &str| is_read_only(&graphql(serde_json::json!({"query": query})));
+
+        assert!(read_only("query($id: ID!) { course(id: $id) { name } }"));
+        assert!(read_only("{ course(id: \"1\") { name(x: \"\") } }"));
+        assert!(read_only(
+            "query Q { course(id: 1) { ...F } }\n# mutation in a comment\nfragment F on Course { name(arg: \"}\") }"
+        ));
+
+        assert!(!read_only("mutation { deleteThing(id: 1) { id } }"));
+        assert!(!read_only(
+            "# note\nmutation {\n  deleteThing(id: 1) { id }\n}"
+        ));
+        assert!(!read_only("subscription { events { id } }"));
+        assert!(!read_only("query { a } }"));
+        assert!(!is_read_only(&graphql(serde_json::json!({
+            "query": "query Safe { course(id: 1) { name } } mutation Hidden { deleteThing(id: 1) { id } }",
+            "operationName": "Hidden"
+        }))));
+    }
+
+    #[test]
+    fn test_synthetic_body_overlays_form_fields() {
+        let request = reqwest::Client::new()
+            .put("https://canvas.test/api/v1/courses/1/assignments/7")
+            .form(&[
+                ("assignment[points_possible]", "10"),
+                ("assignment[submission_types][]", "online_upload"),
+                ("assignment[submission_types][]", "online_url"),
+            ])
+            .build()
+            .unwrap();
+        let current = serde_json::json!({
+            "id": 7, "name": "Essay 1", "points_possible": 5, "submission_types": ["none"]
+        });
+
+        let body = synthetic_body(&request, Some(current));
+        assert_eq!(body["id"], 7);
+        assert_eq!(body["name"], "Essay 1");
+        assert_eq!(body["points_possible"], 10.0);
+        assert_eq!(
+            body["submission_types"],
+            serde_json::json!(["online_upload", "online_url"])
+        );
+        assert_eq!(body["dry_run"], true);
+    }
+}
@@ -1,3 +1,4 @@
+use std::time::Duration;
 use thiserror::Error;
 
 /// Errors that can occur in the Canvas MCP server
@@ -17,7 +18,12 @@ pub enum CanvasError {
 
     /// Canvas API error
     #[error("Canvas API error: {status} - {message}")]
-    Api { status: u16, message: String },
+    Api {
+        status: u16,
+        message: String,
+        /// `error_code` values from Canvas's `errors` array, if any
+        error_codes: Vec<String>,
+    },
 
     /// Resource not found
     #[error("Resource not found: {0}")]
@@ -28,8 +34,21 @@ pub enum CanvasError {
     Auth(String),
 
     /// Rate limit exceeded
-    #[error("Rate limit exceeded: {0}")]
-    RateLimit(String),
+    #[error("Rate limit exceeded: {message}")]
+    RateLimit {
+        message: String,
+        /// How long Canvas asked us to wait, from the `Retry-After` header
+        retry_after: Option<Duration>,
+    },
+
+    /// A retryable failure that persisted through every retry
+    #[error("Gave up after {attempts} attempts: {source}")]
+    RetriesExhausted {
+        /// Requests sent, including the first
+        attempts: u32,
+        /// The error from the last attempt
+        source: Box<CanvasError>,
+    },
 
     /// Invalid parameter
     #[error("Invalid parameter: {0}")]
@@ -40,6 +59,73 @@ pub enum CanvasError {
     Internal(String),
 }
 
+/// Convert a Canvas error into an MCP tool error
+///
+/// The Canvas status code, `error_code`s, and any `Retry-After` delay are
+/// kept in the error's `data` payload.
+impl From<CanvasError> for rmcp::Error {
+    fn from(error: CanvasError) -> Self {
+        // Classify by the last attempt's error, noting how many were made
+        if let CanvasError::RetriesExhausted { attempts, source } = error {
+            let mut error = rmcp::Error::from(*source);
+            error.message =
+                format!("Gave up after {} attempts: {}", attempts, error.message).into();
+            let mut data = match error.data.take() {
+                Some(serde_json::Value::Object(data)) => data,
+                _ => serde_json::Map::new(),
+            };
+            data.insert("attempts".to_string(), attempts.into());
+            error.data = Some(serde_json::Value::Object(data));
+            return error;
+        }
+
+        let mut data = serde_json::Map::new();
+        if let Some(status) = error.status_code() {
+            data.insert("status".to_string(), status.into());
+        }
+        if let CanvasError::Api { error_codes, .. } = &error {
+            if !error_codes.is_empty() {
+                data.insert("error_codes".to_string(), error_codes.clone().into());
+            }
+        }
+        if let CanvasError::RateLimit {
+            retry_after: Some(delay),
+            ..
+        } = &error
+        {
+            data.insert("retry_after_secs".to_string(), delay.as_secs_f64().into());
+        }
+        let data = (!data.is_empty()).then_some(serde_json::Value::Object(data));
+
+        let message = error.to_string();
+        match error {
+            CanvasError::Auth(_) => rmcp::Error::invalid_request(message, data),
+            CanvasError::NotFound(_) => rmcp::Error::invalid_params(
+                format!(
+                    "{}. Check that the ID is correct and visible to this token",
+                    message
+                ),
+                data,
+            ),
+            CanvasError::RateLimit { retry_after, .. } => rmcp::Error::internal_error(
+                format!(
+                    "{}. Wait {} and try again",
+                    message,
+                    retry_after
+                        .map(|d| format!("{} seconds", d.as_secs().max(1)))
+                        .unwrap_or_else(|| "a minute".to_string())
+                ),
+                data,
+            ),
+            CanvasError::InvalidParameter(_) => rmcp::Error::invalid_params(message, data),
+            CanvasError::Api { status, .. } if (400..500).contains(&status) => {
+                rmcp::Error::invalid_params(message, data)
+            }
+            _ => rmcp::Error::internal_error(message, data),
+        }
+    }
+}
+
 /// Result type for Canvas operations
 pub type Result<T> = std::result::Result<T, CanvasError>;
 
@@ -54,6 +140,7 @@ impl CanvasError {
         Self::Api {
             status,
             message: message.into(),
+            error_codes: Vec::new(),
         }
     }
 
@@ -67,8 +154,142 @@ impl CanvasError {
         Self::Auth(msg.into())
     }
 
+    /// Create a rate limit error
+    pub fn rate_limit(msg: impl Into<String>, retry_after: Option<Duration>) -> Self {
+        Self::RateLimit {
+            message: msg.into(),
+            retry_after,
+        }
+    }
+
     /// Create an internal error
     pub fn internal(msg: impl Into<String>) -> Self {
         Self::Internal(msg.into())
     }
+
+    /// Wrap the last attempt's error once retries are used up
+    pub fn retries_exhausted(attempts: u32, source: CanvasError) -> Self {
+        Self::RetriesExhausted {
+            attempts,
+            source: Box::new(source),
+        }
+    }
+
+    /// Whether the failure is a temporary server or network problem: a
+    /// timeout, a dropped connection, or a 5xx response
+    pub fn is_transient(&self) -> bool {
+        match self {
+            Self::Http(e) => {
+                e.is_timeout() || e.is_connect() || e.status().is_some_and(|s| s.is_server_error())
+            }
+            Self::Api { status, .. } => (500..600).contains(status),
+            Self::RetriesExhausted { source, .. } => source.is_transient(),
+            _ => false,
+        }
+    }
+
+    /// Whether the same request may succeed if tried again later
+    ///
+    /// True for rate limiting and transient failures; false for errors that
+    /// will keep failing, such as bad credentials or a missing resource.
+    pub fn is_retryable(&self) -> bool {
+        match self {
+            Self::RateLimit { .. } => true,
+            Self::RetriesExhausted { source, .. } => source.is_retryable(),
+            _ => self.is_transient(),
+        }
+    }
+
+    /// HTTP status code behind the error, when known
+    pub fn status_code(&self) -> Option<u16> {
+        match self {
+            Self::Api { status, .. } => Some(*status),
+            Self::Http(e) => e.status().map(|s| s.as_u16()),
+            Self::NotFound(_) => Some(404),
+            Self::RetriesExhausted { source, .. } => source.status_code(),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_classification() {
+        let cases = [
+            (
+                CanvasError::rate_limit("slow down", None),
+                true,
+                false,
+                None,
+            ),
+            (CanvasError::api(503, "unavailable"), true, true, Some(503)),
+            (CanvasError::api(500, "oops"), true, true, Some(500)),
+            (
+                CanvasError::api(400, "bad request"),
+                false,
+                false,
+                Some(400),
+            ),
+            (CanvasError::auth("invalid token"), false, false, None),
+            (CanvasError::not_found("course 1"), false, false, Some(404)),
+            (
+                CanvasError::InvalidParameter("course_id".to_string()),
+                false,
+                false,
+                None,
+            ),
+            (CanvasError::config("missing token"), false, false, None),
+            (CanvasError::internal("bug"), false, false, None),
+        ];
+
+        for (error, retryable, transient, status) in cases {
+            assert_eq!(error.is_retryable(), retryable, "{}", error);
+            assert_eq!(error.is_transient(), transient, "{}", error);
+            assert_eq!(error.status_code(), status, "{}", error);
+        }
+    }
+
+    #[test]
+    fn test_into_mcp_error() {
+        let error = rmcp::Error::from(CanvasError::Api {
+            status: 400,
+            message: "due_at can't be before unlock_at".to_string(),
+            error_codes: vec!["invalid_date".to_string()],
+        });
+        assert_eq!(error.code, rmcp::model::ErrorCode::INVALID_PARAMS);
+        let data = error.data.unwrap();
+        assert_eq!(data["status"], 400);
+        assert_eq!(data["error_codes"][0], "invalid_date");
+
+        let error = rmcp::Error::from(CanvasError::rate_limit(
+            "403 Forbidden (Rate Limit Exceeded)",
+            Some(Duration::from_secs(30)),
+        ));
+        assert!(error.message.ends_with("Wait 30 seconds and try again"));
+        assert_eq!(error.data.unwrap()["retry_after_secs"], 30.0);
+
+        // A sub-second Retry-After must not read as "retry now"
+        let error = rmcp::Error::from(CanvasError::rate_limit(
+            "429 Too Many Requests",
+            Some(Duration::from_millis(500)),
+        ));
+        assert_eq!(error.data.unwrap()["retry_after_secs"], 0.5);
+
+        let error = rmcp::Error::from(CanvasError::retries_exhausted(
+            3,
+            CanvasError::api(503, "unavailable"),
+        ));
+        assert!(error
+            .message
+            .starts_with("Gave up after 3 attempts: Canvas API error: 503"));
+        let data = error.data.unwrap();
+        assert_eq!(data["status"], 503);
+        assert_eq!(data["attempts"], 3);
+
+        let error = rmcp::Error::from(CanvasError::auth("Invalid access token"));
+        assert_eq!(error.code, rmcp::model::ErrorCode::INVALID_REQUEST);
+    }
 }
@@ -0,0 +1,242 @@
+use crate::client::CanvasClient;
+use crate::error::{CanvasError, Result};
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Body of a GraphQL request
+#[derive(Debug, Serialize)]
+struct GraphQlRequest<'a> {
+    query: &'a str,
+    variables: serde_json::Value,
+}
+
+/// Body of a GraphQL response; Canvas may return partial `data` alongside `errors`
+#[derive(Debug, Deserialize)]
+struct GraphQlResponse {
+    data: Option<serde_json::Value>,
+    #[serde(default)]
+    errors: Vec<GraphQlError>,
+}
+
+#[derive(Debug, Deserialize)]
+struct GraphQlError {
+    message: String,
+    #[serde(default)]
+    path: Vec<serde_json::Value>,
+}
+
+/// One page of a GraphQL connection, as selected by
+/// `nodes { ... } pageInfo { hasNextPage endCursor }`
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Connection<T> {
+    nodes: Vec<T>,
+    page_info: PageInfo,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct PageInfo {
+    has_next_page: bool,
+    end_cursor: Option<String>,
+}
+
+impl CanvasClient {
+    /// Run a query against Canvas's GraphQL endpoint (`/api/graphql`) and
+    /// deserialize its `data`
+    ///
+    /// Nested data such as course -> assignments -> submissions comes back in
+    /// one request instead of one REST call per level. Any GraphQL `errors`
+    /// fail the call as `CanvasError::Api`, even if partial data came back.
+    ///
+    /// Queries are sent even in dry-run mode; mutations are not.
+    ///
+    /// GraphQL uses camelCase field names that the anonymizer doesn't
+    /// recognize, so queries are refused while anonymization is enabled.
+    pub async fn graphql<T: DeserializeOwned>(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+    ) -> Result<T> {
+        if self.anonymizer().is_some() {
+            return Err(CanvasError::config(
+                "GraphQL queries are disabled while ENABLE_DATA_ANONYMIZATION is on, since their responses can't be anonymized",
+            ));
+        }
+
+        let url = Url::parse(self.base_url())
+            .and_then(|base| base.join("/api/graphql"))
+            .map_err(|e| CanvasError::config(format!("Invalid API URL: {}", e)))?;
+        let response: GraphQlResponse = self
+            .post(url.as_str(), &GraphQlRequest { query, variables })
+            .await?;
+
+        if !response.errors.is_empty() {
+            let message = response
+                .errors
+                .iter()
+                .map(|error| match error.path.as_slice() {
+                    [] => error.message.clone(),
+                    path => format!(
+                        "{} (at {})",
+                        error.message,
+                        path.iter()
+                            .map(|segment| segment.to_string().trim_matches('"').to_string())
+                            .collect::<Vec<_>>()
+                            .join(".")
+                    ),
+                })
+                .collect::<Vec<_>>()
+                .join("; ");
+            return Err(CanvasError::api(200, format!("GraphQL error: {}", message)));
+        }
+
+        let data = response
+            .data
+            .ok_or_else(|| CanvasError::internal("GraphQL response had neither data nor errors"))?;
+        serde_json::from_value(data)
+            .map_err(|e| CanvasError::internal(format!("Failed to parse GraphQL data: {}", e)))
+    }
+
+    /// Collect every node of a GraphQL connection by following its cursor
+    ///
+    /// The query must declare an `$after: String` variable, pass it to the
+    /// connection, and select `nodes` and `pageInfo { hasNextPage endCursor }`.
+    /// `connection` picks the connection out of each response's `data`, e.g.
+    /// `|data| data.get("course")?.get("submissionsConnection")`. Pages are
+    /// fetched until `hasNextPage` is false, up to `CANVAS_MAX_PAGES`.
+    pub async fn graphql_paginate<T, F>(
+        &self,
+        query: &str,
+        variables: serde_json::Value,
+        connection: F,
+    ) -> Result<Vec<T>>
+    where
+        T: DeserializeOwned,
+        F: Fn(&serde_json::Value) -> Option<&serde_json::Value>,
+    {
+        let serde_json::Value::Object(mut variables) = variables else {
+            return Err(CanvasError::InvalidParameter(
+                "GraphQL variables must be a JSON object".to_string(),
+            ));
+        };
+
+        let mut nodes = Vec::new();
+        let mut after: Option<String> = None;
+        for _ in 0..self.max_pages() {
+            variables.insert("after".to_string(), serde_json::json!(after));
+            let data: serde_json::Value = self
+                .graphql(query, serde_json::Value::Object(variables.clone()))
+                .await?;
+            let page = connection(&data).ok_or_else(|| {
+                CanvasError::internal("GraphQL response did not contain the expected connection")
+            })?;
+            let page: Connection<T> = serde_json::from_value(page.clone()).map_err(|e| {
+                CanvasError::internal(format!("Failed to parse GraphQL connection: {}", e))
+            })?;
+
+            nodes.extend(page.nodes);
+            match page.page_info.end_cursor {
+                Some(cursor) if page.page_info.has_next_page && after.as_ref() != Some(&cursor) => {
+                    after = Some(cursor)
+                }
+                _ => return Ok(nodes),
+            }
+        }
+
+        Err(CanvasError::internal(format!(
+            "GraphQL pagination exceeded the maximum of {} pages",
+            self.max_pages()
+        )))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_graphql_returns_data_and_surfaces_errors() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/api/graphql")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({"variables": {"id": "1"}}),
+            ))
+            .with_body(r#"{"data": {"course": {"name": "Biology"}}}"#)
+            .create_async()
+            .await;
+        server
+            .mock("POST", "/api/graphql")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({"variables": {"id": "2"}}),
+            ))
+            .with_body(
+                r#"{"data": null, "errors": [{"message": "not found", "path": ["course"]}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let client = CanvasClient::new(config).unwrap();
+        let query = "query($id: ID!) { course(id: $id) { name } }";
+
+        let data: serde_json::Value = client
+            .graphql(query, serde_json::json!({"id": "1"}))
+            .await
+            .unwrap();
+        assert_eq!(data["course"]["name"], "Biology");
+
+        let error = client
+            .graphql::<serde_json::Value>(query, serde_json::json!({"id": "2"}))
+            .await
+            .unwrap_err();
+        assert!(matches!(error, CanvasError::Api { .. }));
+        assert!(error.to_string().contains("not found (at course)"));
+    }
+
+    #[tokio::test]
+    async fn test_graphql_paginate_follows_cursor() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("POST", "/api/graphql")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({"variables": {"after": null}}),
+            ))
+            .with_body(
+                r#"{"data": {"course": {"usersConnection": {
+                    "nodes": [{"name": "Ada"}, {"name": "Grace"}],
+                    "pageInfo": {"hasNextPage": true, "endCursor": "MQ"}}}}}"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("POST", "/api/graphql")
+            .match_body(mockito::Matcher::PartialJson(
+                serde_json::json!({"variables": {"id": "1", "after": "MQ"}}),
+            ))
+            .with_body(
+                r#"{"data": {"course": {"usersConnection": {
+                    "nodes": [{"name": "Alan"}],
+                    "pageInfo": {"hasNextPage": false, "endCursor": "Mg"}}}}}"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let client = CanvasClient::new(config).unwrap();
+        let query = "query($id: ID!, $after: String) { course(id: $id) { usersConnection(after: $after) { nodes { name } pageInfo { hasNextPage endCursor } } } }";
+
+        let users: Vec<serde_json::Value> = client
+            .graphql_paginate(query, serde_json::json!({"id": "1"}), |data| {
+                data.get("course")?.get("usersConnection")
+            })
+            .await
+            .unwrap();
+        let names: Vec<&str> = users.iter().filter_map(|u| u["name"].as_str()).collect();
+        assert_eq!(names, ["Ada", "Grace", "Alan"]);
+    }
+}
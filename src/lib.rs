@@ -1,12 +1,32 @@
 /// Canvas MCP Server Library
 ///
 /// This library provides the core functionality for the Canvas MCP server,
-/// including configuration, HTTP client, and Canvas API integrations.
+/// including configuration, HTTP client, Canvas API integrations, and the
+/// MCP tools built on top of them.
+pub mod anonymize;
+pub mod backend;
+pub mod cache;
 pub mod client;
 pub mod config;
+pub mod datetime;
+pub mod dry_run;
 pub mod error;
+pub mod graphql;
+pub mod logging;
+pub mod metrics;
+pub mod models;
+pub mod prompts;
+pub mod rate_limit;
+pub mod redact;
+pub mod render;
+pub mod resources;
+pub mod retry;
+pub mod server;
+pub mod tools;
 
 // Re-export commonly used types
-pub use client::CanvasClient;
+pub use client::{CanvasClient, QueryBuilder, UserBatch};
 pub use config::CanvasConfig;
 pub use error::{CanvasError, Result};
+pub use models::{Assignment, Course, Enrollment, FlexibleId, SisKind, User};
+pub use server::CanvasServer;
@@ -0,0 +1,46 @@
+use crate::config::CanvasConfig;
+use crate::error::{CanvasError, Result};
+use std::env;
+use std::path::PathBuf;
+use tracing_appender::non_blocking::WorkerGuard;
+
+/// Install the global tracing subscriber
+///
+/// Logs go to daily-rotated files under `~/.canvas-mcp/logs`, never to
+/// stdout (the JSON-RPC stream) or stderr (which some MCP clients surface as
+/// errors). Warnings about the loaded config are logged once the
+/// subscriber is up. Keep the returned guard alive for the life of the process so
+/// buffered lines are flushed on exit.
+pub fn setup_logging(config: &CanvasConfig) -> Result<WorkerGuard> {
+    let dir = log_dir();
+    std::fs::create_dir_all(&dir).map_err(|e| {
+        CanvasError::config(format!(
+            "Failed to create log directory {}: {}",
+            dir.display(),
+            e
+        ))
+    })?;
+
+    let appender = tracing_appender::rolling::daily(&dir, "canvas-mcp.log");
+    let (writer, guard) = tracing_appender::non_blocking(appender);
+
+    tracing_subscriber::fmt()
+        .with_writer(writer)
+        .with_max_level(config.log_level)
+        .with_ansi(false)
+        .try_init()
+        .map_err(|e| CanvasError::config(format!("Failed to initialize logging: {}", e)))?;
+    // The config was loaded before there was a subscriber to log to
+    config.log_api_url_warning();
+
+    Ok(guard)
+}
+
+/// Directory log files are written to
+fn log_dir() -> PathBuf {
+    env::var_os("HOME")
+        .map(PathBuf::from)
+        .unwrap_or_else(env::temp_dir)
+        .join(".canvas-mcp")
+        .join("logs")
+}
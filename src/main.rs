@@ -1,4 +1,7 @@
-use rust_canvas_mcp::{CanvasClient, CanvasConfig};
+use rmcp::transport::stdio;
+use rmcp::ServiceExt;
+use rust_canvas_mcp::logging::setup_logging;
+use rust_canvas_mcp::{CanvasConfig, CanvasServer};
 use std::env;
 use std::sync::Arc;
 
@@ -10,18 +13,19 @@ async fn main() -> anyhow::Result<()> {
         return run_connection_test().await;
     }
 
-    println!("Rust Canvas MCP Server");
-    println!("Version: {}", env!("CARGO_PKG_VERSION"));
-    println!();
-    println!("This is a Phase 2 implementation demonstrating:");
-    println!("- Configuration loading from environment");
-    println!("- Canvas API HTTP client");
-    println!("- Connection testing");
-    println!();
-    println!("Usage:");
-    println!("  cargo run -- --test    Test Canvas API connection");
-    println!();
-    println!("Full MCP server implementation coming in Phase 2 (Issue #4)");
+    run_server().await
+}
+
+/// Run the MCP server over stdio
+///
+/// stdout carries the JSON-RPC stream, so nothing else may be printed to it.
+async fn run_server() -> anyhow::Result<()> {
+    let config = CanvasConfig::from_env()?;
+    let _log_guard = setup_logging(&config)?;
+    let server = CanvasServer::new(Arc::new(config))?;
+
+    let service = server.serve(stdio()).await?;
+    service.waiting().await?;
 
     Ok(())
 }
@@ -39,6 +43,7 @@ async fn run_connection_test() -> anyhow::Result<()> {
                 println!("  Institution: {}", inst);
             }
             println!("  API URL: {}", cfg.api_url);
+            println!("  Log level: {}", cfg.log_level);
             cfg
         }
         Err(e) => {
@@ -51,31 +56,20 @@ async fn run_connection_test() -> anyhow::Result<()> {
         }
     };
 
-    // Create HTTP client
-    let client = match CanvasClient::new(Arc::new(config)) {
-        Ok(c) => {
-            println!("✓ HTTP client created");
-            c
-        }
+    let _log_guard = match setup_logging(&config) {
+        Ok(guard) => Some(guard),
         Err(e) => {
-            eprintln!("✗ Failed to create HTTP client: {}", e);
-            std::process::exit(1);
+            eprintln!("⚠ Logging disabled: {}", e);
+            None
         }
     };
 
-    // Test connection by fetching current user
+    // Create the server, checking the token against Canvas and fetching the user
     print!("Testing API connection... ");
-    match client.get_current_user().await {
-        Ok(user) => {
+    let (_server, user) = match CanvasServer::new_validated(Arc::new(config)).await {
+        Ok(validated) => {
             println!("✓");
-            if let Some(name) = user.get("name").and_then(|v| v.as_str()) {
-                println!("✓ Connected as: {}", name);
-            }
-            if let Some(id) = user.get("id").and_then(|v| v.as_u64()) {
-                println!("  User ID: {}", id);
-            }
-            println!();
-            println!("✓ All tests passed!");
+            validated
         }
         Err(e) => {
             println!("✗");
@@ -87,7 +81,12 @@ async fn run_connection_test() -> anyhow::Result<()> {
             eprintln!("  - You have network access to Canvas");
             std::process::exit(1);
         }
-    }
+    };
+
+    println!("✓ Connected as: {}", user.name);
+    println!("  User ID: {}", user.id);
+    println!();
+    println!("✓ All tests passed!");
 
     Ok(())
 }
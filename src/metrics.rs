@@ -0,0 +1,132 @@
+use serde::Serialize;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::Duration;
+
+/// Upper bounds of the latency histogram buckets, in milliseconds; a final
+/// overflow bucket catches anything slower
+pub const LATENCY_BUCKETS_MS: [u64; 7] = [50, 100, 250, 500, 1000, 2500, 5000];
+
+/// Counters for Canvas API traffic, shared by every clone of a client
+#[derive(Debug, Default)]
+pub struct Metrics {
+    requests: AtomicU64,
+    retries: AtomicU64,
+    /// 1xx..5xx responses, indexed by status class minus one
+    status_classes: [AtomicU64; 5],
+    /// Requests that failed without a response (timeouts, connection errors)
+    network_errors: AtomicU64,
+    latency: [AtomicU64; LATENCY_BUCKETS_MS.len() + 1],
+    total_latency_ms: AtomicU64,
+    /// GETs answered from the response cache, including 304 revalidations
+    cache_hits: AtomicU64,
+}
+
+/// Point-in-time copy of the metrics
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MetricsSnapshot {
+    pub requests: u64,
+    pub retries: u64,
+    pub status_1xx: u64,
+    pub status_2xx: u64,
+    pub status_3xx: u64,
+    pub status_4xx: u64,
+    pub status_5xx: u64,
+    pub network_errors: u64,
+    pub cache_hits: u64,
+    /// Request counts per latency bucket, labelled by upper bound
+    /// (`"<=100ms"`, ..., `">5000ms"`)
+    pub latency_histogram: Vec<(String, u64)>,
+    pub average_latency_ms: Option<f64>,
+}
+
+impl Metrics {
+    /// Record one HTTP attempt; `status` is `None` if no response arrived
+    pub fn record(&self, status: Option<u16>, latency: Duration) {
+        self.requests.fetch_add(1, Ordering::Relaxed);
+        match status {
+            Some(status @ 100..=599) => {
+                self.status_classes[(status / 100 - 1) as usize].fetch_add(1, Ordering::Relaxed);
+            }
+            Some(_) => {}
+            None => {
+                self.network_errors.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let ms = latency.as_millis() as u64;
+        let bucket = LATENCY_BUCKETS_MS
+            .iter()
+            .position(|bound| ms <= *bound)
+            .unwrap_or(LATENCY_BUCKETS_MS.len());
+        self.latency[bucket].fetch_add(1, Ordering::Relaxed);
+        self.total_latency_ms.fetch_add(ms, Ordering::Relaxed);
+    }
+
+    /// Record that a request is being retried
+    pub fn record_retry(&self) {
+        self.retries.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a GET served from the response cache
+    pub fn record_cache_hit(&self) {
+        self.cache_hits.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Copy the current counters
+    pub fn snapshot(&self) -> MetricsSnapshot {
+        let load = |counter: &AtomicU64| counter.load(Ordering::Relaxed);
+        let requests = load(&self.requests);
+
+        let latency_histogram = self
+            .latency
+            .iter()
+            .enumerate()
+            .map(|(i, count)| {
+                let label = match LATENCY_BUCKETS_MS.get(i) {
+                    Some(bound) => format!("<={}ms", bound),
+                    None => format!(">{}ms", LATENCY_BUCKETS_MS[LATENCY_BUCKETS_MS.len() - 1]),
+                };
+                (label, load(count))
+            })
+            .collect();
+
+        MetricsSnapshot {
+            requests,
+            retries: load(&self.retries),
+            status_1xx: load(&self.status_classes[0]),
+            status_2xx: load(&self.status_classes[1]),
+            status_3xx: load(&self.status_classes[2]),
+            status_4xx: load(&self.status_classes[3]),
+            status_5xx: load(&self.status_classes[4]),
+            network_errors: load(&self.network_errors),
+            cache_hits: load(&self.cache_hits),
+            latency_histogram,
+            average_latency_ms: (requests > 0)
+                .then(|| load(&self.total_latency_ms) as f64 / requests as f64),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record_and_snapshot() {
+        let metrics = Metrics::default();
+        metrics.record(Some(200), Duration::from_millis(40));
+        metrics.record(Some(503), Duration::from_millis(300));
+        metrics.record(None, Duration::from_secs(30));
+        metrics.record_retry();
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.requests, 3);
+        assert_eq!(snapshot.retries, 1);
+        assert_eq!(snapshot.status_2xx, 1);
+        assert_eq!(snapshot.status_5xx, 1);
+        assert_eq!(snapshot.network_errors, 1);
+        assert_eq!(snapshot.latency_histogram[0], ("<=50ms".to_string(), 1));
+        assert_eq!(snapshot.latency_histogram[3], ("<=500ms".to_string(), 1));
+        assert_eq!(snapshot.latency_histogram[7], (">5000ms".to_string(), 1));
+    }
+}
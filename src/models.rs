@@ -0,0 +1,1211 @@
+use chrono::{DateTime, Utc};
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
+use rmcp::schemars;
+use serde::{Deserialize, Deserializer, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Canvas object identifier
+///
+/// Canvas is inconsistent about ID types: most endpoints return integers, but
+/// SIS contexts and some newer APIs return strings. This accepts either.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, schemars::JsonSchema)]
+#[serde(untagged)]
+pub enum FlexibleId {
+    Numeric(u64),
+    Text(String),
+}
+
+impl FlexibleId {
+    /// Numeric value of the ID, if it is (or parses as) an integer
+    pub fn as_u64(&self) -> Option<u64> {
+        match self {
+            Self::Numeric(id) => Some(*id),
+            Self::Text(id) => id.parse().ok(),
+        }
+    }
+
+    /// Whether this looks like something Canvas accepts in a path: a numeric
+    /// ID or a reference with a known prefix, such as `sis_course_id:BIOL101`
+    pub fn is_valid(&self) -> bool {
+        match self {
+            Self::Numeric(_) => true,
+            Self::Text(id) => {
+                let id = id.trim();
+                if !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()) {
+                    return true;
+                }
+                match id.split_once(':') {
+                    Some((prefix, value)) => {
+                        !value.is_empty()
+                            && (SisKind::ALL.iter().any(|kind| kind.prefix() == prefix)
+                                || prefix == "lti_context_id"
+                                || prefix == "uuid")
+                    }
+                    None => false,
+                }
+            }
+        }
+    }
+}
+
+impl FlexibleId {
+    /// SIS-prefixed reference, e.g. `sis_course_id:BIOL101`
+    pub fn sis(kind: SisKind, sis_id: &str) -> Self {
+        Self::Text(format!("{}:{}", kind.prefix(), sis_id))
+    }
+
+    /// The ID encoded for use as a URL path segment
+    ///
+    /// Text IDs are percent-encoded so SIS IDs containing slashes, spaces, or
+    /// other reserved characters reach Canvas intact; the `prefix:` colon is
+    /// kept as Canvas expects.
+    pub fn path_segment(&self) -> String {
+        match self {
+            Self::Numeric(id) => id.to_string(),
+            Self::Text(id) => encode_path_segment(id.trim()),
+        }
+    }
+}
+
+/// Percent-encode a string (an SIS ID, a page slug, ...) for use as a URL path segment
+pub fn encode_path_segment(segment: &str) -> String {
+    utf8_percent_encode(segment, PATH_SEGMENT).to_string()
+}
+
+/// Characters escaped in path segments: everything but unreserved characters
+/// and the colon separating an SIS prefix from its value
+const PATH_SEGMENT: &AsciiSet = &NON_ALPHANUMERIC
+    .remove(b'-')
+    .remove(b'_')
+    .remove(b'.')
+    .remove(b'~')
+    .remove(b':');
+
+/// Deserialize a number Canvas may send as an integer, a float, a string
+/// (`"10"`, `"7.5"`), or null
+///
+/// Empty and non-numeric strings become `None` rather than failing the
+/// whole object.
+pub fn deserialize_flexible_f64<'de, D>(deserializer: D) -> Result<Option<f64>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Flexible {
+        Number(f64),
+        Text(String),
+    }
+
+    Ok(match Option::<Flexible>::deserialize(deserializer)? {
+        Some(Flexible::Number(n)) => Some(n),
+        Some(Flexible::Text(text)) => text.trim().parse().ok().filter(|n: &f64| n.is_finite()),
+        None => None,
+    })
+}
+
+/// Kinds of object Canvas can look up by SIS ID
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SisKind {
+    Course,
+    User,
+    Section,
+    Account,
+}
+
+impl SisKind {
+    /// Every kind, e.g. for recognizing SIS prefixes
+    pub const ALL: [SisKind; 4] = [Self::Course, Self::User, Self::Section, Self::Account];
+
+    /// ID prefix Canvas uses for this kind, e.g. `sis_course_id`
+    pub fn prefix(self) -> &'static str {
+        match self {
+            Self::Course => "sis_course_id",
+            Self::User => "sis_user_id",
+            Self::Section => "sis_section_id",
+            Self::Account => "sis_account_id",
+        }
+    }
+
+    /// API collection the object lives under, e.g. `/courses`
+    pub fn collection(self) -> &'static str {
+        match self {
+            Self::Course => "/courses",
+            Self::User => "/users",
+            Self::Section => "/sections",
+            Self::Account => "/accounts",
+        }
+    }
+}
+
+impl fmt::Display for FlexibleId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Numeric(id) => write!(f, "{}", id),
+            Self::Text(id) => f.write_str(id),
+        }
+    }
+}
+
+impl From<u64> for FlexibleId {
+    fn from(id: u64) -> Self {
+        Self::Numeric(id)
+    }
+}
+
+impl From<String> for FlexibleId {
+    fn from(id: String) -> Self {
+        Self::Text(id)
+    }
+}
+
+impl From<&str> for FlexibleId {
+    fn from(id: &str) -> Self {
+        Self::Text(id.to_string())
+    }
+}
+
+/// Canvas course
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Course {
+    pub id: FlexibleId,
+
+    /// Missing for courses the user can no longer access by date
+    #[serde(default)]
+    pub name: String,
+
+    pub course_code: Option<String>,
+
+    /// `unpublished`, `available`, `completed`, or `deleted`
+    pub workflow_state: Option<String>,
+
+    pub start_at: Option<DateTime<Utc>>,
+
+    pub end_at: Option<DateTime<Utc>>,
+
+    pub enrollment_term_id: Option<FlexibleId>,
+
+    /// Present when requested with `include[]=term`
+    pub term: Option<EnrollmentTerm>,
+
+    /// HTML syllabus, present when requested with `include[]=syllabus_body`
+    pub syllabus_body: Option<String>,
+
+    /// Whether this is a blueprint course whose content syncs to associated courses
+    pub blueprint: Option<bool>,
+
+    /// Whether final grades weight assignment groups by `group_weight`
+    /// rather than summing points
+    pub apply_assignment_group_weights: Option<bool>,
+}
+
+/// Canvas user
+///
+/// `email` and `login_id` are only returned to callers with permission to
+/// see them, so they are often missing.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct User {
+    pub id: FlexibleId,
+
+    #[serde(default)]
+    pub name: String,
+
+    pub sortable_name: Option<String>,
+
+    pub short_name: Option<String>,
+
+    pub email: Option<String>,
+
+    pub login_id: Option<String>,
+
+    pub avatar_url: Option<String>,
+
+    /// Present when requested with `include[]=enrollments`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub enrollments: Vec<Enrollment>,
+}
+
+/// Course section
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Section {
+    pub id: FlexibleId,
+
+    #[serde(default)]
+    pub name: String,
+
+    pub sis_section_id: Option<String>,
+
+    pub course_id: Option<FlexibleId>,
+
+    /// Original course of a section cross-listed into this one
+    pub nonxlist_course_id: Option<FlexibleId>,
+
+    /// Present when requested with `include[]=total_students`
+    pub total_students: Option<u32>,
+
+    /// Present when requested with `include[]=students`; Canvas returns
+    /// `null` rather than an empty list for sections without students
+    pub students: Option<Vec<User>>,
+}
+
+/// Student group in a course
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Group {
+    pub id: FlexibleId,
+
+    #[serde(default)]
+    pub name: String,
+
+    pub group_category_id: Option<FlexibleId>,
+
+    pub members_count: Option<u32>,
+
+    /// Present when requested with `include[]=users`
+    pub users: Option<Vec<User>>,
+}
+
+/// Group set that groups belong to, e.g. "Project Teams"
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GroupCategory {
+    pub id: FlexibleId,
+
+    #[serde(default)]
+    pub name: String,
+}
+
+/// A user's enrollment in a course
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Enrollment {
+    pub id: FlexibleId,
+
+    pub user_id: Option<FlexibleId>,
+
+    pub course_id: Option<FlexibleId>,
+
+    pub course_section_id: Option<FlexibleId>,
+
+    /// `StudentEnrollment`, `TeacherEnrollment`, `TaEnrollment`, `DesignerEnrollment`,
+    /// or `ObserverEnrollment`
+    #[serde(rename = "type")]
+    pub enrollment_type: String,
+
+    /// Role name, which differs from the type for custom roles
+    pub role: Option<String>,
+
+    /// `active`, `invited`, `inactive`, `completed`, `creation_pending`, or `deleted`
+    pub enrollment_state: Option<String>,
+
+    pub last_activity_at: Option<DateTime<Utc>>,
+
+    pub user: Option<User>,
+}
+
+/// Canvas enrollment term (e.g. "Fall 2025")
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EnrollmentTerm {
+    pub id: FlexibleId,
+
+    pub name: Option<String>,
+
+    pub start_at: Option<DateTime<Utc>>,
+
+    pub end_at: Option<DateTime<Utc>>,
+
+    pub sis_term_id: Option<String>,
+
+    /// `active` or `deleted`
+    pub workflow_state: Option<String>,
+}
+
+/// Canvas assignment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Assignment {
+    pub id: FlexibleId,
+
+    #[serde(default)]
+    pub name: String,
+
+    /// HTML description
+    pub description: Option<String>,
+
+    pub due_at: Option<DateTime<Utc>>,
+
+    #[serde(default, deserialize_with = "deserialize_flexible_f64")]
+    pub points_possible: Option<f64>,
+
+    /// e.g. `online_text_entry`, `online_upload`, `none`
+    #[serde(default)]
+    pub submission_types: Vec<String>,
+
+    #[serde(default)]
+    pub published: bool,
+
+    pub assignment_group_id: Option<FlexibleId>,
+
+    pub html_url: Option<String>,
+
+    /// Number of submissions awaiting grading (visible to graders only)
+    pub needs_grading_count: Option<u64>,
+
+    /// The current user's submission, when requested with `include[]=submission`
+    pub submission: Option<Submission>,
+
+    /// Rubric criteria, when the assignment has a rubric attached
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub rubric: Vec<RubricCriterion>,
+
+    /// Per-student, per-section, and per-group date overrides, when requested
+    /// with `include[]=overrides`
+    #[serde(default)]
+    pub overrides: Vec<AssignmentOverride>,
+}
+
+impl Assignment {
+    /// Due date for a student, honoring any override that applies to them
+    ///
+    /// An override naming the student wins; otherwise, as in Canvas, the
+    /// latest due date among the student's sections' overrides applies
+    /// (an undated override counts as latest). Falls back to `due_at`.
+    pub fn due_at_for(
+        &self,
+        user_id: &FlexibleId,
+        section_ids: &[FlexibleId],
+    ) -> Option<DateTime<Utc>> {
+        if let Some(student) = self
+            .overrides
+            .iter()
+            .find(|o| o.student_ids.contains(user_id))
+        {
+            return student.due_at;
+        }
+        let sections: Vec<Option<DateTime<Utc>>> = self
+            .overrides
+            .iter()
+            .filter(|o| {
+                o.course_section_id
+                    .as_ref()
+                    .is_some_and(|id| section_ids.contains(id))
+            })
+            .map(|o| o.due_at)
+            .collect();
+        if sections.is_empty() {
+            return self.due_at;
+        }
+        if sections.contains(&None) {
+            return None;
+        }
+        sections.into_iter().flatten().max()
+    }
+}
+
+/// Grade category such as "Homework" or "Exams"
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssignmentGroup {
+    pub id: FlexibleId,
+
+    #[serde(default)]
+    pub name: String,
+
+    pub position: Option<u32>,
+
+    /// Percentage of the final grade, when the course weights groups
+    pub group_weight: Option<f64>,
+
+    pub rules: Option<AssignmentGroupRules>,
+
+    /// Present when requested with `include[]=assignments`
+    #[serde(default)]
+    pub assignments: Vec<Assignment>,
+}
+
+/// Drop rules applied to an assignment group's scores
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct AssignmentGroupRules {
+    pub drop_lowest: Option<u32>,
+
+    pub drop_highest: Option<u32>,
+
+    /// Assignment IDs that are never dropped
+    #[serde(default)]
+    pub never_drop: Vec<FlexibleId>,
+}
+
+/// Dates that replace an assignment's own for some students, a section, or a group
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssignmentOverride {
+    pub id: FlexibleId,
+
+    pub assignment_id: Option<FlexibleId>,
+
+    /// e.g. "Section 2" or "2 students"
+    pub title: Option<String>,
+
+    /// Students the override applies to, for an adhoc override
+    #[serde(default)]
+    pub student_ids: Vec<FlexibleId>,
+
+    pub course_section_id: Option<FlexibleId>,
+
+    pub group_id: Option<FlexibleId>,
+
+    pub due_at: Option<DateTime<Utc>>,
+
+    pub unlock_at: Option<DateTime<Utc>>,
+
+    pub lock_at: Option<DateTime<Utc>>,
+}
+
+/// Canvas rubric
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Rubric {
+    pub id: FlexibleId,
+
+    #[serde(default)]
+    pub title: String,
+
+    /// ID of the course or account that owns the rubric
+    pub context_id: Option<FlexibleId>,
+
+    /// `Course` or `Account`
+    pub context_type: Option<String>,
+
+    pub points_possible: Option<f64>,
+
+    /// Whether graders write comments instead of picking ratings
+    #[serde(default)]
+    pub free_form_criterion_comments: bool,
+
+    /// Criteria, which Canvas calls `data` on rubric objects
+    #[serde(default, alias = "data")]
+    pub criteria: Vec<RubricCriterion>,
+
+    /// Completed assessments, when requested with `include[]=assessments`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub assessments: Vec<RubricAssessment>,
+}
+
+/// One row of a rubric
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RubricCriterion {
+    /// Criterion ID, e.g. `_1234`
+    pub id: String,
+
+    #[serde(default)]
+    pub description: String,
+
+    pub long_description: Option<String>,
+
+    /// Maximum points for the criterion
+    #[serde(default)]
+    pub points: f64,
+
+    #[serde(default)]
+    pub ratings: Vec<RubricRating>,
+}
+
+/// A rating level within a rubric criterion
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RubricRating {
+    pub id: Option<String>,
+
+    #[serde(default)]
+    pub description: String,
+
+    pub long_description: Option<String>,
+
+    #[serde(default)]
+    pub points: f64,
+}
+
+/// A completed rubric assessment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RubricAssessment {
+    pub id: FlexibleId,
+
+    pub score: Option<f64>,
+
+    /// e.g. `Submission`
+    pub artifact_type: Option<String>,
+
+    pub artifact_id: Option<FlexibleId>,
+
+    pub assessor_id: Option<FlexibleId>,
+
+    /// Per-criterion points and comments
+    #[serde(default)]
+    pub data: Vec<RubricAssessmentRow>,
+}
+
+/// Points and comments given for one criterion in an assessment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RubricAssessmentRow {
+    pub criterion_id: Option<String>,
+
+    pub points: Option<f64>,
+
+    pub comments: Option<String>,
+}
+
+/// Canvas submission for an assignment
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Submission {
+    pub id: Option<FlexibleId>,
+
+    pub user_id: Option<FlexibleId>,
+
+    pub assignment_id: Option<FlexibleId>,
+
+    /// `unsubmitted`, `submitted`, `graded`, or `pending_review`
+    pub workflow_state: Option<String>,
+
+    /// How the latest attempt was submitted, e.g. `online_text_entry`
+    pub submission_type: Option<String>,
+
+    /// Attempt number of the latest submission (1 for the first)
+    pub attempt: Option<u32>,
+
+    pub score: Option<f64>,
+
+    pub grade: Option<String>,
+
+    pub submitted_at: Option<DateTime<Utc>>,
+
+    #[serde(default)]
+    pub late: bool,
+
+    #[serde(default)]
+    pub missing: bool,
+
+    pub excused: Option<bool>,
+
+    /// Due date for this student, reflecting any override that applies to them
+    pub cached_due_date: Option<DateTime<Utc>>,
+
+    /// Points taken off by the course's late policy
+    pub points_deducted: Option<f64>,
+
+    /// Text of an `online_text_entry` submission (HTML)
+    pub body: Option<String>,
+
+    /// Link of an `online_url` submission
+    pub url: Option<String>,
+
+    /// Comment thread, when requested with `include[]=submission_comments`
+    /// or returned after posting a comment
+    #[serde(default)]
+    pub submission_comments: Vec<SubmissionComment>,
+
+    /// Every attempt, oldest first, when requested with
+    /// `include[]=submission_history`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub submission_history: Vec<Submission>,
+
+    /// Points and comments keyed by rubric criterion ID, when requested with
+    /// `include[]=rubric_assessment`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rubric_assessment: Option<BTreeMap<String, RubricAssessmentRow>>,
+}
+
+/// A comment on a submission
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubmissionComment {
+    pub id: FlexibleId,
+
+    pub author_id: Option<FlexibleId>,
+
+    pub author_name: Option<String>,
+
+    #[serde(default)]
+    pub comment: String,
+
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// Page views and participations for one day of course activity analytics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ActivityDay {
+    /// Day the counts cover, e.g. `2025-09-01`
+    pub date: String,
+
+    #[serde(default)]
+    pub views: u64,
+
+    #[serde(default)]
+    pub participations: u64,
+}
+
+/// Score distribution and submission timing for one assignment, from course analytics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssignmentAnalytics {
+    pub assignment_id: FlexibleId,
+
+    #[serde(default)]
+    pub title: String,
+
+    pub points_possible: Option<f64>,
+
+    pub due_at: Option<DateTime<Utc>>,
+
+    pub min_score: Option<f64>,
+
+    pub max_score: Option<f64>,
+
+    pub median: Option<f64>,
+
+    pub first_quartile: Option<f64>,
+
+    pub third_quartile: Option<f64>,
+
+    pub tardiness_breakdown: Option<TardinessBreakdown>,
+}
+
+/// One assignment from a student's analytics, compared with the class
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudentAssignmentAnalytics {
+    pub assignment_id: FlexibleId,
+
+    #[serde(default)]
+    pub title: String,
+
+    pub points_possible: Option<f64>,
+
+    pub due_at: Option<DateTime<Utc>>,
+
+    /// Class median score
+    pub median: Option<f64>,
+
+    /// `on_time`, `late`, `missing`, or `floating` (not yet due, not submitted)
+    pub status: Option<String>,
+
+    pub submission: Option<StudentAnalyticsSubmission>,
+}
+
+/// The student's submission as reported by analytics
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StudentAnalyticsSubmission {
+    pub submitted_at: Option<DateTime<Utc>>,
+
+    pub score: Option<f64>,
+}
+
+/// Share of students who submitted on time, late, or not at all
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TardinessBreakdown {
+    #[serde(default)]
+    pub on_time: f64,
+
+    #[serde(default)]
+    pub late: f64,
+
+    #[serde(default)]
+    pub missing: f64,
+}
+
+/// Item on the current user's to-do list
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TodoItem {
+    /// `submitting` for students, `grading` for instructors
+    #[serde(rename = "type")]
+    pub todo_type: String,
+
+    pub course_id: Option<FlexibleId>,
+
+    /// Name of the course (or group) the item belongs to
+    pub context_name: Option<String>,
+
+    pub assignment: Option<Assignment>,
+
+    pub quiz: Option<Quiz>,
+
+    pub needs_grading_count: Option<u64>,
+
+    pub html_url: Option<String>,
+}
+
+impl TodoItem {
+    /// Title of the assignment or quiz
+    pub fn title(&self) -> &str {
+        self.assignment
+            .as_ref()
+            .map(|a| a.name.as_str())
+            .or_else(|| self.quiz.as_ref().map(|q| q.title.as_str()))
+            .unwrap_or("Untitled")
+    }
+
+    pub fn due_at(&self) -> Option<DateTime<Utc>> {
+        self.assignment
+            .as_ref()
+            .and_then(|a| a.due_at)
+            .or_else(|| self.quiz.as_ref().and_then(|q| q.due_at))
+    }
+}
+
+/// Canvas progress object for asynchronous jobs (bulk grading, migrations, ...)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Progress {
+    pub id: FlexibleId,
+
+    /// `queued`, `running`, `completed`, or `failed`
+    pub workflow_state: String,
+
+    /// Percent complete, 0-100
+    pub completion: Option<f64>,
+
+    pub message: Option<String>,
+
+    /// API URL to poll for updates
+    pub url: Option<String>,
+}
+
+impl Progress {
+    /// Whether the job has finished, successfully or not
+    pub fn is_finished(&self) -> bool {
+        matches!(self.workflow_state.as_str(), "completed" | "failed")
+    }
+}
+
+/// Canvas classic quiz
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Quiz {
+    pub id: FlexibleId,
+
+    #[serde(default)]
+    pub title: String,
+
+    /// `practice_quiz`, `assignment`, `graded_survey`, or `survey`
+    pub quiz_type: Option<String>,
+
+    /// HTML instructions
+    pub description: Option<String>,
+
+    pub points_possible: Option<f64>,
+
+    /// Time limit in minutes
+    pub time_limit: Option<u32>,
+
+    /// Attempts allowed; -1 means unlimited
+    pub allowed_attempts: Option<i32>,
+
+    pub question_count: Option<u32>,
+
+    pub due_at: Option<DateTime<Utc>>,
+
+    pub unlock_at: Option<DateTime<Utc>>,
+
+    pub lock_at: Option<DateTime<Utc>>,
+
+    pub published: Option<bool>,
+
+    pub html_url: Option<String>,
+}
+
+/// A question in a classic quiz
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QuizQuestion {
+    pub id: FlexibleId,
+
+    pub position: Option<u32>,
+
+    pub question_name: Option<String>,
+
+    /// e.g. `multiple_choice_question`, `essay_question`, `numerical_question`
+    pub question_type: Option<String>,
+
+    /// HTML question stem
+    pub question_text: Option<String>,
+
+    pub points_possible: Option<f64>,
+}
+
+/// File stored in Canvas
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CanvasFile {
+    pub id: FlexibleId,
+
+    /// Name shown in the Canvas UI
+    #[serde(default)]
+    pub display_name: String,
+
+    /// Name the file was uploaded with
+    pub filename: Option<String>,
+
+    /// Size in bytes
+    pub size: Option<u64>,
+
+    #[serde(rename = "content-type")]
+    pub content_type: Option<String>,
+
+    /// Download URL; redirects to a short-lived CDN link
+    pub url: Option<String>,
+
+    pub folder_id: Option<FlexibleId>,
+
+    pub updated_at: Option<DateTime<Utc>>,
+
+    /// Locked for the current user (e.g. by a module requirement or date)
+    pub locked_for_user: Option<bool>,
+}
+
+/// Folder in a course's file area
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Folder {
+    pub id: FlexibleId,
+
+    #[serde(default)]
+    pub name: String,
+
+    /// Path from the root, e.g. `course files/Lectures/Week 1`
+    pub full_name: Option<String>,
+
+    pub parent_folder_id: Option<FlexibleId>,
+
+    pub files_count: Option<u32>,
+
+    pub folders_count: Option<u32>,
+}
+
+/// Event on a Canvas calendar
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CalendarEvent {
+    pub id: FlexibleId,
+
+    #[serde(default)]
+    pub title: String,
+
+    pub start_at: Option<DateTime<Utc>>,
+
+    pub end_at: Option<DateTime<Utc>>,
+
+    #[serde(default)]
+    pub all_day: bool,
+
+    /// HTML description
+    pub description: Option<String>,
+
+    pub location_name: Option<String>,
+
+    pub location_address: Option<String>,
+
+    /// Calendar the event belongs to, e.g. `course_123`
+    pub context_code: Option<String>,
+
+    pub html_url: Option<String>,
+}
+
+/// Canvas inbox conversation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Conversation {
+    pub id: FlexibleId,
+
+    pub subject: Option<String>,
+
+    /// `read`, `unread`, or `archived` for the current user
+    pub workflow_state: Option<String>,
+
+    /// Plain-text snippet of the latest message
+    pub last_message: Option<String>,
+
+    pub last_message_at: Option<DateTime<Utc>>,
+
+    pub message_count: Option<u32>,
+
+    #[serde(default)]
+    pub starred: bool,
+
+    /// Everyone in the conversation, including the current user
+    #[serde(default)]
+    pub participants: Vec<ConversationParticipant>,
+}
+
+/// A participant as listed on a conversation
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ConversationParticipant {
+    pub id: FlexibleId,
+
+    #[serde(default)]
+    pub name: String,
+
+    pub full_name: Option<String>,
+}
+
+/// Canvas wiki page
+///
+/// Pages are addressed by their `url` slug rather than a numeric ID.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Page {
+    pub page_id: Option<FlexibleId>,
+
+    /// Slug used in page URLs, e.g. `week-1-overview`
+    pub url: String,
+
+    #[serde(default)]
+    pub title: String,
+
+    /// HTML body; omitted from page listings
+    pub body: Option<String>,
+
+    pub published: Option<bool>,
+
+    /// Whether this is the course's front page
+    #[serde(default)]
+    pub front_page: bool,
+
+    pub updated_at: Option<DateTime<Utc>>,
+
+    pub html_url: Option<String>,
+}
+
+/// Canvas discussion topic or announcement
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscussionTopic {
+    pub id: FlexibleId,
+
+    #[serde(default)]
+    pub title: String,
+
+    /// HTML body of the opening post
+    pub message: Option<String>,
+
+    pub posted_at: Option<DateTime<Utc>>,
+
+    /// Scheduled publish time for delayed posts
+    pub delayed_post_at: Option<DateTime<Utc>>,
+
+    pub published: Option<bool>,
+
+    /// Number of entries, including replies
+    pub discussion_subentry_count: Option<u32>,
+
+    pub html_url: Option<String>,
+
+    /// Display name of the poster
+    pub user_name: Option<String>,
+
+    /// The poster, whose ID the anonymizer uses to pseudonymize `user_name`
+    pub author: Option<DiscussionAuthor>,
+}
+
+/// Poster of a discussion topic as Canvas embeds it; empty for anonymous
+/// discussions
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscussionAuthor {
+    pub id: Option<FlexibleId>,
+
+    pub display_name: Option<String>,
+}
+
+/// A post (or threaded reply) in a Canvas discussion topic
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DiscussionEntry {
+    pub id: FlexibleId,
+
+    pub user_id: Option<FlexibleId>,
+
+    /// Entry this one replies to, if it is a threaded reply
+    pub parent_id: Option<FlexibleId>,
+
+    /// HTML body
+    pub message: Option<String>,
+
+    pub created_at: Option<DateTime<Utc>>,
+}
+
+/// Canvas course module
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Module {
+    pub id: FlexibleId,
+
+    #[serde(default)]
+    pub name: String,
+
+    pub position: Option<u32>,
+
+    /// Only visible to teachers; students only see published modules
+    pub published: Option<bool>,
+
+    pub items_count: Option<u32>,
+
+    pub unlock_at: Option<DateTime<Utc>>,
+
+    /// Whether items must be completed in order
+    pub require_sequential_progress: Option<bool>,
+
+    /// The current user's progress: `locked`, `unlocked`, `started`, or
+    /// `completed` (students only)
+    pub state: Option<String>,
+
+    /// Present when requested with `include[]=items`; Canvas omits them for
+    /// modules with too many items, which then need `/items` fetched separately
+    pub items: Option<Vec<ModuleItem>>,
+}
+
+/// An entry in a course module
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ModuleItem {
+    pub id: FlexibleId,
+
+    pub module_id: Option<FlexibleId>,
+
+    pub position: Option<u32>,
+
+    #[serde(default)]
+    pub title: String,
+
+    /// `File`, `Page`, `Discussion`, `Assignment`, `Quiz`, `SubHeader`,
+    /// `ExternalUrl`, or `ExternalTool`
+    #[serde(rename = "type")]
+    pub item_type: String,
+
+    /// ID of the linked object (absent for pages, sub-headers, and URLs)
+    pub content_id: Option<FlexibleId>,
+
+    /// Slug of the linked page, for `Page` items
+    pub page_url: Option<String>,
+
+    /// Link target, for `ExternalUrl` items
+    pub external_url: Option<String>,
+
+    pub html_url: Option<String>,
+
+    /// Indentation level in the Canvas UI
+    pub indent: Option<u32>,
+
+    pub completion_requirement: Option<CompletionRequirement>,
+}
+
+/// What a student must do to complete a module item
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CompletionRequirement {
+    /// `must_view`, `must_submit`, `must_contribute`, `min_score`, or `must_mark_done`
+    #[serde(rename = "type")]
+    pub requirement_type: String,
+
+    /// Score needed, for `min_score` requirements
+    pub min_score: Option<f64>,
+
+    /// Whether the current user has met the requirement (students only)
+    pub completed: Option<bool>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_course_with_flexible_ids() {
+        let numeric: Course = serde_json::from_str(
+            r#"{"id": 101, "name": "Biology", "course_code": "BIOL101",
+                "workflow_state": "available", "start_at": "2025-08-25T07:00:00Z",
+                "end_at": null, "enrollment_term_id": 5}"#,
+        )
+        .unwrap();
+        assert_eq!(numeric.id, FlexibleId::Numeric(101));
+        assert_eq!(numeric.id.to_string(), "101");
+        assert!(numeric.start_at.is_some());
+        assert!(numeric.end_at.is_none());
+
+        let text: Course =
+            serde_json::from_str(r#"{"id": "sis_course_id:BIOL101", "enrollment_term_id": "5"}"#)
+                .unwrap();
+        assert_eq!(text.id.to_string(), "sis_course_id:BIOL101");
+        assert_eq!(text.id.as_u64(), None);
+        assert_eq!(text.enrollment_term_id.unwrap().as_u64(), Some(5));
+        assert_eq!(text.name, "");
+    }
+
+    #[test]
+    fn test_path_segment_encoding() {
+        assert_eq!(FlexibleId::from(42).path_segment(), "42");
+        assert_eq!(
+            FlexibleId::sis(SisKind::Course, "BIOL 101/A").path_segment(),
+            "sis_course_id:BIOL%20101%2FA"
+        );
+        assert_eq!(
+            FlexibleId::from("sis_user_id:jdoe@example.edu").path_segment(),
+            "sis_user_id:jdoe%40example.edu"
+        );
+    }
+
+    #[test]
+    fn test_assignment_deserialization() {
+        let assignment: Assignment = serde_json::from_str(
+            r#"{"id": 7, "name": "Essay 1", "description": "<p>Write</p>",
+                "due_at": "2025-10-03T23:59:00Z", "points_possible": 10.0,
+                "submission_types": ["online_text_entry", "online_upload"],
+                "published": true, "assignment_group_id": 3}"#,
+        )
+        .unwrap();
+        assert_eq!(assignment.name, "Essay 1");
+        assert_eq!(
+            assignment.due_at.unwrap().to_rfc3339(),
+            "2025-10-03T23:59:00+00:00"
+        );
+        assert_eq!(assignment.submission_types.len(), 2);
+        assert!(assignment.published);
+
+        let undated: Assignment =
+            serde_json::from_str(r#"{"id": 8, "name": "Reading", "due_at": null}"#).unwrap();
+        assert!(undated.due_at.is_none());
+        assert!(undated.submission_types.is_empty());
+    }
+
+    #[test]
+    fn test_due_at_for_prefers_applicable_override() {
+        let assignment: Assignment = serde_json::from_str(
+            r#"{"id": 7, "due_at": "2025-10-03T23:59:00Z", "overrides": [
+                {"id": 1, "student_ids": [42], "due_at": "2025-10-10T23:59:00Z"},
+                {"id": 2, "course_section_id": 5, "due_at": "2025-10-05T23:59:00Z"},
+                {"id": 3, "course_section_id": 6, "due_at": "2025-10-06T23:59:00Z"}]}"#,
+        )
+        .unwrap();
+        let due = |user: u64, sections: &[u64]| {
+            let sections: Vec<FlexibleId> = sections.iter().map(|&s| s.into()).collect();
+            assignment
+                .due_at_for(&user.into(), &sections)
+                .map(|d| d.to_rfc3339())
+        };
+
+        assert_eq!(due(42, &[5]).as_deref(), Some("2025-10-10T23:59:00+00:00"));
+        assert_eq!(
+            due(43, &[5, 6]).as_deref(),
+            Some("2025-10-06T23:59:00+00:00")
+        );
+        assert_eq!(due(43, &[9]).as_deref(), Some("2025-10-03T23:59:00+00:00"));
+    }
+
+    #[test]
+    fn test_id_validation() {
+        assert!(FlexibleId::from(42).is_valid());
+        assert!(FlexibleId::from("42").is_valid());
+        assert!(FlexibleId::from("sis_course_id:BIOL101").is_valid());
+        assert!(!FlexibleId::from("").is_valid());
+        assert!(!FlexibleId::from("biology").is_valid());
+        assert!(!FlexibleId::from("sis_course_id:").is_valid());
+        assert!(FlexibleId::from("sis_user_id:jdoe").is_valid());
+        assert!(FlexibleId::from("uuid:abc123").is_valid());
+        assert!(!FlexibleId::from("sis_foo:x").is_valid());
+        assert!(!FlexibleId::from("sis_:x").is_valid());
+        assert!(!FlexibleId::from("../admin").is_valid());
+    }
+
+    #[test]
+    fn test_assignment_points_possible_representations() {
+        let points = |value: &str| {
+            let json = format!(r#"{{"id": 1, "points_possible": {}}}"#, value);
+            serde_json::from_str::<Assignment>(&json)
+                .unwrap()
+                .points_possible
+        };
+        assert_eq!(points("10"), Some(10.0));
+        assert_eq!(points("7.5"), Some(7.5));
+        assert_eq!(points(r#""12""#), Some(12.0));
+        assert_eq!(points(r#"" 2.5 ""#), Some(2.5));
+        assert_eq!(points(r#""""#), None);
+        assert_eq!(points(r#""n/a""#), None);
+        assert_eq!(points("null"), None);
+
+        let missing: Assignment = serde_json::from_str(r#"{"id": 1}"#).unwrap();
+        assert_eq!(missing.points_possible, None);
+    }
+}
@@ -0,0 +1,156 @@
+use rmcp::model::{
+    GetPromptResult, JsonObject, Prompt, PromptArgument, PromptMessage, PromptMessageRole,
+};
+use rmcp::Error as McpError;
+
+/// A prompt argument: name, description, required
+type ArgSpec = (&'static str, &'static str, bool);
+
+/// Prompt definitions: name, description, arguments
+const PROMPTS: &[(&str, &str, &[ArgSpec])] = &[
+    (
+        "grade_with_rubric",
+        "Walk through grading an assignment's submissions against its rubric",
+        &[
+            ("course_id", "Canvas course ID", true),
+            ("assignment_id", "Canvas assignment ID", true),
+        ],
+    ),
+    (
+        "weekly_summary",
+        "Summarize what is due in the coming week",
+        &[(
+            "course_id",
+            "Limit the summary to one course (default: all active courses)",
+            false,
+        )],
+    ),
+    (
+        "draft_announcement",
+        "Draft a course announcement for review before posting",
+        &[
+            ("course_id", "Canvas course ID", true),
+            ("topic", "What the announcement is about", true),
+            ("tone", "Tone to write in, e.g. friendly or formal", false),
+        ],
+    ),
+];
+
+/// All prompts offered by the server
+pub fn list_prompts() -> Vec<Prompt> {
+    PROMPTS
+        .iter()
+        .map(|(name, description, args)| {
+            Prompt::new(
+                *name,
+                Some(*description),
+                Some(
+                    args.iter()
+                        .map(|(name, description, required)| PromptArgument {
+                            name: name.to_string(),
+                            description: Some(description.to_string()),
+                            required: Some(*required),
+                        })
+                        .collect(),
+                ),
+            )
+        })
+        .collect()
+}
+
+/// Render a prompt's messages with the given arguments filled in
+pub fn get_prompt(name: &str, arguments: Option<&JsonObject>) -> Result<GetPromptResult, McpError> {
+    let (_, description, specs) = PROMPTS
+        .iter()
+        .find(|(n, _, _)| *n == name)
+        .ok_or_else(|| McpError::invalid_params(format!("Unknown prompt: {}", name), None))?;
+
+    let arg = |key: &str| -> Option<String> {
+        arguments?.get(key).and_then(|v| match v {
+            serde_json::Value::String(s) if !s.trim().is_empty() => Some(s.trim().to_string()),
+            serde_json::Value::Number(n) => Some(n.to_string()),
+            _ => None,
+        })
+    };
+    for (key, _, required) in specs.iter() {
+        if *required && arg(key).is_none() {
+            return Err(McpError::invalid_params(
+                format!("Prompt {} requires the {} argument", name, key),
+                None,
+            ));
+        }
+    }
+    let required = |key: &str| arg(key).unwrap_or_default();
+
+    let text = match name {
+        "grade_with_rubric" => format!(
+            "Help me grade assignment {assignment} in course {course} using its rubric.\n\n\
+             1. Use list_submissions (course_id {course}, assignment_id {assignment}, \
+             include_rubric_assessment true) to see who has submitted and what is already graded.\n\
+             2. For each ungraded submission, review it against each rubric criterion and \
+             propose points per criterion with a one-sentence justification.\n\
+             3. Show me the proposed grades and comments as a table and wait for my approval.\n\
+             4. Once I approve, post them with grade_submission, or bulk_grade for many students at once.",
+            course = required("course_id"),
+            assignment = required("assignment_id"),
+        ),
+        "weekly_summary" => {
+            let scope = match arg("course_id") {
+                Some(course) => format!(
+                    "Use list_assignments with course_id {} and bucket \"upcoming\".",
+                    course
+                ),
+                None => "Use list_courses to find my active courses, then list_assignments \
+                         with bucket \"upcoming\" for each."
+                    .to_string(),
+            };
+            format!(
+                "Summarize what is due in the next 7 days.\n\n{}\n\n\
+                 Group the results by day, list each assignment with its course, due time, \
+                 and points, and call out anything I haven't submitted yet.",
+                scope
+            )
+        }
+        "draft_announcement" => format!(
+            "Draft an announcement for course {course} about: {topic}\n\n\
+             Write it in a {tone} tone, keep it under 200 words, and give it a clear title. \
+             Use get_course (course_id {course}) for the course name and any context you need. \
+             Show me the draft for review; do not post it.",
+            course = required("course_id"),
+            topic = required("topic"),
+            tone = arg("tone").unwrap_or_else(|| "friendly, clear".to_string()),
+        ),
+        _ => unreachable!("prompt names are checked above"),
+    };
+
+    Ok(GetPromptResult {
+        description: Some(description.to_string()),
+        messages: vec![PromptMessage::new_text(PromptMessageRole::User, text)],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rmcp::model::PromptMessageContent;
+    use serde_json::json;
+
+    #[test]
+    fn test_get_prompt_fills_arguments() {
+        let args = json!({"course_id": "101", "assignment_id": 7});
+        let result = get_prompt("grade_with_rubric", args.as_object()).unwrap();
+        let PromptMessageContent::Text { text } = &result.messages[0].content else {
+            panic!("expected text");
+        };
+        assert!(text.contains("assignment 7 in course 101"));
+
+        let error = get_prompt(
+            "draft_announcement",
+            json!({"course_id": "101"}).as_object(),
+        )
+        .unwrap_err();
+        assert!(error.message.contains("topic"));
+        assert!(get_prompt("weekly_summary", None).is_ok());
+        assert_eq!(list_prompts().len(), 3);
+    }
+}
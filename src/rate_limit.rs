@@ -0,0 +1,136 @@
+use reqwest::header::HeaderMap;
+use std::sync::Mutex;
+use std::time::Duration;
+
+/// Header Canvas uses to report the remaining request cost budget
+pub const RATE_LIMIT_REMAINING: &str = "x-rate-limit-remaining";
+
+/// Tuning for the client-side rate limiter
+#[derive(Debug, Clone, PartialEq)]
+pub struct RateLimiterConfig {
+    /// Remaining budget below which requests start being delayed
+    pub low_water_mark: f64,
+
+    /// Delay applied when the budget is fully exhausted
+    pub max_delay: Duration,
+
+    /// Shape of the delay curve between the low-water mark and zero;
+    /// 1.0 is linear, larger values stay gentle until the budget is nearly gone
+    pub exponent: f64,
+}
+
+impl Default for RateLimiterConfig {
+    fn default() -> Self {
+        Self {
+            low_water_mark: 100.0,
+            max_delay: Duration::from_secs(2),
+            exponent: 2.0,
+        }
+    }
+}
+
+impl RateLimiterConfig {
+    /// Delay to apply before the next request given the remaining budget
+    pub fn delay_for(&self, remaining: f64) -> Duration {
+        if self.low_water_mark <= 0.0 || remaining >= self.low_water_mark {
+            return Duration::ZERO;
+        }
+        let pressure = ((self.low_water_mark - remaining.max(0.0)) / self.low_water_mark)
+            .clamp(0.0, 1.0)
+            .powf(self.exponent);
+        self.max_delay.mul_f64(pressure)
+    }
+}
+
+/// Client-side limiter driven by Canvas's `X-Rate-Limit-Remaining` header
+///
+/// Canvas throttles tokens whose cost bucket runs dry, so once the reported
+/// budget drops below the low-water mark subsequent requests are delayed to
+/// give the bucket time to refill.
+#[derive(Debug)]
+pub struct RateLimiter {
+    config: RateLimiterConfig,
+    remaining: Mutex<Option<f64>>,
+}
+
+impl RateLimiter {
+    /// Create a rate limiter with the given tuning
+    pub fn new(config: RateLimiterConfig) -> Self {
+        Self {
+            config,
+            remaining: Mutex::new(None),
+        }
+    }
+
+    /// Record the remaining budget reported by a response
+    pub fn observe(&self, headers: &HeaderMap) {
+        let remaining = headers
+            .get(RATE_LIMIT_REMAINING)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<f64>().ok());
+
+        if let Some(remaining) = remaining {
+            *self.remaining.lock().unwrap_or_else(|e| e.into_inner()) = Some(remaining);
+        }
+    }
+
+    /// Last remaining budget reported by Canvas, if any
+    pub fn remaining(&self) -> Option<f64> {
+        *self.remaining.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// Current delay to apply before sending a request
+    pub fn current_delay(&self) -> Duration {
+        self.remaining()
+            .map(|remaining| self.config.delay_for(remaining))
+            .unwrap_or(Duration::ZERO)
+    }
+
+    /// Wait until it's reasonable to send the next request
+    pub async fn acquire(&self) {
+        let delay = self.current_delay();
+        if !delay.is_zero() {
+            tracing::debug!(?delay, remaining = ?self.remaining(), "throttling Canvas request");
+            tokio::time::sleep(delay).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_delay_curve() {
+        let config = RateLimiterConfig {
+            low_water_mark: 100.0,
+            max_delay: Duration::from_millis(1000),
+            exponent: 1.0,
+        };
+
+        assert_eq!(config.delay_for(700.0), Duration::ZERO);
+        assert_eq!(config.delay_for(100.0), Duration::ZERO);
+        assert_eq!(config.delay_for(50.0), Duration::from_millis(500));
+        assert_eq!(config.delay_for(0.0), Duration::from_millis(1000));
+        assert_eq!(config.delay_for(-5.0), Duration::from_millis(1000));
+    }
+
+    #[test]
+    fn test_observes_remaining_header() {
+        let limiter = RateLimiter::new(RateLimiterConfig::default());
+        assert_eq!(limiter.current_delay(), Duration::ZERO);
+
+        let mut headers = HeaderMap::new();
+        headers.insert(RATE_LIMIT_REMAINING, "650.5".parse().unwrap());
+        limiter.observe(&headers);
+        assert_eq!(limiter.remaining(), Some(650.5));
+        assert_eq!(limiter.current_delay(), Duration::ZERO);
+
+        headers.insert(RATE_LIMIT_REMAINING, "0.0".parse().unwrap());
+        limiter.observe(&headers);
+        assert_eq!(
+            limiter.current_delay(),
+            RateLimiterConfig::default().max_delay
+        );
+    }
+}
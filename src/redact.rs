@@ -0,0 +1,100 @@
+/// Fields whose values are scrubbed wherever they appear, as JSON string
+/// values (`"password": "..."`) or form/query parameters (`password=...`)
+pub const SECRET_FIELDS: &[&str] = &["access_token", "refresh_token", "client_secret", "password"];
+
+/// Placeholder written in place of a secret
+pub const REDACTED: &str = "[REDACTED]";
+
+/// Scrub credentials from text before it is logged or put in an error message
+///
+/// Removes every occurrence of `secrets` (such as the API token), the
+/// credential following `Bearer `, and the values of `SECRET_FIELDS`.
+pub fn redact(text: &str, secrets: &[&str]) -> String {
+    let mut text = text.to_string();
+    for secret in secrets.iter().filter(|s| !s.is_empty()) {
+        text = text.replace(secret, REDACTED);
+    }
+    text = redact_after(&text, "Bearer ", |rest| {
+        rest.find(|c: char| c.is_whitespace() || c == '"' || c == '\'')
+            .unwrap_or(rest.len())
+    });
+    for field in SECRET_FIELDS {
+        text = redact_after(&text, &format!("\"{}\"", field), json_string_value);
+        text = redact_after(&text, &format!("{}=", field), |rest| {
+            rest.find(|c: char| c == '&' || c.is_whitespace())
+                .unwrap_or(rest.len())
+        });
+    }
+    text
+}
+
+/// Replace the span after each `marker` that `span` measures, unless it is
+/// empty or already redacted
+fn redact_after(text: &str, marker: &str, span: impl Fn(&str) -> usize) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(index) = rest.find(marker) {
+        let (before, after) = rest.split_at(index + marker.len());
+        out.push_str(before);
+        let len = span(after);
+        if len > 0 && !after[..len].ends_with(REDACTED) {
+            out.push_str(&redact_span(&after[..len]));
+        } else {
+            out.push_str(&after[..len]);
+        }
+        rest = &after[len..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// For a JSON string value, keep the `: "` and closing quote around the
+/// placeholder
+fn redact_span(span: &str) -> String {
+    match (span.find('"'), span.ends_with('"')) {
+        (Some(open), true) if open + 1 < span.len() => {
+            format!("{}{}\"", &span[..=open], REDACTED)
+        }
+        _ => REDACTED.to_string(),
+    }
+}
+
+/// Length of `: "value"` following a JSON key, or 0 if the value isn't a string
+fn json_string_value(rest: &str) -> usize {
+    let trimmed = rest.trim_start();
+    let Some(value) = trimmed.strip_prefix(':') else {
+        return 0;
+    };
+    let value = value.trim_start();
+    if !value.starts_with('"') {
+        return 0;
+    }
+    let mut escaped = false;
+    for (i, c) in value.char_indices().skip(1) {
+        match c {
+            _ if escaped => escaped = false,
+            '\\' => escaped = true,
+            '"' => return rest.len() - value.len() + i + 1,
+            _ => {}
+        }
+    }
+    0
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_redact_tokens_and_secret_fields() {
+        let text = r#"Authorization: Bearer abc123 {"access_token": "xyz789", "password":"p\"w", "name": "Jane"} ?client_secret=s3cret&x=1"#;
+        let redacted = redact(text, &["1234~long-api-token"]);
+        assert_eq!(
+            redacted,
+            r#"Authorization: Bearer [REDACTED] {"access_token": "[REDACTED]", "password":"[REDACTED]", "name": "Jane"} ?client_secret=[REDACTED]&x=1"#
+        );
+
+        let redacted = redact("token 1234~long-api-token leaked", &["1234~long-api-token"]);
+        assert_eq!(redacted, "token [REDACTED] leaked");
+    }
+}
@@ -0,0 +1,80 @@
+/// Output formats for Canvas rich content (descriptions, pages, syllabus,
+/// discussion entries), as accepted by the tools' `format` parameter
+pub const FORMATS: &[&str] = &["markdown", "text", "html"];
+
+/// Line width used when rendering plain text; wide enough that paragraphs
+/// aren't hard-wrapped in the assistant's output
+const RENDER_WIDTH: usize = 1000;
+
+/// Render Canvas HTML in one of `FORMATS`
+///
+/// `html` (or any unrecognized format) returns the content unchanged.
+pub fn render(html: &str, format: &str) -> String {
+    match format {
+        "markdown" => html_to_markdown(html),
+        "text" => html_to_text(html),
+        _ => html.to_string(),
+    }
+}
+
+/// Convert Canvas HTML to Markdown, keeping headings, lists, links, tables,
+/// and emphasis
+///
+/// Falls back to the original HTML if the conversion fails.
+pub fn html_to_markdown(html: &str) -> String {
+    match htmd::convert(html) {
+        Ok(markdown) => markdown.trim().to_string(),
+        Err(e) => {
+            tracing::warn!(error = %e, "failed to convert HTML to Markdown, returning it unchanged");
+            html.to_string()
+        }
+    }
+}
+
+/// Render Canvas HTML as plain text
+///
+/// Falls back to the original HTML if it can't be parsed, so content is never
+/// lost.
+pub fn html_to_text(html: &str) -> String {
+    match html2text::config::plain_no_decorate()
+        .no_link_wrapping()
+        .string_from_read(html.as_bytes(), RENDER_WIDTH)
+    {
+        Ok(text) => text.trim().to_string(),
+        Err(e) => {
+            tracing::warn!(error = %e, "failed to render HTML, returning it unchanged");
+            html.to_string()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_html_to_text() {
+        let text = html_to_text("<p>Read <strong>chapter 3</strong>&amp; reply.</p><p>Thanks!</p>");
+        assert!(text.contains("Read chapter 3& reply."));
+        assert!(text.contains("Thanks!"));
+        assert!(!text.contains('<'));
+    }
+
+    #[test]
+    fn test_html_to_markdown() {
+        let markdown = html_to_markdown(
+            r#"<h2>Week 1</h2><ul><li>Read <a href="https://example.edu/ch1">chapter 1</a></li></ul>
+               <table><tr><th>Task</th><th>Points</th></tr><tr><td>Essay</td><td>10</td></tr></table>"#,
+        );
+        assert!(markdown.starts_with("## Week 1"));
+        assert!(markdown.contains("[chapter 1](https://example.edu/ch1)"));
+        assert!(markdown.contains("| Task  | Points |"));
+        assert!(markdown.contains("| Essay | 10     |"));
+    }
+
+    #[test]
+    fn test_render_html_passthrough() {
+        assert_eq!(render("<p>Hi</p>", "html"), "<p>Hi</p>");
+        assert_eq!(render("<p>Hi</p>", "text"), "Hi");
+    }
+}
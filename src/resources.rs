@@ -0,0 +1,159 @@
+use crate::client::QueryBuilder;
+use crate::error::CanvasError;
+use crate::models::{Course, FlexibleId, Module};
+use crate::server::CanvasServer;
+use rmcp::model::{AnnotateAble, RawResource, ReadResourceResult, Resource, ResourceContents};
+use rmcp::Error as McpError;
+
+/// URI prefix for course overview resources, e.g. `canvas://course/101`
+pub const COURSE_URI_PREFIX: &str = "canvas://course/";
+
+impl CanvasServer {
+    /// One resource per active course
+    pub(crate) async fn course_resources(&self) -> Result<Vec<Resource>, McpError> {
+        let courses: Vec<Course> = self
+            .client
+            .get_all("/courses?enrollment_state=active")
+            .await?;
+
+        Ok(courses
+            .into_iter()
+            .map(|course| {
+                let mut resource =
+                    RawResource::new(format!("{}{}", COURSE_URI_PREFIX, course.id), course.name);
+                resource.description = course.course_code;
+                resource.mime_type = Some("text/markdown".to_string());
+                resource.no_annotation()
+            })
+            .collect())
+    }
+
+    /// Course overview (syllabus and module list) for a `canvas://course/<id>` URI
+    pub(crate) async fn read_course_resource(
+        &self,
+        uri: &str,
+    ) -> Result<ReadResourceResult, McpError> {
+        let course_id = uri
+            .strip_prefix(COURSE_URI_PREFIX)
+            .map(FlexibleId::from)
+            .filter(FlexibleId::is_valid)
+            .ok_or_else(|| {
+                McpError::resource_not_found(format!("Unknown resource URI: {}", uri), None)
+            })?;
+        let course_path = format!("/courses/{}", course_id.path_segment());
+
+        let course: serde_json::Value = self
+            .client
+            .get(
+                &QueryBuilder::new()
+                    .include("syllabus_body")
+                    .to_path(&course_path),
+            )
+            .await
+            .map_err(|e| match e {
+                CanvasError::NotFound(message) => McpError::resource_not_found(message, None),
+                other => McpError::from(other),
+            })?;
+        let modules: Vec<Module> = self
+            .client
+            .get_all(&format!("{}/modules", course_path))
+            .await?;
+
+        Ok(ReadResourceResult {
+            contents: vec![ResourceContents::TextResourceContents {
+                uri: uri.to_string(),
+                mime_type: Some("text/markdown".to_string()),
+                text: course_overview(&course, &modules),
+            }],
+        })
+    }
+}
+
+/// Render a course as Markdown: title, syllabus (converted from HTML), then
+/// modules in order
+fn course_overview(course: &serde_json::Value, modules: &[Module]) -> String {
+    let field = |name: &str| course.get(name).and_then(|v| v.as_str());
+
+    let mut text = format!("# {}\n", field("name").unwrap_or("Untitled course"));
+    if let Some(code) = field("course_code") {
+        text.push_str(&format!("\nCourse code: {}\n", code));
+    }
+
+    text.push_str("\n## Syllabus\n\n");
+    match field("syllabus_body").filter(|s| !s.trim().is_empty()) {
+        Some(syllabus) => text.push_str(&crate::render::html_to_markdown(syllabus)),
+        None => text.push_str("_No syllabus posted._"),
+    }
+    text.push('\n');
+
+    text.push_str("\n## Modules\n\n");
+    if modules.is_empty() {
+        text.push_str("_No modules._\n");
+    }
+    for module in modules {
+        text.push_str(&format!("- {}", module.name));
+        if let Some(count) = module.items_count {
+            text.push_str(&format!(" ({} items)", count));
+        }
+        if module.published == Some(false) {
+            text.push_str(" [unpublished]");
+        }
+        text.push('\n');
+    }
+
+    text
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_read_course_resource() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/courses/101?include[]=syllabus_body")
+            .with_body(
+                r#"{"id": 101, "name": "Biology", "course_code": "BIOL101",
+                    "syllabus_body": "<p>Welcome to <strong>Biology</strong></p>"}"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/api/v1/courses/101/modules?per_page=100")
+            .with_body(
+                r#"[{"id": 1, "name": "Week 1: Cells", "position": 1, "items_count": 4},
+                    {"id": 2, "name": "Week 2: Genetics", "position": 2, "published": false}]"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .read_course_resource("canvas://course/101")
+            .await
+            .unwrap();
+        let ResourceContents::TextResourceContents { text, .. } = &result.contents[0] else {
+            panic!("expected text contents");
+        };
+        assert!(text.starts_with("# Biology"));
+        assert!(text.contains("## Syllabus\n\nWelcome to **Biology**\n"));
+        assert!(!text.contains("<p>"));
+        assert!(text.contains("- Week 1: Cells (4 items)"));
+        assert!(text.contains("- Week 2: Genetics [unpublished]"));
+
+        assert!(canvas
+            .read_course_resource("canvas://user/1")
+            .await
+            .is_err());
+        let error = canvas
+            .read_course_resource("canvas://course/101?as_user_id=5")
+            .await
+            .unwrap_err();
+        assert_eq!(error.code, rmcp::model::ErrorCode::RESOURCE_NOT_FOUND);
+    }
+}
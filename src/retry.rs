@@ -0,0 +1,211 @@
+use crate::config::CanvasConfig;
+use chrono::{DateTime, Utc};
+use rand::Rng;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Method, StatusCode};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+/// Upper bound on any single backoff delay
+const MAX_BACKOFF: Duration = Duration::from_secs(30);
+
+/// Retry policy for transient Canvas API failures
+#[derive(Debug, Clone)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the initial attempt
+    pub max_retries: u32,
+
+    /// Base delay, doubled on each subsequent retry
+    pub base_backoff: Duration,
+
+    /// Also retry POST and PUT requests (not idempotent in general)
+    pub retry_writes: bool,
+}
+
+impl RetryPolicy {
+    /// Build a retry policy from configuration
+    pub fn from_config(config: &CanvasConfig) -> Self {
+        Self {
+            max_retries: config.max_retries,
+            base_backoff: Duration::from_millis(config.base_backoff_ms),
+            retry_writes: config.retry_writes,
+        }
+    }
+
+    /// Whether requests with the given method may be retried
+    pub fn allows(&self, method: &Method) -> bool {
+        *method == Method::GET
+            || (self.retry_writes && (*method == Method::POST || *method == Method::PUT))
+    }
+
+    /// Delay before retry number `attempt` (starting at 0)
+    ///
+    /// Uses exponential backoff with "equal jitter": half of the delay is
+    /// fixed and the other half is random, so concurrent clients spread out.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exp = self
+            .base_backoff
+            .saturating_mul(2u32.saturating_pow(attempt))
+            .min(MAX_BACKOFF);
+        let half = exp / 2;
+        let jitter = rand::thread_rng().gen_range(0..=half.as_millis() as u64);
+        half + Duration::from_millis(jitter)
+    }
+}
+
+/// Backoff shared by every clone of a client
+///
+/// When one request is rate limited, the governor holds back every other
+/// request until the backoff has passed, so concurrent fetches wait together
+/// instead of each retrying into the limit and keeping the token throttled.
+#[derive(Debug, Default)]
+pub struct RetryGovernor {
+    paused_until: Mutex<Option<Instant>>,
+}
+
+impl RetryGovernor {
+    /// Create a governor with no pause in effect
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Hold off all requests for at least `delay` (capped at `MAX_BACKOFF`);
+    /// an existing longer pause is kept
+    pub fn pause(&self, delay: Duration) {
+        let now = Instant::now();
+        let until = now
+            .checked_add(delay.min(MAX_BACKOFF))
+            .unwrap_or(now + MAX_BACKOFF);
+        let mut paused_until = self.paused_until.lock().unwrap_or_else(|e| e.into_inner());
+        if paused_until.is_none_or(|current| current < until) {
+            *paused_until = Some(until);
+        }
+    }
+
+    /// Time left before requests may be sent again, if paused
+    pub fn remaining(&self) -> Option<Duration> {
+        let paused_until = *self.paused_until.lock().unwrap_or_else(|e| e.into_inner());
+        paused_until
+            .map(|until| until.saturating_duration_since(Instant::now()))
+            .filter(|left| !left.is_zero())
+    }
+
+    /// Wait until no pause is in effect
+    pub async fn wait(&self) {
+        // Loop, since another request may extend the pause while we sleep
+        while let Some(left) = self.remaining() {
+            tokio::time::sleep(left).await;
+        }
+    }
+}
+
+/// Whether a response status indicates a transient failure worth retrying
+pub fn is_retryable_status(status: StatusCode) -> bool {
+    matches!(
+        status,
+        StatusCode::TOO_MANY_REQUESTS
+            | StatusCode::INTERNAL_SERVER_ERROR
+            | StatusCode::BAD_GATEWAY
+            | StatusCode::SERVICE_UNAVAILABLE
+            | StatusCode::GATEWAY_TIMEOUT
+    )
+}
+
+/// Parse a `Retry-After` header, given either as delay seconds or an HTTP date
+///
+/// The delay is capped at `MAX_BACKOFF`, so a bogus or hostile header can't
+/// stall the client for hours.
+pub fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    let delay = if let Ok(secs) = value.parse::<f64>() {
+        if secs.is_nan() || secs < 0.0 {
+            return None;
+        }
+        Duration::try_from_secs_f64(secs).unwrap_or(MAX_BACKOFF)
+    } else {
+        let date = DateTime::parse_from_rfc2822(value).ok()?;
+        (date.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or(Duration::ZERO)
+    };
+    Some(delay.min(MAX_BACKOFF))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_backoff_grows_and_is_capped() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            base_backoff: Duration::from_millis(100),
+            retry_writes: false,
+        };
+
+        for attempt in 0..4 {
+            let exp = Duration::from_millis(100 * 2u64.pow(attempt));
+            let delay = policy.backoff(attempt);
+            assert!(delay >= exp / 2 && delay <= exp);
+        }
+        assert!(policy.backoff(30) <= MAX_BACKOFF);
+
+        assert!(policy.allows(&Method::GET));
+        assert!(!policy.allows(&Method::POST));
+        assert!(!is_retryable_status(StatusCode::NOT_FOUND));
+        assert!(is_retryable_status(StatusCode::TOO_MANY_REQUESTS));
+    }
+
+    #[test]
+    fn test_retry_after_parsing() {
+        let mut headers = HeaderMap::new();
+        assert_eq!(retry_after(&headers), None);
+
+        headers.insert(RETRY_AFTER, "5".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(Duration::from_secs(5)));
+
+        headers.insert(RETRY_AFTER, "0.25".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(Duration::from_millis(250)));
+
+        headers.insert(
+            RETRY_AFTER,
+            "Wed, 21 Oct 2015 07:28:00 GMT".parse().unwrap(),
+        );
+        assert_eq!(retry_after(&headers), Some(Duration::ZERO));
+
+        headers.insert(RETRY_AFTER, "soon".parse().unwrap());
+        assert_eq!(retry_after(&headers), None);
+    }
+
+    #[test]
+    fn test_retry_after_is_capped() {
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, "1e20".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(MAX_BACKOFF));
+
+        headers.insert(RETRY_AFTER, "86400".parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(MAX_BACKOFF));
+
+        let tomorrow = (Utc::now() + chrono::Duration::days(1)).to_rfc2822();
+        headers.insert(RETRY_AFTER, tomorrow.parse().unwrap());
+        assert_eq!(retry_after(&headers), Some(MAX_BACKOFF));
+    }
+
+    #[test]
+    fn test_governor_keeps_the_longest_pause() {
+        let governor = RetryGovernor::new();
+        assert_eq!(governor.remaining(), None);
+
+        governor.pause(Duration::from_secs(10));
+        governor.pause(Duration::from_secs(1));
+        let left = governor.remaining().unwrap();
+        assert!(left > Duration::from_secs(9) && left <= Duration::from_secs(10));
+
+        governor.pause(Duration::from_secs(20));
+        assert!(governor.remaining().unwrap() > Duration::from_secs(19));
+
+        governor.pause(Duration::MAX);
+        assert!(governor.remaining().unwrap() <= MAX_BACKOFF);
+    }
+}
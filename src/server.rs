@@ -0,0 +1,388 @@
+use crate::backend::HttpBackend;
+use crate::client::CanvasClient;
+use crate::config::CanvasConfig;
+use crate::error::CanvasError;
+use crate::models::{EnrollmentTerm, User};
+use crate::prompts;
+use rmcp::handler::server::tool::ToolCallContext;
+use rmcp::model::{
+    CallToolRequestParam, CallToolResult, GetPromptRequestParam, GetPromptResult, Implementation,
+    ListPromptsResult, ListResourcesResult, ListToolsResult, PaginatedRequestParam,
+    ProtocolVersion, ReadResourceRequestParam, ReadResourceResult, ServerCapabilities, ServerInfo,
+    Tool,
+};
+use rmcp::service::RequestContext;
+use rmcp::{Error as McpError, RoleServer, ServerHandler};
+use std::sync::Arc;
+
+/// Canvas MCP server
+///
+/// Holds the Canvas client and configuration shared by every tool. Tools are
+/// implemented in the `tools` module and registered in the tool box below.
+#[derive(Clone)]
+pub struct CanvasServer {
+    pub(crate) client: CanvasClient,
+    pub(crate) config: Arc<CanvasConfig>,
+    /// Enrollment terms of the admin account, fetched once per session for
+    /// resolving term names
+    pub(crate) terms: Arc<tokio::sync::Mutex<Option<Vec<EnrollmentTerm>>>>,
+}
+
+impl CanvasServer {
+    /// Create a new server from configuration
+    pub fn new(config: Arc<CanvasConfig>) -> crate::Result<Self> {
+        let client = CanvasClient::new(config.clone())?;
+        Ok(Self::from_client(client, config))
+    }
+
+    /// Create a server whose client sends requests through `backend`, so
+    /// tools can be tested without a network or mock HTTP server
+    pub fn with_backend(
+        config: Arc<CanvasConfig>,
+        backend: Arc<dyn HttpBackend>,
+    ) -> crate::Result<Self> {
+        let client = CanvasClient::with_backend(config.clone(), backend)?;
+        Ok(Self::from_client(client, config))
+    }
+
+    fn from_client(client: CanvasClient, config: Arc<CanvasConfig>) -> Self {
+        Self {
+            client,
+            config,
+            terms: Arc::default(),
+        }
+    }
+
+    /// Create a new server and check the API token with `GET /users/self`
+    ///
+    /// Catches a bad or expired token at startup instead of on the first tool
+    /// call, and a mistyped host as a DNS failure rather than a generic
+    /// network error. Use `new` when Canvas may not be reachable yet.
+    ///
+    /// Returns the server along with the user the token authenticates as.
+    pub async fn new_validated(config: Arc<CanvasConfig>) -> crate::Result<(Self, User)> {
+        resolve_api_host(&config).await?;
+        let server = Self::new(config)?;
+        match server.client.get_current_user().await {
+            Ok(user) => Ok((server, user)),
+            Err(CanvasError::Auth(message)) => Err(CanvasError::auth(format!(
+                "Canvas rejected the API token ({}). Check that CANVAS_API_TOKEN is current and was issued by the instance at {}",
+                message,
+                server.config.api_url
+            ))),
+            Err(e) => Err(e),
+        }
+    }
+
+    /// Get the underlying Canvas client
+    pub fn client(&self) -> &CanvasClient {
+        &self.client
+    }
+
+    /// Client for a read tool, skipping the response cache when the caller
+    /// asked for fresh data
+    ///
+    /// Every read tool with parameters takes `cache_bypass`. The exceptions
+    /// are `raw_api_request` and `download_file`, which are gated as write
+    /// tools, and the parameterless `get_upcoming_events` and `list_todo`,
+    /// which only ever go stale by the cache TTL. `health_check` always
+    /// skips the cache so its latency is a real round trip.
+    pub(crate) fn reader(&self, cache_bypass: Option<bool>) -> CanvasClient {
+        if cache_bypass.unwrap_or(false) {
+            self.client.without_cache()
+        } else {
+            self.client.clone()
+        }
+    }
+
+    /// Timezone dates are shown in, from `TIMEZONE` (UTC if unset)
+    pub(crate) fn timezone(&self) -> &str {
+        self.config.timezone.as_deref().unwrap_or("UTC")
+    }
+
+    /// Tools offered to clients: the tool box minus any disabled by
+    /// `CANVAS_DISABLED_TOOLS` or `CANVAS_READ_ONLY`
+    pub(crate) fn enabled_tools(&self) -> Vec<Tool> {
+        Self::tool_box()
+            .list()
+            .into_iter()
+            .filter(|tool| self.config.tool_enabled(&tool.name))
+            .collect()
+    }
+
+    rmcp::tool_box!(CanvasServer {
+        list_courses,
+        get_course,
+        list_assignments,
+        grade_submission,
+        bulk_grade,
+        list_submissions,
+        post_discussion_entry,
+        reveal_pseudonym,
+        health_check,
+        get_metrics,
+        list_enrollments,
+        get_gradebook,
+        export_gradebook_csv,
+        list_modules,
+        list_module_items,
+        mark_module_item_done,
+        list_discussion_topics,
+        get_discussion,
+        create_announcement,
+        list_pages,
+        get_page,
+        create_page,
+        update_page,
+        list_quizzes,
+        get_quiz,
+        list_quiz_questions,
+        list_files,
+        list_folders,
+        download_file,
+        list_calendar_events,
+        create_calendar_event,
+        get_syllabus,
+        update_syllabus,
+        send_message,
+        list_conversations,
+        list_sections,
+        list_groups,
+        list_group_members,
+        get_course_analytics,
+        get_student_analytics,
+        list_todo,
+        get_upcoming_events,
+        create_assignment,
+        update_assignment,
+        comment_on_submission,
+        raw_api_request,
+        missing_submissions,
+        late_submissions,
+        list_assignment_overrides,
+        grade_distribution,
+        export_calendar_ics,
+        list_rubrics,
+        get_rubric,
+        grade_submission_with_rubric,
+        submit_assignment,
+        get_submission,
+        list_assignment_groups,
+        search_account_users,
+        list_account_courses,
+        list_enrollment_terms,
+        get_assignment,
+    });
+}
+
+impl ServerHandler for CanvasServer {
+    async fn list_tools(
+        &self,
+        _request: PaginatedRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListToolsResult, McpError> {
+        Ok(ListToolsResult {
+            tools: self.enabled_tools(),
+            next_cursor: None,
+        })
+    }
+
+    async fn call_tool(
+        &self,
+        request: CallToolRequestParam,
+        context: RequestContext<RoleServer>,
+    ) -> Result<CallToolResult, McpError> {
+        if !self.config.tool_enabled(&request.name) {
+            return Err(McpError::invalid_request(
+                format!("Tool {} is disabled on this server", request.name),
+                None,
+            ));
+        }
+        Self::tool_box()
+            .call(ToolCallContext::new(self, request, context))
+            .await
+    }
+
+    async fn list_resources(
+        &self,
+        _request: PaginatedRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListResourcesResult, McpError> {
+        Ok(ListResourcesResult {
+            resources: self.course_resources().await?,
+            next_cursor: None,
+        })
+    }
+
+    async fn read_resource(
+        &self,
+        request: ReadResourceRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ReadResourceResult, McpError> {
+        self.read_course_resource(&request.uri).await
+    }
+
+    async fn list_prompts(
+        &self,
+        _request: PaginatedRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<ListPromptsResult, McpError> {
+        Ok(ListPromptsResult {
+            prompts: prompts::list_prompts(),
+            next_cursor: None,
+        })
+    }
+
+    async fn get_prompt(
+        &self,
+        request: GetPromptRequestParam,
+        _context: RequestContext<RoleServer>,
+    ) -> Result<GetPromptResult, McpError> {
+        prompts::get_prompt(&request.name, request.arguments.as_ref())
+    }
+
+    fn get_info(&self) -> ServerInfo {
+        let instructions = match &self.config.institution_name {
+            Some(name) => format!("Tools for working with the {} Canvas LMS.", name),
+            None => "Tools for working with Canvas LMS.".to_string(),
+        };
+
+        ServerInfo {
+            protocol_version: ProtocolVersion::default(),
+            capabilities: ServerCapabilities::builder()
+                .enable_tools()
+                .enable_resources()
+                .enable_prompts()
+                .build(),
+            server_info: Implementation {
+                name: env!("CARGO_PKG_NAME").to_string(),
+                version: env!("CARGO_PKG_VERSION").to_string(),
+            },
+            instructions: Some(instructions),
+        }
+    }
+}
+
+/// Check that the `CANVAS_API_URL` host resolves
+///
+/// Skipped when a proxy is configured, since the proxy does the lookup and
+/// the host may not resolve locally.
+async fn resolve_api_host(config: &CanvasConfig) -> crate::Result<()> {
+    check_api_host(config, |host, port| async move {
+        tokio::net::lookup_host((host.as_str(), port))
+            .await
+            .map(|_| ())
+    })
+    .await
+}
+
+/// `resolve_api_host` with the DNS lookup supplied by the caller
+async fn check_api_host<F, Fut>(config: &CanvasConfig, lookup: F) -> crate::Result<()>
+where
+    F: FnOnce(String, u16) -> Fut,
+    Fut: std::future::Future<Output = std::io::Result<()>>,
+{
+    if config.http_proxy.is_some() || config.https_proxy.is_some() {
+        return Ok(());
+    }
+    let url = url::Url::parse(&config.api_url)
+        .map_err(|e| CanvasError::config(format!("Invalid CANVAS_API_URL: {}", e)))?;
+    let (Some(host), Some(port)) = (url.host_str(), url.port_or_known_default()) else {
+        return Err(CanvasError::config("CANVAS_API_URL has no host"));
+    };
+
+    match lookup(host.to_string(), port).await {
+        Ok(_) => Ok(()),
+        Err(e) => Err(CanvasError::config(format!(
+            "Could not resolve {} from CANVAS_API_URL ({}). Check the address for typos; it should be your Canvas site, e.g. https://school.instructure.com",
+            host, e
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_new_validated_rejects_bad_token() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/users/self")
+            .with_status(401)
+            .with_header("www-authenticate", "Bearer realm=\"canvas-lms\"")
+            .with_body(r#"{"errors": [{"message": "Invalid access token."}]}"#)
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("bad".to_string(), server.url()));
+        let error = CanvasServer::new_validated(config).await.err().unwrap();
+
+        assert!(matches!(error, CanvasError::Auth(_)));
+        assert!(error.to_string().contains("rejected the API token"));
+    }
+
+    #[tokio::test]
+    async fn test_check_api_host_rejects_unresolvable_host() {
+        let unresolvable = |host: String, port: u16| async move {
+            assert_eq!((host.as_str(), port), ("canvas.invalid", 443));
+            Err(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                "no such host",
+            ))
+        };
+        let mut config =
+            CanvasConfig::new("token".to_string(), "https://canvas.invalid".to_string());
+        let error = check_api_host(&config, unresolvable).await.err().unwrap();
+
+        assert!(matches!(error, CanvasError::Config(_)));
+        assert!(error
+            .to_string()
+            .contains("Could not resolve canvas.invalid"));
+
+        // A proxy resolves the host itself
+        config.https_proxy = Some("http://proxy.test:3128".to_string());
+        assert!(check_api_host(&config, unresolvable).await.is_ok());
+    }
+
+    #[test]
+    fn test_disabled_and_read_only_tools_are_hidden() {
+        let mut config = CanvasConfig::new("token".to_string(), "https://canvas.test".to_string());
+        config.disabled_tools = vec!["list_pages".to_string()];
+        let server = CanvasServer::new(Arc::new(config.clone())).unwrap();
+        let names: Vec<String> = server
+            .enabled_tools()
+            .into_iter()
+            .map(|tool| tool.name.to_string())
+            .collect();
+        assert!(!names.contains(&"list_pages".to_string()));
+        assert!(!names.contains(&"raw_api_request".to_string()));
+
+        config.enable_raw_api = true;
+        let server = CanvasServer::new(Arc::new(config.clone())).unwrap();
+        let names: Vec<String> = server
+            .enabled_tools()
+            .into_iter()
+            .map(|tool| tool.name.to_string())
+            .collect();
+        for write in crate::tools::WRITE_TOOLS {
+            assert!(
+                names.contains(&write.to_string()),
+                "{} is not a tool",
+                write
+            );
+        }
+
+        config.read_only = true;
+        let server = CanvasServer::new(Arc::new(config)).unwrap();
+        let names: Vec<String> = server
+            .enabled_tools()
+            .into_iter()
+            .map(|tool| tool.name.to_string())
+            .collect();
+        assert!(names.contains(&"list_courses".to_string()));
+        for write in crate::tools::WRITE_TOOLS {
+            assert!(!names.contains(&write.to_string()), "{} is listed", write);
+        }
+    }
+}
@@ -0,0 +1,359 @@
+use super::{tool_result, validate_id};
+use crate::error::CanvasError;
+use crate::models::{ActivityDay, AssignmentAnalytics, FlexibleId, StudentAssignmentAnalytics};
+use crate::server::CanvasServer;
+use rmcp::model::CallToolResult;
+use rmcp::{schemars, tool, Error as McpError};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetCourseAnalyticsParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetStudentAnalyticsParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Canvas user ID of the student
+    pub user_id: FlexibleId,
+
+    /// Show the student's real Canvas ID even when anonymization is enabled,
+    /// for advising conversations (default false)
+    pub reveal_identity: Option<bool>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+/// Missing assignments at or above which a student is flagged
+const AT_RISK_MISSING: usize = 2;
+
+/// Late share of submitted work at or above which a student is flagged
+const AT_RISK_LATE_SHARE: f64 = 0.3;
+
+/// How far below the class median (as a fraction of points possible) the
+/// student's average can fall before being flagged
+const AT_RISK_BELOW_MEDIAN: f64 = 0.15;
+
+/// A student's per-assignment analytics with an at-risk assessment
+#[derive(Debug, Serialize)]
+struct StudentAnalytics {
+    /// Canvas user ID, or a pseudonym when anonymized
+    student: String,
+    on_time: usize,
+    late: usize,
+    missing: usize,
+    at_risk: bool,
+    reasons: Vec<String>,
+    assignments: Vec<StudentAssignmentAnalytics>,
+}
+
+impl StudentAnalytics {
+    fn new(student: String, assignments: Vec<StudentAssignmentAnalytics>) -> Self {
+        let count = |status: &str| {
+            assignments
+                .iter()
+                .filter(|a| a.status.as_deref() == Some(status))
+                .count()
+        };
+        let (on_time, late, missing) = (count("on_time"), count("late"), count("missing"));
+
+        let mut reasons = Vec::new();
+        if missing >= AT_RISK_MISSING {
+            reasons.push(format!("{} missing assignments", missing));
+        }
+        let submitted = on_time + late;
+        if submitted > 0 && late as f64 / submitted as f64 >= AT_RISK_LATE_SHARE {
+            reasons.push(format!("{} of {} submissions late", late, submitted));
+        }
+        // Average gap to the class median, as a fraction of points possible
+        let gaps: Vec<f64> = assignments
+            .iter()
+            .filter_map(|a| {
+                let score = a.submission.as_ref()?.score?;
+                let points = a.points_possible.filter(|p| *p > 0.0)?;
+                Some((score - a.median?) / points)
+            })
+            .collect();
+        if !gaps.is_empty() {
+            let gap = gaps.iter().sum::<f64>() / gaps.len() as f64;
+            if gap <= -AT_RISK_BELOW_MEDIAN {
+                reasons.push(format!(
+                    "scores average {:.0} points per 100 below the class median",
+                    -gap * 100.0
+                ));
+            }
+        }
+
+        Self {
+            student,
+            on_time,
+            late,
+            missing,
+            at_risk: !reasons.is_empty(),
+            reasons,
+            assignments,
+        }
+    }
+}
+
+/// Course activity by day plus per-assignment score distributions
+#[derive(Debug, Serialize)]
+struct CourseAnalytics {
+    activity: Vec<ActivityDay>,
+    assignments: Vec<AssignmentAnalytics>,
+}
+
+/// Explain the 403 Canvas returns when analytics are turned off
+pub(crate) fn analytics_error(e: CanvasError) -> CanvasError {
+    match e {
+        CanvasError::Auth(message) => CanvasError::auth(format!(
+            "{}. Course analytics may be disabled for this account, or the token lacks the \"View usage reports\" permission",
+            message
+        )),
+        other => other,
+    }
+}
+
+impl CanvasServer {
+    #[tool(
+        description = "Course engagement analytics: daily page views and participations, and each assignment's score distribution (min/median/max) and on-time/late/missing rates"
+    )]
+    pub(crate) async fn get_course_analytics(
+        &self,
+        #[tool(aggr)] params: GetCourseAnalyticsParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+
+        let base = format!("/courses/{}/analytics", params.course_id.path_segment());
+        let activity_path = format!("{}/activity", base);
+        let assignments_path = format!("{}/assignments", base);
+        let client = self.reader(params.cache_bypass);
+        let (activity, assignments) = tokio::try_join!(
+            client.get::<Vec<ActivityDay>>(&activity_path),
+            client.get::<Vec<AssignmentAnalytics>>(&assignments_path),
+        )
+        .map_err(analytics_error)?;
+
+        let views: u64 = activity.iter().map(|d| d.views).sum();
+        let participations: u64 = activity.iter().map(|d| d.participations).sum();
+        let mut summary = format!(
+            "Activity over {} day(s): {} page views, {} participations",
+            activity.len(),
+            views,
+            participations
+        );
+        if let Some(busiest) = activity.iter().max_by_key(|d| d.views) {
+            summary.push_str(&format!(
+                " (busiest {} with {} views)",
+                busiest.date, busiest.views
+            ));
+        }
+
+        summary.push_str(&format!("\n\n{} assignment(s):", assignments.len()));
+        let score = |s: Option<f64>| s.map(|s| s.to_string()).unwrap_or_else(|| "-".to_string());
+        for assignment in &assignments {
+            summary.push_str(&format!(
+                "\n- {}: min {}, median {}, max {}",
+                assignment.title,
+                score(assignment.min_score),
+                score(assignment.median),
+                score(assignment.max_score)
+            ));
+            if let Some(points) = assignment.points_possible {
+                summary.push_str(&format!(" of {}", points));
+            }
+            if let Some(t) = &assignment.tardiness_breakdown {
+                summary.push_str(&format!(
+                    "; {:.0}% on time, {:.0}% late, {:.0}% missing",
+                    t.on_time * 100.0,
+                    t.late * 100.0,
+                    t.missing * 100.0
+                ));
+            }
+        }
+
+        tool_result(
+            summary,
+            &CourseAnalytics {
+                activity,
+                assignments,
+            },
+        )
+    }
+
+    #[tool(
+        description = "A student's assignment analytics: on-time/late/missing status and score vs. class median per assignment, with an at-risk summary"
+    )]
+    pub(crate) async fn get_student_analytics(
+        &self,
+        #[tool(aggr)] params: GetStudentAnalyticsParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        validate_id("user_id", &params.user_id)?;
+
+        let path = format!(
+            "/courses/{}/analytics/users/{}/assignments",
+            params.course_id.path_segment(),
+            params.user_id.path_segment()
+        );
+        let assignments: Vec<StudentAssignmentAnalytics> = self
+            .reader(params.cache_bypass)
+            .get(&path)
+            .await
+            .map_err(analytics_error)?;
+
+        let student = match self.client.anonymizer() {
+            Some(anonymizer) if !params.reveal_identity.unwrap_or(false) => {
+                anonymizer.pseudonym(&params.user_id.to_string())
+            }
+            _ => format!("user {}", params.user_id),
+        };
+        let analytics = StudentAnalytics::new(student, assignments);
+
+        let mut summary = format!(
+            "{}: {} on time, {} late, {} missing",
+            analytics.student, analytics.on_time, analytics.late, analytics.missing
+        );
+        if analytics.at_risk {
+            summary.push_str(&format!("\nAt risk: {}", analytics.reasons.join("; ")));
+        } else {
+            summary.push_str("\nNot flagged as at risk");
+        }
+        let score = |s: Option<f64>| s.map(|s| s.to_string()).unwrap_or_else(|| "-".to_string());
+        for assignment in &analytics.assignments {
+            summary.push_str(&format!(
+                "\n- {} [{}]: {} vs. median {}",
+                assignment.title,
+                assignment.status.as_deref().unwrap_or("unknown"),
+                score(assignment.submission.as_ref().and_then(|s| s.score)),
+                score(assignment.median)
+            ));
+        }
+
+        tool_result(summary, &analytics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_get_course_analytics() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/courses/1/analytics/activity")
+            .with_body(
+                r#"[{"date": "2025-09-01", "views": 40, "participations": 5},
+                    {"date": "2025-09-02", "views": 90, "participations": 12}]"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/api/v1/courses/1/analytics/assignments")
+            .with_body(
+                r#"[{"assignment_id": 7, "title": "Essay 1", "points_possible": 10,
+                     "min_score": 4, "median": 8.5, "max_score": 10,
+                     "tardiness_breakdown": {"on_time": 0.8, "late": 0.15, "missing": 0.05, "total": 20}}]"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .get_course_analytics(GetCourseAnalyticsParams {
+                course_id: FlexibleId::from(1),
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.starts_with(
+            "Activity over 2 day(s): 130 page views, 17 participations (busiest 2025-09-02 with 90 views)"
+        ));
+        assert!(summary.contains(
+            "- Essay 1: min 4, median 8.5, max 10 of 10; 80% on time, 15% late, 5% missing"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_student_analytics_flags_risk_and_anonymizes() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/courses/1/analytics/users/42/assignments")
+            .expect(2)
+            .with_body(
+                r#"[{"assignment_id": 7, "title": "Essay 1", "points_possible": 10, "median": 8,
+                     "status": "late", "submission": {"score": 5}},
+                    {"assignment_id": 8, "title": "Quiz 1", "points_possible": 10, "median": 9,
+                     "status": "missing", "submission": {"score": null}},
+                    {"assignment_id": 9, "title": "Quiz 2", "points_possible": 10, "median": 9,
+                     "status": "missing"}]"#,
+            )
+            .create_async()
+            .await;
+
+        let mut config = CanvasConfig::new("token".to_string(), server.url());
+        config.enable_anonymization = true;
+        config.anonymization_salt = "salt".to_string();
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+
+        let params = |reveal| GetStudentAnalyticsParams {
+            course_id: FlexibleId::from(1),
+            user_id: FlexibleId::from(42),
+            reveal_identity: reveal,
+            cache_bypass: None,
+        };
+        let result = canvas.get_student_analytics(params(None)).await.unwrap();
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.starts_with(&format!(
+            "{}: 0 on time, 1 late, 2 missing",
+            crate::anonymize::pseudonym("42", "salt")
+        )));
+        assert!(summary.contains(
+            "At risk: 2 missing assignments; 1 of 1 submissions late; scores average 30 points per 100 below the class median"
+        ));
+
+        let revealed = canvas
+            .get_student_analytics(params(Some(true)))
+            .await
+            .unwrap();
+        let summary = revealed.content[0].as_text().unwrap().text.clone();
+        assert!(summary.starts_with("user 42:"));
+    }
+
+    #[tokio::test]
+    async fn test_analytics_disabled_is_explained() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", mockito::Matcher::Regex("/analytics/".to_string()))
+            .with_status(403)
+            .with_body(r#"{"errors": [{"message": "user not authorized to perform that action"}]}"#)
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let error = canvas
+            .get_course_analytics(GetCourseAnalyticsParams {
+                course_id: FlexibleId::from(1),
+                cache_bypass: None,
+            })
+            .await
+            .unwrap_err();
+        assert!(error.message.contains("analytics may be disabled"));
+    }
+}
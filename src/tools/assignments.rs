@@ -0,0 +1,921 @@
+use super::{check_choice, content_format, due_label, tool_result, validate_id};
+use crate::client::QueryBuilder;
+use crate::datetime::{format_local, parse_datetime};
+use crate::error::CanvasError;
+use crate::models::{Assignment, AssignmentGroup, AssignmentOverride, FlexibleId};
+use crate::render::render;
+use crate::server::CanvasServer;
+use chrono::{DateTime, Utc};
+use rmcp::model::CallToolResult;
+use rmcp::{schemars, tool, Error as McpError};
+use serde::{Deserialize, Serialize};
+
+const BUCKETS: &[&str] = &[
+    "past",
+    "overdue",
+    "undated",
+    "ungraded",
+    "unsubmitted",
+    "upcoming",
+    "future",
+];
+
+const ORDER_BY: &[&str] = &["position", "name", "due_at"];
+
+/// Submission types Canvas accepts when creating or updating an assignment
+const SUBMISSION_TYPES: &[&str] = &[
+    "online_text_entry",
+    "online_upload",
+    "online_url",
+    "media_recording",
+    "student_annotation",
+    "online_quiz",
+    "discussion_topic",
+    "external_tool",
+    "on_paper",
+    "none",
+];
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListAssignmentsParams {
+    /// Canvas course ID, or an SIS reference such as "sis_course_id:BIOL101"
+    pub course_id: FlexibleId,
+
+    /// Only return assignments in this bucket: "past", "overdue", "undated",
+    /// "ungraded", "unsubmitted", "upcoming", or "future"
+    pub bucket: Option<String>,
+
+    /// Sort order: "position" (default), "name", or "due_at"
+    pub order_by: Option<String>,
+
+    /// Extra data to include, e.g. "overrides", "all_dates", "score_statistics"
+    /// (the current user's submission is always included)
+    pub include: Option<Vec<String>>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetAssignmentParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Canvas assignment ID
+    pub assignment_id: FlexibleId,
+
+    /// How to return the description: "markdown" (default), "text", or "html"
+    pub format: Option<String>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct CreateAssignmentParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Assignment name
+    pub name: String,
+
+    /// Assignment description (HTML)
+    pub description: Option<String>,
+
+    /// Maximum points
+    pub points_possible: Option<f64>,
+
+    /// Due date as an RFC 3339 timestamp, e.g. "2025-10-10T23:59:00-07:00"
+    pub due_at: Option<String>,
+
+    /// How students submit, any of "online_text_entry", "online_upload",
+    /// "online_url", "media_recording", "student_annotation", "online_quiz",
+    /// "discussion_topic", "external_tool", "on_paper", or "none"
+    pub submission_types: Option<Vec<String>>,
+
+    /// Assignment group to put the assignment in (defaults to the course's first group)
+    pub assignment_group_id: Option<FlexibleId>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct UpdateAssignmentParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Assignment ID
+    pub assignment_id: FlexibleId,
+
+    /// New name
+    pub name: Option<String>,
+
+    /// Publish (true) or unpublish (false) the assignment
+    pub published: Option<bool>,
+
+    /// New due date as an RFC 3339 timestamp, or "" to remove the due date
+    pub due_at: Option<String>,
+
+    /// New maximum points
+    pub points_possible: Option<f64>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListAssignmentOverridesParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Assignment ID
+    pub assignment_id: FlexibleId,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListAssignmentGroupsParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// List the assignments in each group (default true)
+    pub include_assignments: Option<bool>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+/// Result of `list_assignment_groups`
+#[derive(Debug, Serialize)]
+struct AssignmentGroupList {
+    course_id: FlexibleId,
+    /// Whether the course weights groups (`apply_assignment_group_weights`)
+    weighted: bool,
+    groups: Vec<AssignmentGroupRow>,
+}
+
+#[derive(Debug, Serialize)]
+struct AssignmentGroupRow {
+    id: FlexibleId,
+    name: String,
+    /// Share of the final grade; only meaningful when the course is weighted
+    group_weight: Option<f64>,
+    drop_lowest: Option<u32>,
+    drop_highest: Option<u32>,
+    points_possible: f64,
+    assignments: Vec<GroupAssignment>,
+}
+
+#[derive(Debug, Serialize)]
+struct GroupAssignment {
+    id: FlexibleId,
+    name: String,
+    points_possible: Option<f64>,
+    due_at: Option<DateTime<Utc>>,
+    published: bool,
+}
+
+impl From<AssignmentGroup> for AssignmentGroupRow {
+    fn from(group: AssignmentGroup) -> Self {
+        let rules = group.rules.unwrap_or_default();
+        Self {
+            id: group.id,
+            name: group.name,
+            group_weight: group.group_weight,
+            drop_lowest: rules.drop_lowest.filter(|n| *n > 0),
+            drop_highest: rules.drop_highest.filter(|n| *n > 0),
+            points_possible: group
+                .assignments
+                .iter()
+                .filter_map(|a| a.points_possible)
+                .sum(),
+            assignments: group
+                .assignments
+                .into_iter()
+                .map(|a| GroupAssignment {
+                    id: a.id,
+                    name: a.name,
+                    points_possible: a.points_possible,
+                    due_at: a.due_at,
+                    published: a.published,
+                })
+                .collect(),
+        }
+    }
+}
+
+/// One field changed by `update_assignment`
+#[derive(Debug, Serialize)]
+struct FieldChange {
+    field: &'static str,
+    before: serde_json::Value,
+    after: serde_json::Value,
+}
+
+/// Result of `update_assignment`: what changed, plus the updated assignment
+#[derive(Debug, Serialize)]
+struct AssignmentUpdate {
+    changes: Vec<FieldChange>,
+    warnings: Vec<String>,
+    assignment: Assignment,
+}
+
+/// Compact assignment listing returned by `list_assignments`
+#[derive(Debug, Serialize)]
+struct AssignmentRow {
+    id: FlexibleId,
+    name: String,
+    due_at: Option<DateTime<Utc>>,
+    due_local: Option<String>,
+    points_possible: Option<f64>,
+    submission_status: Option<String>,
+    needs_grading_count: Option<u64>,
+    /// Date overrides, when requested with `include: ["overrides"]`
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    overrides: Vec<AssignmentOverride>,
+}
+
+impl CanvasServer {
+    #[tool(
+        description = "List assignments in a course, optionally filtered by bucket (past, overdue, undated, ungraded, unsubmitted, upcoming, future)"
+    )]
+    pub(crate) async fn list_assignments(
+        &self,
+        #[tool(aggr)] params: ListAssignmentsParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+
+        let mut query = QueryBuilder::new();
+        query.include("submission");
+        for include in params.include.iter().flatten() {
+            query.include(include.as_str());
+        }
+        if let Some(bucket) = &params.bucket {
+            check_choice("bucket", bucket, BUCKETS)?;
+            query.param("bucket", bucket);
+        }
+        if let Some(order_by) = &params.order_by {
+            check_choice("order_by", order_by, ORDER_BY)?;
+            query.param("order_by", order_by);
+        }
+        let path = query.to_path(&format!(
+            "/courses/{}/assignments",
+            params.course_id.path_segment()
+        ));
+
+        let assignments: Vec<Assignment> = self.reader(params.cache_bypass).get_all(&path).await?;
+
+        let tz = self.timezone();
+        let rows: Vec<AssignmentRow> = assignments
+            .into_iter()
+            .map(|a| AssignmentRow {
+                due_local: a.due_at.map(|due| format_local(due, tz)),
+                submission_status: a.submission.as_ref().and_then(|s| s.workflow_state.clone()),
+                id: a.id,
+                name: a.name,
+                due_at: a.due_at,
+                points_possible: a.points_possible,
+                needs_grading_count: a.needs_grading_count,
+                overrides: a.overrides,
+            })
+            .collect();
+
+        let mut summary = format!("Found {} assignment(s):", rows.len());
+        for row in &rows {
+            summary.push_str(&format!("\n- {} (ID {})", row.name, row.id));
+            summary.push_str(&format!(" — {}", due_label(row.due_at, tz)));
+            if let Some(points) = row.points_possible {
+                summary.push_str(&format!(", {} pts", points));
+            }
+            if let Some(status) = &row.submission_status {
+                summary.push_str(&format!(", {}", status));
+            }
+            if let Some(count) = row.needs_grading_count.filter(|c| *c > 0) {
+                summary.push_str(&format!(", {} to grade", count));
+            }
+            if !row.overrides.is_empty() {
+                summary.push_str(&format!(", {} date override(s)", row.overrides.len()));
+            }
+        }
+
+        tool_result(summary, &rows)
+    }
+
+    #[tool(
+        description = "Get one assignment with its description, due date, points, and submission types"
+    )]
+    pub(crate) async fn get_assignment(
+        &self,
+        #[tool(aggr)] params: GetAssignmentParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        validate_id("assignment_id", &params.assignment_id)?;
+        let format = content_format(params.format.as_deref(), "markdown")?;
+
+        let mut assignment = self
+            .reader(params.cache_bypass)
+            .get_assignment(&params.course_id, &params.assignment_id)
+            .await?;
+        assignment.description = assignment
+            .description
+            .as_deref()
+            .map(|description| render(description, &format));
+
+        let tz = self.timezone();
+        let mut summary = format!(
+            "{} (ID {}) — {}",
+            assignment.name,
+            assignment.id,
+            due_label(assignment.due_at, tz)
+        );
+        if let Some(points) = assignment.points_possible {
+            summary.push_str(&format!(", {} pts", points));
+        }
+        if !assignment.submission_types.is_empty() {
+            summary.push_str(&format!(
+                "\nSubmission types: {}",
+                assignment.submission_types.join(", ")
+            ));
+        }
+        if let Some(description) = assignment
+            .description
+            .as_deref()
+            .filter(|d| !d.trim().is_empty())
+        {
+            summary.push_str(&format!("\n\n{}", description.trim()));
+        }
+
+        tool_result(summary, &assignment)
+    }
+
+    #[tool(
+        description = "Create an assignment in a course (unpublished), with optional description, points, due date, submission types, and assignment group"
+    )]
+    pub(crate) async fn create_assignment(
+        &self,
+        #[tool(aggr)] params: CreateAssignmentParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        let name = params.name.trim();
+        if name.is_empty() {
+            return Err(McpError::from(CanvasError::InvalidParameter(
+                "name must not be empty".to_string(),
+            )));
+        }
+
+        let mut form = vec![("assignment[name]", name.to_string())];
+        if let Some(description) = &params.description {
+            form.push(("assignment[description]", description.clone()));
+        }
+        if let Some(points) = params.points_possible {
+            if points < 0.0 {
+                return Err(McpError::from(CanvasError::InvalidParameter(format!(
+                    "points_possible must not be negative (got {})",
+                    points
+                ))));
+            }
+            form.push(("assignment[points_possible]", points.to_string()));
+        }
+        if let Some(due_at) = &params.due_at {
+            let due_at = parse_datetime("due_at", due_at)?;
+            form.push(("assignment[due_at]", due_at.to_rfc3339()));
+        }
+        for submission_type in params.submission_types.iter().flatten() {
+            check_choice("submission_types", submission_type, SUBMISSION_TYPES)?;
+            form.push(("assignment[submission_types][]", submission_type.clone()));
+        }
+        if let Some(group_id) = &params.assignment_group_id {
+            validate_id("assignment_group_id", group_id)?;
+            form.push(("assignment[assignment_group_id]", group_id.to_string()));
+        }
+
+        let path = format!("/courses/{}/assignments", params.course_id.path_segment());
+        let assignment: Assignment = self.client.post_form(&path, &form).await?;
+
+        let mut summary = format!(
+            "Created assignment \"{}\" (ID {}) — {}",
+            assignment.name,
+            assignment.id,
+            due_label(assignment.due_at, self.timezone())
+        );
+        if let Some(url) = &assignment.html_url {
+            summary.push_str(&format!("\n{}", url));
+        }
+
+        self.write_result(summary, &assignment)
+    }
+
+    #[tool(
+        description = "Update an assignment's name, published state, due date, or points. Only the fields given are changed; returns a before/after diff."
+    )]
+    pub(crate) async fn update_assignment(
+        &self,
+        #[tool(aggr)] params: UpdateAssignmentParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        validate_id("assignment_id", &params.assignment_id)?;
+
+        let mut form = Vec::new();
+        if let Some(name) = &params.name {
+            let name = name.trim();
+            if name.is_empty() {
+                return Err(McpError::from(CanvasError::InvalidParameter(
+                    "name must not be empty".to_string(),
+                )));
+            }
+            form.push(("assignment[name]", name.to_string()));
+        }
+        if let Some(published) = params.published {
+            form.push(("assignment[published]", published.to_string()));
+        }
+        if let Some(due_at) = &params.due_at {
+            let due_at = if due_at.trim().is_empty() {
+                String::new()
+            } else {
+                parse_datetime("due_at", due_at)?.to_rfc3339()
+            };
+            form.push(("assignment[due_at]", due_at));
+        }
+        if let Some(points) = params.points_possible {
+            if points < 0.0 {
+                return Err(McpError::from(CanvasError::InvalidParameter(format!(
+                    "points_possible must not be negative (got {})",
+                    points
+                ))));
+            }
+            form.push(("assignment[points_possible]", points.to_string()));
+        }
+        if form.is_empty() {
+            return Err(McpError::from(CanvasError::InvalidParameter(
+                "Nothing to update: set at least one of name, published, due_at, points_possible"
+                    .to_string(),
+            )));
+        }
+
+        let before = self
+            .client
+            .get_assignment(&params.course_id, &params.assignment_id)
+            .await?;
+        let path = format!(
+            "/courses/{}/assignments/{}",
+            params.course_id.path_segment(),
+            params.assignment_id.path_segment()
+        );
+        let after: Assignment = self.client.put_form(&path, &form).await?;
+
+        let mut changes = Vec::new();
+        let mut diff = |field, before: serde_json::Value, after: serde_json::Value| {
+            if before != after {
+                changes.push(FieldChange {
+                    field,
+                    before,
+                    after,
+                });
+            }
+        };
+        diff(
+            "name",
+            before.name.clone().into(),
+            after.name.clone().into(),
+        );
+        diff("published", before.published.into(), after.published.into());
+        diff(
+            "due_at",
+            serde_json::json!(before.due_at),
+            serde_json::json!(after.due_at),
+        );
+        diff(
+            "points_possible",
+            before.points_possible.into(),
+            after.points_possible.into(),
+        );
+
+        let mut warnings = Vec::new();
+        if params.published == Some(true) && after.submission_types.is_empty() {
+            warnings.push(
+                "Published with no submission types set; students will have no way to submit"
+                    .to_string(),
+            );
+        }
+
+        let tz = self.timezone();
+        let mut summary = if changes.is_empty() {
+            format!("Updated {} (ID {}): no changes", after.name, after.id)
+        } else {
+            format!("Updated {} (ID {}):", after.name, after.id)
+        };
+        for change in &changes {
+            let describe = |value: &serde_json::Value| match (change.field, value) {
+                (_, serde_json::Value::Null) => "none".to_string(),
+                ("due_at", serde_json::Value::String(at)) => parse_datetime("due_at", at)
+                    .map(|at| format_local(at, tz))
+                    .unwrap_or_else(|_| at.clone()),
+                (_, serde_json::Value::String(text)) => text.clone(),
+                (_, other) => other.to_string(),
+            };
+            summary.push_str(&format!(
+                "\n- {}: {} -> {}",
+                change.field,
+                describe(&change.before),
+                describe(&change.after)
+            ));
+        }
+        for warning in &warnings {
+            summary.push_str(&format!("\nWarning: {}", warning));
+        }
+
+        self.write_result(
+            summary,
+            &AssignmentUpdate {
+                changes,
+                warnings,
+                assignment: after,
+            },
+        )
+    }
+
+    #[tool(
+        description = "List an assignment's due-date overrides: extended or different dates for specific students (e.g. accommodations), sections, or groups"
+    )]
+    pub(crate) async fn list_assignment_overrides(
+        &self,
+        #[tool(aggr)] params: ListAssignmentOverridesParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        validate_id("assignment_id", &params.assignment_id)?;
+
+        let path = format!(
+            "/courses/{}/assignments/{}/overrides",
+            params.course_id.path_segment(),
+            params.assignment_id.path_segment()
+        );
+        let overrides: Vec<AssignmentOverride> =
+            self.reader(params.cache_bypass).get_all(&path).await?;
+
+        let tz = self.timezone();
+        let mut summary = format!(
+            "{} override(s) for assignment {}:",
+            overrides.len(),
+            params.assignment_id
+        );
+        for o in &overrides {
+            let applies_to = if !o.student_ids.is_empty() {
+                let ids: Vec<String> = o.student_ids.iter().map(|id| id.to_string()).collect();
+                format!("students {}", ids.join(", "))
+            } else if let Some(section) = &o.course_section_id {
+                format!("section {}", section)
+            } else if let Some(group) = &o.group_id {
+                format!("group {}", group)
+            } else {
+                "nobody".to_string()
+            };
+            summary.push_str(&format!(
+                "\n- {} ({}): {}",
+                o.title.as_deref().unwrap_or("Override"),
+                applies_to,
+                due_label(o.due_at, tz)
+            ));
+            if let Some(unlock_at) = o.unlock_at {
+                summary.push_str(&format!(", unlocks {}", format_local(unlock_at, tz)));
+            }
+            if let Some(lock_at) = o.lock_at {
+                summary.push_str(&format!(", locks {}", format_local(lock_at, tz)));
+            }
+        }
+
+        tool_result(summary, &overrides)
+    }
+
+    #[tool(
+        description = "List a course's assignment groups (grade categories) with their weights, drop rules, and the assignments in each"
+    )]
+    pub(crate) async fn list_assignment_groups(
+        &self,
+        #[tool(aggr)] params: ListAssignmentGroupsParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        let include_assignments = params.include_assignments.unwrap_or(true);
+
+        let mut query = QueryBuilder::new();
+        if include_assignments {
+            query.include("assignments");
+        }
+        let path = query.to_path(&format!(
+            "/courses/{}/assignment_groups",
+            params.course_id.path_segment()
+        ));
+        let client = self.reader(params.cache_bypass);
+        let (course, mut groups) = tokio::try_join!(
+            client.get_course(&params.course_id),
+            client.get_all::<AssignmentGroup>(&path),
+        )?;
+        groups.sort_by_key(|g| g.position.unwrap_or(u32::MAX));
+
+        let weighted = course.apply_assignment_group_weights.unwrap_or(false);
+        let groups: Vec<AssignmentGroupRow> = groups.into_iter().map(Into::into).collect();
+
+        let mut summary = if weighted {
+            let total: f64 = groups.iter().filter_map(|g| g.group_weight).sum();
+            let mut line = format!(
+                "{} weights its {} assignment group(s); weights total {}%",
+                course.name,
+                groups.len(),
+                total
+            );
+            if (total - 100.0).abs() > 0.01 {
+                line.push_str(" (Canvas scales grades when weights don't total 100%)");
+            }
+            line
+        } else {
+            format!(
+                "{} has {} assignment group(s) and does not weight them; final grades are total points",
+                course.name,
+                groups.len()
+            )
+        };
+        for group in &groups {
+            summary.push_str(&format!("\n- {}", group.name));
+            if weighted {
+                summary.push_str(&format!(" — {}%", group.group_weight.unwrap_or(0.0)));
+            }
+            if include_assignments {
+                summary.push_str(&format!(
+                    " ({} assignment(s), {} pts)",
+                    group.assignments.len(),
+                    group.points_possible
+                ));
+            }
+            if let Some(n) = group.drop_lowest {
+                summary.push_str(&format!(", drops lowest {}", n));
+            }
+            if let Some(n) = group.drop_highest {
+                summary.push_str(&format!(", drops highest {}", n));
+            }
+            for assignment in &group.assignments {
+                summary.push_str(&format!("\n  - {}", assignment.name));
+                if let Some(points) = assignment.points_possible {
+                    summary.push_str(&format!(" ({} pts)", points));
+                }
+            }
+        }
+
+        tool_result(
+            summary,
+            &AssignmentGroupList {
+                course_id: course.id,
+                weighted,
+                groups,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_list_assignments_in_bucket() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock(
+                "GET",
+                "/api/v1/courses/1/assignments?include[]=submission&bucket=overdue&per_page=100",
+            )
+            .with_body(
+                r#"[{"id": 7, "name": "Essay 1", "due_at": "2025-10-04T06:59:00Z",
+                     "points_possible": 10, "submission": {"workflow_state": "unsubmitted"}}]"#,
+            )
+            .create_async()
+            .await;
+
+        let mut config = CanvasConfig::new("token".to_string(), server.url());
+        config.timezone = Some("America/Los_Angeles".to_string());
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+
+        let result = canvas
+            .list_assignments(ListAssignmentsParams {
+                course_id: FlexibleId::from(1),
+                bucket: Some("overdue".to_string()),
+                order_by: None,
+                include: None,
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains("Essay 1 (ID 7) — due Fri, Oct 3 at 11:59 PM"));
+        assert!(summary.contains("unsubmitted"));
+    }
+
+    #[tokio::test]
+    async fn test_get_assignment_renders_description() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/courses/1/assignments/7")
+            .with_body(
+                r#"{"id": 7, "name": "Essay 1", "points_possible": 10,
+                    "submission_types": ["online_upload"],
+                    "description": "<p>Read <a href=\"https://example.com/ch1\">chapter 1</a></p><ul><li>500 words</li></ul>"}"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+        let params = |format: Option<&str>| GetAssignmentParams {
+            course_id: FlexibleId::from(1),
+            assignment_id: FlexibleId::from(7),
+            format: format.map(str::to_string),
+            cache_bypass: None,
+        };
+
+        let result = canvas.get_assignment(params(None)).await.unwrap();
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.starts_with("Essay 1 (ID 7) — no due date, 10 pts"));
+        assert!(summary.contains("[chapter 1](https://example.com/ch1)"));
+        assert!(crate::tools::result_json(&result)["description"]
+            .as_str()
+            .unwrap()
+            .contains("500 words"));
+
+        let result = canvas.get_assignment(params(Some("html"))).await.unwrap();
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains("<li>500 words</li>"));
+    }
+
+    #[tokio::test]
+    async fn test_create_assignment_sends_form_fields() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/api/v1/courses/1/assignments")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("assignment[name]".into(), "Lab 2".into()),
+                mockito::Matcher::UrlEncoded(
+                    "assignment[due_at]".into(),
+                    "2025-10-11T06:59:00+00:00".into(),
+                ),
+                mockito::Matcher::UrlEncoded(
+                    "assignment[submission_types][]".into(),
+                    "online_upload".into(),
+                ),
+            ]))
+            .with_body(
+                r#"{"id": 12, "name": "Lab 2", "due_at": "2025-10-11T06:59:00Z",
+                    "html_url": "https://canvas.example.edu/courses/1/assignments/12"}"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .create_assignment(CreateAssignmentParams {
+                course_id: FlexibleId::from(1),
+                name: "Lab 2".to_string(),
+                description: None,
+                points_possible: None,
+                due_at: Some("2025-10-10T23:59:00-07:00".to_string()),
+                submission_types: Some(vec!["online_upload".to_string()]),
+                assignment_group_id: None,
+            })
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.starts_with("Created assignment \"Lab 2\" (ID 12)"));
+        assert!(summary.contains("/courses/1/assignments/12"));
+    }
+
+    #[tokio::test]
+    async fn test_create_assignment_rejects_bad_due_at() {
+        let config = Arc::new(CanvasConfig::new(
+            "token".to_string(),
+            "https://example.instructure.com".to_string(),
+        ));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .create_assignment(CreateAssignmentParams {
+                course_id: FlexibleId::from(1),
+                name: "Lab 2".to_string(),
+                description: None,
+                points_possible: None,
+                due_at: Some("next Friday".to_string()),
+                submission_types: None,
+                assignment_group_id: None,
+            })
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_update_assignment_reports_diff_and_warns() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/courses/1/assignments/7")
+            .with_body(
+                r#"{"id": 7, "name": "Essay 1", "published": false,
+                    "due_at": "2025-10-04T06:59:00Z", "points_possible": 10}"#,
+            )
+            .create_async()
+            .await;
+        let put = server
+            .mock("PUT", "/api/v1/courses/1/assignments/7")
+            .match_body("assignment%5Bpublished%5D=true")
+            .with_body(
+                r#"{"id": 7, "name": "Essay 1", "published": true,
+                    "due_at": "2025-10-04T06:59:00Z", "points_possible": 10}"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .update_assignment(UpdateAssignmentParams {
+                course_id: FlexibleId::from(1),
+                assignment_id: FlexibleId::from(7),
+                name: None,
+                published: Some(true),
+                due_at: None,
+                points_possible: None,
+            })
+            .await
+            .unwrap();
+
+        put.assert_async().await;
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains("- published: false -> true"));
+        assert!(!summary.contains("due_at"));
+        assert!(summary.contains("Warning: Published with no submission types"));
+    }
+
+    #[tokio::test]
+    async fn test_list_assignments_rejects_unknown_bucket() {
+        let config = Arc::new(CanvasConfig::new(
+            "token".to_string(),
+            "https://example.instructure.com".to_string(),
+        ));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .list_assignments(ListAssignmentsParams {
+                course_id: FlexibleId::from(1),
+                bucket: Some("someday".to_string()),
+                order_by: None,
+                include: None,
+                cache_bypass: None,
+            })
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_list_assignment_groups_with_weights() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/courses/1")
+            .with_body(r#"{"id": 1, "name": "Biology", "apply_assignment_group_weights": true}"#)
+            .create_async()
+            .await;
+        server
+            .mock(
+                "GET",
+                "/api/v1/courses/1/assignment_groups?include[]=assignments&per_page=100",
+            )
+            .with_body(
+                r#"[{"id": 20, "name": "Exams", "position": 2, "group_weight": 60.0, "assignments": [
+                        {"id": 201, "name": "Midterm", "points_possible": 100}]},
+                    {"id": 10, "name": "Homework", "position": 1, "group_weight": 40.0,
+                     "rules": {"drop_lowest": 1}, "assignments": [
+                        {"id": 101, "name": "HW 1", "points_possible": 10},
+                        {"id": 102, "name": "HW 2", "points_possible": "10"}]}]"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+        let result = canvas
+            .list_assignment_groups(ListAssignmentGroupsParams {
+                course_id: FlexibleId::from(1),
+                include_assignments: None,
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(
+            summary.starts_with("Biology weights its 2 assignment group(s); weights total 100%")
+        );
+        assert!(summary.contains("- Homework — 40% (2 assignment(s), 20 pts), drops lowest 1"));
+        assert!(summary.contains("  - Midterm (100 pts)"));
+        assert!(summary.find("Homework") < summary.find("Exams"));
+        assert_eq!(crate::tools::result_json(&result)["weighted"], true);
+    }
+}
@@ -0,0 +1,591 @@
+use super::{tool_result, validate_id};
+use crate::client::QueryBuilder;
+use crate::datetime::{check_date, format_local, parse_datetime, parse_timezone, to_local};
+use crate::error::CanvasError;
+use crate::models::{CalendarEvent, FlexibleId};
+use crate::server::CanvasServer;
+use chrono::{DateTime, Duration, Offset, TimeZone, Utc};
+use chrono_tz::{OffsetComponents, OffsetName, Tz};
+use icalendar::{Calendar, CalendarDateTime, Component, EventLike};
+use rmcp::model::CallToolResult;
+use rmcp::{schemars, tool, Error as McpError};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListCalendarEventsParams {
+    /// Canvas course IDs whose calendars to include (at least one)
+    pub course_ids: Vec<FlexibleId>,
+
+    /// First day to include, e.g. "2025-10-01" (Canvas defaults to today)
+    pub start_date: Option<String>,
+
+    /// Last day to include, e.g. "2025-10-31"
+    pub end_date: Option<String>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct CreateCalendarEventParams {
+    /// Canvas course ID whose calendar gets the event
+    pub course_id: FlexibleId,
+
+    /// Event title, e.g. "Office hours"
+    pub title: String,
+
+    /// Start time as an RFC 3339 timestamp, e.g. "2025-10-06T15:00:00-06:00"
+    pub start_at: String,
+
+    /// End time as an RFC 3339 timestamp
+    pub end_at: Option<String>,
+
+    /// Event details (HTML or plain text)
+    pub description: Option<String>,
+
+    /// Where the event takes place, e.g. "Room 204" or a video link
+    pub location_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ExportCalendarIcsParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// First day to include, e.g. "2025-08-25" (default: the whole calendar)
+    pub start_date: Option<String>,
+
+    /// Last day to include, e.g. "2025-12-15"
+    pub end_date: Option<String>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+/// Calendar event with its times rendered in the configured timezone
+#[derive(Debug, Serialize)]
+struct EventRow {
+    #[serde(flatten)]
+    event: CalendarEvent,
+    start_local: Option<String>,
+    end_local: Option<String>,
+}
+
+impl EventRow {
+    fn new(event: CalendarEvent, tz: &str) -> Self {
+        Self {
+            start_local: event.start_at.map(|at| format_local(at, tz)),
+            end_local: event.end_at.map(|at| format_local(at, tz)),
+            event,
+        }
+    }
+
+    fn describe(&self) -> String {
+        let mut line = format!(
+            "{} (ID {}) — {}",
+            self.event.title,
+            self.event.id,
+            self.start_local.as_deref().unwrap_or("no start time")
+        );
+        // Assignment due dates come through with start == end
+        let has_span = !self.event.all_day && self.event.end_at != self.event.start_at;
+        if let Some(end) = self.end_local.as_deref().filter(|_| has_span) {
+            line.push_str(&format!(" to {}", end));
+        }
+        if let Some(location) = self
+            .event
+            .location_name
+            .as_deref()
+            .filter(|l| !l.is_empty())
+        {
+            line.push_str(&format!(" @ {}", location));
+        }
+        line
+    }
+}
+
+/// Calendar context code for a course, e.g. `course_123`
+///
+/// Canvas only accepts numeric IDs here, not SIS references; values that
+/// already carry the `course_` prefix are accepted as-is.
+fn course_context_code(course_id: &FlexibleId) -> Result<String, McpError> {
+    let text = course_id.to_string();
+    let id = text.trim().trim_start_matches("course_");
+    if !id.is_empty() && id.chars().all(|c| c.is_ascii_digit()) {
+        Ok(format!("course_{}", id))
+    } else {
+        Err(McpError::from(CanvasError::InvalidParameter(format!(
+            "Calendar course IDs must be numeric Canvas IDs (got {})",
+            course_id
+        ))))
+    }
+}
+
+/// Result of `export_calendar_ics`
+#[derive(Debug, Serialize)]
+struct CalendarExport {
+    /// Number of events and due dates in the calendar
+    events: usize,
+    ics: String,
+}
+
+/// Serialize events as an iCalendar (RFC 5545) document with one VEVENT each
+///
+/// Times are written in `tz` with a `TZID`, backed by a VTIMEZONE covering
+/// the events, so calendar apps show the same local times as Canvas. Events
+/// without a start time are skipped. UIDs combine the Canvas event ID with
+/// `host`, so re-importing updates events rather than duplicating them.
+fn to_ics(name: &str, events: &[CalendarEvent], tz: &str, host: &str) -> String {
+    let tz = parse_timezone(tz).name();
+    let mut calendar = Calendar::new();
+    calendar.name(name).timezone(tz);
+
+    for event in events {
+        let Some(start) = event.start_at else {
+            continue;
+        };
+        let mut vevent = icalendar::Event::new();
+        vevent
+            .uid(&format!("canvas-{}@{}", event.id, host))
+            .summary(&event.title);
+        if event.all_day {
+            vevent.all_day(to_local(start, tz).date_naive());
+        } else {
+            let end = event.end_at.unwrap_or(start);
+            vevent
+                .starts(CalendarDateTime::from(&to_local(start, tz)))
+                .ends(CalendarDateTime::from(&to_local(end, tz)));
+        }
+        if let Some(description) = event.description.as_deref().filter(|d| !d.is_empty()) {
+            vevent.description(crate::render::html_to_text(description).trim());
+        }
+        if let Some(location) = event.location_name.as_deref().filter(|l| !l.is_empty()) {
+            vevent.location(location);
+        }
+        if let Some(url) = &event.html_url {
+            vevent.url(url);
+        }
+        calendar.push(vevent.done());
+    }
+
+    let mut ics = calendar.done().to_string();
+    let timed = events.iter().filter(|event| !event.all_day);
+    let first = timed.clone().filter_map(|event| event.start_at).min();
+    let last = timed
+        .filter_map(|event| event.end_at.or(event.start_at))
+        .max();
+    // The icalendar crate has no VTIMEZONE component, so it goes in ahead of
+    // the events by hand
+    if let (Some(first), Some(last)) = (first, last) {
+        let at = ics.find("BEGIN:VEVENT").unwrap_or(ics.len());
+        ics.insert_str(at, &vtimezone(parse_timezone(tz), first, last));
+    }
+    ics
+}
+
+/// VTIMEZONE component for `tz`, with every offset change between `first`
+/// and `last` as a STANDARD or DAYLIGHT observance
+fn vtimezone(tz: Tz, first: DateTime<Utc>, last: DateTime<Utc>) -> String {
+    let offset_at = |t: DateTime<Utc>| tz.offset_from_utc_datetime(&t.naive_utc());
+    let observance = |t: DateTime<Utc>, from: i32| {
+        let offset = offset_at(t);
+        let to = offset.fix().local_minus_utc();
+        let kind = if offset.dst_offset().is_zero() {
+            "STANDARD"
+        } else {
+            "DAYLIGHT"
+        };
+        let mut lines = vec![
+            format!("BEGIN:{}", kind),
+            // Local time of the change, on the clock in effect before it
+            format!(
+                "DTSTART:{}",
+                (t.naive_utc() + Duration::seconds(from.into())).format("%Y%m%dT%H%M%S")
+            ),
+            format!("TZOFFSETFROM:{}", ics_offset(from)),
+            format!("TZOFFSETTO:{}", ics_offset(to)),
+        ];
+        if let Some(abbreviation) = offset.abbreviation() {
+            lines.push(format!("TZNAME:{}", abbreviation));
+        }
+        lines.push(format!("END:{}", kind));
+        lines
+    };
+
+    // Start a day early so the first observance is in effect at `first`
+    let mut t = first - Duration::days(1);
+    let mut current = offset_at(t).fix().local_minus_utc();
+    let mut lines = vec!["BEGIN:VTIMEZONE".to_string(), format!("TZID:{}", tz.name())];
+    lines.extend(observance(t, current));
+    while t < last {
+        let next = t + Duration::days(1);
+        if offset_at(next).fix().local_minus_utc() != current {
+            // Narrow the change down to the second
+            let (mut before, mut after) = (t, next);
+            while after - before > Duration::seconds(1) {
+                let middle = before + (after - before) / 2;
+                if offset_at(middle).fix().local_minus_utc() == current {
+                    before = middle;
+                } else {
+                    after = middle;
+                }
+            }
+            lines.extend(observance(after, current));
+            current = offset_at(after).fix().local_minus_utc();
+        }
+        t = next;
+    }
+    lines.push("END:VTIMEZONE".to_string());
+
+    lines.iter().map(|line| format!("{}\r\n", line)).collect()
+}
+
+/// UTC offset in iCalendar form, e.g. "-0600"
+fn ics_offset(seconds: i32) -> String {
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let minutes = seconds.abs() / 60;
+    format!("{}{:02}{:02}", sign, minutes / 60, minutes % 60)
+}
+
+impl CanvasServer {
+    #[tool(
+        description = "List calendar events for one or more courses within a date range, with times in the configured timezone"
+    )]
+    pub(crate) async fn list_calendar_events(
+        &self,
+        #[tool(aggr)] params: ListCalendarEventsParams,
+    ) -> Result<CallToolResult, McpError> {
+        if params.course_ids.is_empty() {
+            return Err(McpError::from(CanvasError::InvalidParameter(
+                "course_ids must list at least one course".to_string(),
+            )));
+        }
+
+        let mut query = QueryBuilder::new();
+        query.param("type", "event");
+        for course_id in &params.course_ids {
+            query.param("context_codes[]", course_context_code(course_id)?);
+        }
+        if let Some(start) = &params.start_date {
+            check_date("start_date", start)?;
+            query.param("start_date", start.trim());
+        }
+        if let Some(end) = &params.end_date {
+            check_date("end_date", end)?;
+            query.param("end_date", end.trim());
+        }
+        let path = query.to_path("/calendar_events");
+
+        let events: Vec<CalendarEvent> = self.reader(params.cache_bypass).get_all(&path).await?;
+
+        let tz = self.timezone();
+        let rows: Vec<EventRow> = events.into_iter().map(|e| EventRow::new(e, tz)).collect();
+
+        let mut summary = format!("Found {} event(s):", rows.len());
+        for row in &rows {
+            summary.push_str(&format!("\n- {}", row.describe()));
+        }
+
+        tool_result(summary, &rows)
+    }
+
+    #[tool(description = "Add an event, such as office hours, to a course calendar")]
+    pub(crate) async fn create_calendar_event(
+        &self,
+        #[tool(aggr)] params: CreateCalendarEventParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        if params.title.trim().is_empty() {
+            return Err(McpError::from(CanvasError::InvalidParameter(
+                "title must not be empty".to_string(),
+            )));
+        }
+        let start_at = parse_datetime("start_at", &params.start_at)?;
+        let end_at = params
+            .end_at
+            .as_deref()
+            .map(|end| parse_datetime("end_at", end))
+            .transpose()?;
+        if end_at.is_some_and(|end| end < start_at) {
+            return Err(McpError::from(CanvasError::InvalidParameter(
+                "end_at must not be before start_at".to_string(),
+            )));
+        }
+
+        let mut form = vec![
+            (
+                "calendar_event[context_code]",
+                course_context_code(&params.course_id)?,
+            ),
+            ("calendar_event[title]", params.title.trim().to_string()),
+            ("calendar_event[start_at]", start_at.to_rfc3339()),
+        ];
+        if let Some(end) = end_at {
+            form.push(("calendar_event[end_at]", end.to_rfc3339()));
+        }
+        if let Some(description) = &params.description {
+            form.push(("calendar_event[description]", description.clone()));
+        }
+        if let Some(location) = &params.location_name {
+            form.push(("calendar_event[location_name]", location.clone()));
+        }
+
+        let event: CalendarEvent = self.client.post_form("/calendar_events", &form).await?;
+
+        let tz = self.timezone();
+        let row = EventRow::new(event, tz);
+        let summary = format!("Created event {}", row.describe());
+
+        self.write_result(summary, &row)
+    }
+
+    #[tool(
+        description = "The current user's upcoming calendar events and assignment due dates across all courses, in the configured timezone"
+    )]
+    pub(crate) async fn get_upcoming_events(&self) -> Result<CallToolResult, McpError> {
+        let events: Vec<CalendarEvent> = self.client.get("/users/self/upcoming_events").await?;
+
+        let tz = self.timezone();
+        let rows: Vec<EventRow> = events.into_iter().map(|e| EventRow::new(e, tz)).collect();
+
+        let mut summary = format!("{} upcoming item(s):", rows.len());
+        for row in &rows {
+            summary.push_str(&format!("\n- {}", row.describe()));
+        }
+
+        tool_result(summary, &rows)
+    }
+
+    #[tool(
+        description = "Export a course's calendar events and assignment due dates as an iCalendar (.ics) file for import into other calendar apps"
+    )]
+    pub(crate) async fn export_calendar_ics(
+        &self,
+        #[tool(aggr)] params: ExportCalendarIcsParams,
+    ) -> Result<CallToolResult, McpError> {
+        let context_code = course_context_code(&params.course_id)?;
+
+        let mut query = QueryBuilder::new();
+        query.param("context_codes[]", &context_code);
+        if let Some(start) = &params.start_date {
+            check_date("start_date", start)?;
+            query.param("start_date", start.trim());
+        }
+        if let Some(end) = &params.end_date {
+            check_date("end_date", end)?;
+            query.param("end_date", end.trim());
+        }
+        if params.start_date.is_none() && params.end_date.is_none() {
+            query.param("all_events", true);
+        }
+        let path = query.to_path("/calendar_events");
+        let events_path = format!("{}&type=event", path);
+        let assignments_path = format!("{}&type=assignment", path);
+
+        let client = self.reader(params.cache_bypass);
+        let (mut events, assignments) = tokio::try_join!(
+            client.get_all::<CalendarEvent>(&events_path),
+            client.get_all::<CalendarEvent>(&assignments_path),
+        )?;
+        events.extend(assignments);
+        events.sort_by_key(|event| event.start_at);
+
+        let host = url::Url::parse(self.client.base_url())
+            .ok()
+            .and_then(|url| url.host_str().map(str::to_string))
+            .unwrap_or_else(|| "canvas".to_string());
+        let ics = to_ics(
+            &format!("Canvas {}", context_code),
+            &events,
+            self.timezone(),
+            &host,
+        );
+
+        let tz = self.timezone();
+        let dates = events.iter().filter_map(|event| event.start_at);
+        let range = match (dates.clone().min(), dates.max()) {
+            (Some(first), Some(last)) => format!(
+                ", {} to {}",
+                to_local(first, tz).format("%b %-d, %Y"),
+                to_local(last, tz).format("%b %-d, %Y")
+            ),
+            _ => String::new(),
+        };
+        let summary = format!(
+            "Calendar for course {}: {} event(s) and due date(s){} as iCalendar",
+            params.course_id,
+            events.len(),
+            range
+        );
+
+        tool_result(
+            summary,
+            &CalendarExport {
+                events: events.len(),
+                ics,
+            },
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_course_context_code() {
+        assert_eq!(
+            course_context_code(&FlexibleId::from(123)).unwrap(),
+            "course_123"
+        );
+        assert_eq!(
+            course_context_code(&FlexibleId::from("course_123")).unwrap(),
+            "course_123"
+        );
+        assert!(course_context_code(&FlexibleId::from("sis_course_id:BIOL101")).is_err());
+    }
+
+    #[test]
+    fn test_vtimezone_lists_offset_changes() {
+        let at = |s: &str| s.parse::<DateTime<Utc>>().unwrap();
+        let denver = vtimezone(
+            chrono_tz::America::Denver,
+            at("2025-10-06T21:00:00Z"),
+            at("2025-12-01T00:00:00Z"),
+        );
+        assert!(denver.starts_with("BEGIN:VTIMEZONE\r\nTZID:America/Denver\r\nBEGIN:DAYLIGHT\r\n"));
+        assert!(denver.contains(
+            "BEGIN:STANDARD\r\nDTSTART:20251102T020000\r\nTZOFFSETFROM:-0600\r\nTZOFFSETTO:-0700\r\nTZNAME:MST\r\nEND:STANDARD\r\n"
+        ));
+        assert!(denver.ends_with("END:VTIMEZONE\r\n"));
+
+        let utc = vtimezone(
+            Tz::UTC,
+            at("2025-10-06T21:00:00Z"),
+            at("2026-10-06T21:00:00Z"),
+        );
+        assert_eq!(utc.matches("BEGIN:STANDARD").count(), 1);
+        assert!(utc.contains("TZOFFSETFROM:+0000\r\nTZOFFSETTO:+0000\r\n"));
+    }
+
+    #[tokio::test]
+    async fn test_export_calendar_ics() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock(
+                "GET",
+                "/api/v1/calendar_events?context_codes[]=course_1&all_events=true&type=event&per_page=100",
+            )
+            .with_body(
+                r#"[{"id": 3, "title": "Office hours", "start_at": "2025-10-06T21:00:00Z",
+                     "end_at": "2025-10-06T22:00:00Z", "location_name": "Room 204",
+                     "description": "<p>Bring questions</p>"}]"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock(
+                "GET",
+                "/api/v1/calendar_events?context_codes[]=course_1&all_events=true&type=assignment&per_page=100",
+            )
+            .with_body(
+                r#"[{"id": "assignment_7", "title": "Essay 1", "start_at": "2025-10-04T05:59:00Z",
+                     "end_at": "2025-10-04T05:59:00Z"}]"#,
+            )
+            .create_async()
+            .await;
+
+        let mut config = CanvasConfig::new("token".to_string(), server.url());
+        config.timezone = Some("America/Denver".to_string());
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+
+        let result = canvas
+            .export_calendar_ics(ExportCalendarIcsParams {
+                course_id: FlexibleId::from(1),
+                start_date: None,
+                end_date: None,
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert_eq!(
+            summary,
+            "Calendar for course 1: 2 event(s) and due date(s), Oct 3, 2025 to Oct 6, 2025 as iCalendar"
+        );
+        let json = crate::tools::result_json(&result);
+        assert_eq!(json["events"], 2);
+        let ics = json["ics"].as_str().unwrap();
+        assert!(ics.starts_with("BEGIN:VCALENDAR\r\n"));
+        assert_eq!(ics.matches("BEGIN:VEVENT").count(), 2);
+        assert!(ics.contains("DTSTART;TZID=America/Denver:20251006T150000\r\n"));
+        assert!(ics.contains("DTEND;TZID=America/Denver:20251006T160000\r\n"));
+        assert!(ics.contains("BEGIN:VTIMEZONE\r\nTZID:America/Denver\r\n"));
+        assert!(ics.find("END:VTIMEZONE") < ics.find("BEGIN:VEVENT"));
+        assert!(ics.contains("SUMMARY:Essay 1\r\n"));
+        assert!(ics.contains("LOCATION:Room 204\r\n"));
+        assert!(ics.contains("DESCRIPTION:Bring questions\r\n"));
+        // Due dates come first
+        assert!(ics.find("Essay 1") < ics.find("Office hours"));
+    }
+
+    #[tokio::test]
+    async fn test_get_upcoming_events() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/users/self/upcoming_events")
+            .with_body(
+                r#"[{"id": "assignment_7", "title": "Essay 1", "start_at": "2025-10-04T06:59:00Z",
+                     "end_at": "2025-10-04T06:59:00Z", "context_code": "course_1"}]"#,
+            )
+            .create_async()
+            .await;
+
+        let mut config = CanvasConfig::new("token".to_string(), server.url());
+        config.timezone = Some("America/Los_Angeles".to_string());
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+
+        let result = canvas.get_upcoming_events().await.unwrap();
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.ends_with("- Essay 1 (ID assignment_7) — Fri, Oct 3 at 11:59 PM"));
+    }
+
+    #[tokio::test]
+    async fn test_list_calendar_events() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock(
+                "GET",
+                "/api/v1/calendar_events?type=event&context_codes[]=course_1&context_codes[]=course_2&start_date=2025-10-01&end_date=2025-10-31&per_page=100",
+            )
+            .with_body(
+                r#"[{"id": 3, "title": "Office hours", "start_at": "2025-10-06T21:00:00Z",
+                     "end_at": "2025-10-06T22:00:00Z", "location_name": "Room 204",
+                     "context_code": "course_1"}]"#,
+            )
+            .create_async()
+            .await;
+
+        let mut config = CanvasConfig::new("token".to_string(), server.url());
+        config.timezone = Some("America/Denver".to_string());
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+
+        let result = canvas
+            .list_calendar_events(ListCalendarEventsParams {
+                course_ids: vec![FlexibleId::from(1), FlexibleId::from(2)],
+                start_date: Some("2025-10-01".to_string()),
+                end_date: Some("2025-10-31".to_string()),
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains(
+            "Office hours (ID 3) — Mon, Oct 6 at 3:00 PM to Mon, Oct 6 at 4:00 PM @ Room 204"
+        ));
+        mock.assert_async().await;
+    }
+}
@@ -0,0 +1,305 @@
+use super::{check_choice, tool_result};
+use crate::client::QueryBuilder;
+use crate::datetime::format_local;
+use crate::error::CanvasError;
+use crate::models::Conversation;
+use crate::server::CanvasServer;
+use rmcp::model::CallToolResult;
+use rmcp::{schemars, tool, Error as McpError};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SendMessageParams {
+    /// Canvas user IDs and/or group codes such as "course_123_students",
+    /// "course_123_teachers", "section_45", or "group_9"
+    pub recipients: Vec<String>,
+
+    /// Message subject
+    pub subject: String,
+
+    /// Message body (plain text)
+    pub body: String,
+
+    /// Send each recipient a private copy instead of starting one group
+    /// thread (default false)
+    pub bulk_message: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListConversationsParams {
+    /// "unread", "starred", "archived", or "sent"; defaults to the inbox,
+    /// which leaves out archived conversations
+    pub scope: Option<String>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+/// Whether a recipient is a user ID or a Canvas context code like
+/// `course_123_students`
+fn is_valid_recipient(recipient: &str) -> bool {
+    if !recipient.is_empty() && recipient.chars().all(|c| c.is_ascii_digit()) {
+        return true;
+    }
+    let mut parts = recipient.splitn(3, '_');
+    let (Some(kind), Some(id)) = (parts.next(), parts.next()) else {
+        return false;
+    };
+    matches!(kind, "course" | "section" | "group")
+        && !id.is_empty()
+        && id.chars().all(|c| c.is_ascii_digit())
+        && parts.next().is_none_or(|role| !role.is_empty())
+}
+
+impl CanvasServer {
+    #[tool(
+        description = "List Canvas inbox conversations with subject, latest message, participants, and time; filter to unread, starred, archived, or sent"
+    )]
+    pub(crate) async fn list_conversations(
+        &self,
+        #[tool(aggr)] params: ListConversationsParams,
+    ) -> Result<CallToolResult, McpError> {
+        let mut query = QueryBuilder::new();
+        if let Some(scope) = &params.scope {
+            check_choice("scope", scope, &["unread", "starred", "archived", "sent"])?;
+            query.param("scope", scope);
+        }
+        let path = query.to_path("/conversations");
+
+        let mut conversations: Vec<Conversation> =
+            self.reader(params.cache_bypass).get_all(&path).await?;
+        if params.scope.as_deref() != Some("archived") {
+            conversations.retain(|c| c.workflow_state.as_deref() != Some("archived"));
+        }
+
+        let me = self.client.get_current_user().await?.id;
+        let mut summary = format!("Found {} conversation(s):", conversations.len());
+        for conversation in &conversations {
+            let others: Vec<&str> = conversation
+                .participants
+                .iter()
+                .filter(|p| p.id != me)
+                .map(|p| p.name.as_str())
+                .collect();
+            summary.push_str(&format!(
+                "\n- {}{} (ID {}) with {}",
+                if conversation.workflow_state.as_deref() == Some("unread") {
+                    "[unread] "
+                } else {
+                    ""
+                },
+                conversation
+                    .subject
+                    .as_deref()
+                    .filter(|s| !s.is_empty())
+                    .unwrap_or("(no subject)"),
+                conversation.id,
+                if others.is_empty() {
+                    "no one else".to_string()
+                } else {
+                    others.join(", ")
+                },
+            ));
+            if let Some(at) = conversation.last_message_at {
+                summary.push_str(&format!(", {}", format_local(at, self.timezone())));
+            }
+            if let Some(snippet) = conversation
+                .last_message
+                .as_deref()
+                .filter(|m| !m.is_empty())
+            {
+                let snippet: String = snippet.chars().take(120).collect();
+                summary.push_str(&format!(": \"{}\"", snippet.replace('\n', " ")));
+            }
+        }
+
+        tool_result(summary, &conversations)
+    }
+
+    #[tool(
+        description = "Send a Canvas inbox message to users or to a whole course, section, or group, as one thread or as individual copies"
+    )]
+    pub(crate) async fn send_message(
+        &self,
+        #[tool(aggr)] params: SendMessageParams,
+    ) -> Result<CallToolResult, McpError> {
+        let recipients: Vec<&str> = params.recipients.iter().map(|r| r.trim()).collect();
+        if recipients.is_empty() {
+            return Err(McpError::from(CanvasError::InvalidParameter(
+                "recipients must include at least one user ID or group code".to_string(),
+            )));
+        }
+        if let Some(bad) = recipients.iter().find(|r| !is_valid_recipient(r)) {
+            return Err(McpError::from(CanvasError::InvalidParameter(format!(
+                "recipients must be user IDs or codes like course_123_students (got \"{}\")",
+                bad
+            ))));
+        }
+        if params.body.trim().is_empty() {
+            return Err(McpError::from(CanvasError::InvalidParameter(
+                "body must not be empty".to_string(),
+            )));
+        }
+        // Canvas refuses conversations whose only participant is the sender
+        if recipients
+            .iter()
+            .all(|r| r.chars().all(|c| c.is_ascii_digit()))
+        {
+            let me = self.client.get_current_user().await?.id.to_string();
+            if recipients.iter().all(|r| *r == me) {
+                return Err(McpError::from(CanvasError::InvalidParameter(
+                    "recipients must include someone other than yourself".to_string(),
+                )));
+            }
+        }
+
+        let bulk = params.bulk_message.unwrap_or(false);
+        let mut form: Vec<(&str, &str)> = recipients.iter().map(|r| ("recipients[]", *r)).collect();
+        form.push(("subject", params.subject.trim()));
+        form.push(("body", params.body.as_str()));
+        form.push(("group_conversation", "true"));
+        if bulk {
+            form.push(("bulk_message", "true"));
+        }
+
+        let conversations: Vec<Conversation> =
+            self.client.post_form("/conversations", &form).await?;
+
+        let ids: Vec<String> = conversations.iter().map(|c| c.id.to_string()).collect();
+        let summary = if bulk {
+            format!(
+                "Sent {} individual message(s); conversation IDs: {}",
+                ids.len(),
+                ids.join(", ")
+            )
+        } else {
+            format!("Sent message; conversation ID {}", ids.join(", "))
+        };
+
+        self.write_result(summary, &conversations)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_recipient_validation() {
+        assert!(is_valid_recipient("42"));
+        assert!(is_valid_recipient("course_123_students"));
+        assert!(is_valid_recipient("section_45"));
+        assert!(!is_valid_recipient("course_abc"));
+        assert!(!is_valid_recipient("jane@example.edu"));
+        assert!(!is_valid_recipient(""));
+    }
+
+    #[tokio::test]
+    async fn test_list_conversations_hides_archived_and_anonymizes() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/conversations?per_page=100")
+            .with_body(
+                r#"[{"id": 1, "subject": "Extension?", "workflow_state": "unread",
+                     "last_message": "Could I have two more days?",
+                     "participants": [{"id": 7, "name": "Pat Teacher", "full_name": "Pat Teacher"},
+                                      {"id": 42, "name": "Jane Doe", "full_name": "Jane Doe"}]},
+                    {"id": 2, "subject": "Old thread", "workflow_state": "archived",
+                     "participants": []}]"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/api/v1/users/self")
+            .with_body(r#"{"id": 7, "name": "Pat Teacher", "short_name": "Pat"}"#)
+            .create_async()
+            .await;
+
+        let mut config = CanvasConfig::new("token".to_string(), server.url());
+        config.enable_anonymization = true;
+        config.anonymization_salt = "salt".to_string();
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+
+        let result = canvas
+            .list_conversations(ListConversationsParams {
+                scope: None,
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.starts_with("Found 1 conversation(s):"));
+        assert!(summary.contains(&format!(
+            "[unread] Extension? (ID 1) with {}",
+            crate::anonymize::pseudonym("42", "salt")
+        )));
+        assert!(!summary.contains("Jane"));
+    }
+
+    #[tokio::test]
+    async fn test_send_message_rejects_self_only() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/users/self")
+            .with_body(r#"{"id": 7, "name": "Pat Teacher"}"#)
+            .create_async()
+            .await;
+        let post = server
+            .mock("POST", "/api/v1/conversations")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let error = canvas
+            .send_message(SendMessageParams {
+                recipients: vec!["7".to_string()],
+                subject: "Note to self".to_string(),
+                body: "Remember the quiz".to_string(),
+                bulk_message: None,
+            })
+            .await
+            .unwrap_err();
+        assert!(error.message.contains("someone other than yourself"));
+        post.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_send_bulk_message_to_course() {
+        let mut server = mockito::Server::new_async().await;
+        let post = server
+            .mock("POST", "/api/v1/conversations")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("recipients[]".into(), "course_1_students".into()),
+                mockito::Matcher::UrlEncoded("bulk_message".into(), "true".into()),
+            ]))
+            .with_body(r#"[{"id": 100}, {"id": 101}]"#)
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .send_message(SendMessageParams {
+                recipients: vec!["course_1_students".to_string()],
+                subject: "Exam reminder".to_string(),
+                body: "The exam is Friday.".to_string(),
+                bulk_message: Some(true),
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert_eq!(
+            summary,
+            "Sent 2 individual message(s); conversation IDs: 100, 101"
+        );
+        post.assert_async().await;
+    }
+}
@@ -0,0 +1,565 @@
+use super::terms::find_term;
+use super::{check_choice, content_format, tool_result, validate_id};
+use crate::client::QueryBuilder;
+use crate::error::CanvasError;
+use crate::models::{Course, EnrollmentTerm, FlexibleId};
+use crate::render::render;
+use crate::server::CanvasServer;
+use rmcp::model::CallToolResult;
+use rmcp::{schemars, tool, Error as McpError};
+use serde::Deserialize;
+
+#[derive(Debug, Default, Deserialize, schemars::JsonSchema)]
+pub struct ListCoursesParams {
+    /// Filter by enrollment state: "active" (default), "completed", or "invited"
+    pub enrollment_state: Option<String>,
+
+    /// Include each course's enrollment term
+    pub include_term: Option<bool>,
+
+    /// Only courses in the enrollment term with this name, e.g. "Fall 2025"
+    pub term: Option<String>,
+
+    /// Extra data to include for each course, e.g. "term", "total_students",
+    /// "teachers", "syllabus_body"
+    pub include: Option<Vec<String>>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Default, Deserialize, schemars::JsonSchema)]
+pub struct ListAccountCoursesParams {
+    /// Match against course name, course code, or SIS ID
+    pub search_term: Option<String>,
+
+    /// Only courses in this enrollment term
+    pub enrollment_term_id: Option<FlexibleId>,
+
+    /// Only courses in the enrollment term with this name, e.g. "Fall 2025"
+    /// (instead of enrollment_term_id)
+    pub term: Option<String>,
+
+    /// true for only blueprint courses, false to exclude them
+    pub blueprint: Option<bool>,
+
+    /// true for only published courses, false for only unpublished ones
+    pub published: Option<bool>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetCourseParams {
+    /// Canvas course ID, or an SIS reference such as "sis_course_id:BIOL101"
+    pub course_id: FlexibleId,
+
+    /// Extra data to include: "syllabus_body", "total_students", "teachers", "term"
+    pub include: Option<Vec<String>>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetSyllabusParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// How to return the syllabus: "markdown" (default), "text", or "html"
+    pub format: Option<String>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct UpdateSyllabusParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Syllabus HTML
+    pub body: String,
+
+    /// "replace" (default) to overwrite the syllabus, or "append" to add
+    /// `body` after the existing content
+    pub mode: Option<String>,
+}
+
+/// Whether HTML ends inside a tag, e.g. `<p>Office hours: <a href="`, which
+/// Canvas would store as a broken syllabus
+fn ends_mid_tag(html: &str) -> bool {
+    match (html.rfind('<'), html.rfind('>')) {
+        (Some(open), Some(close)) => open > close,
+        (Some(_), None) => true,
+        _ => false,
+    }
+}
+
+impl CanvasServer {
+    #[tool(description = "List the authenticated user's Canvas courses")]
+    pub(crate) async fn list_courses(
+        &self,
+        #[tool(aggr)] params: ListCoursesParams,
+    ) -> Result<CallToolResult, McpError> {
+        let state = match params.enrollment_state.as_deref().unwrap_or("active") {
+            "active" => "active",
+            "completed" => "completed",
+            "invited" | "invited_or_pending" => "invited_or_pending",
+            other => {
+                return Err(McpError::from(CanvasError::InvalidParameter(format!(
+                    "enrollment_state must be active, completed, or invited (got {})",
+                    other
+                ))))
+            }
+        };
+
+        let mut query = QueryBuilder::new();
+        query.param("enrollment_state", state);
+        if params.include_term.unwrap_or(false) || params.term.is_some() {
+            query.include("term");
+        }
+        for include in params.include.iter().flatten() {
+            query.include(include.as_str());
+        }
+        let path = query.to_path("/courses");
+
+        let mut courses: Vec<Course> = self.reader(params.cache_bypass).get_all(&path).await?;
+        if let Some(name) = &params.term {
+            // Canvas can't filter a user's courses by term, so match against
+            // the terms the courses are in
+            let mut terms: Vec<EnrollmentTerm> = Vec::new();
+            for term in courses.iter().filter_map(|c| c.term.as_ref()) {
+                if !terms.iter().any(|t| t.id == term.id) {
+                    terms.push(term.clone());
+                }
+            }
+            let term_id = find_term(&terms, name)?.id.clone();
+            courses.retain(|c| c.term.as_ref().is_some_and(|t| t.id == term_id));
+        }
+
+        let mut summary = format!("Found {} course(s):", courses.len());
+        for course in &courses {
+            summary.push_str(&format!("\n- {} (ID {})", course.name, course.id));
+            if let Some(code) = &course.course_code {
+                summary.push_str(&format!(" [{}]", code));
+            }
+            if let Some(term) = course.term.as_ref().and_then(|t| t.name.as_deref()) {
+                summary.push_str(&format!(" — {}", term));
+            }
+        }
+
+        tool_result(summary, &courses)
+    }
+
+    #[tool(
+        description = "Admin only: list every course in the configured Canvas account (CANVAS_ACCOUNT_ID), filtered by search term, enrollment term, blueprint status, or published state"
+    )]
+    pub(crate) async fn list_account_courses(
+        &self,
+        #[tool(aggr)] params: ListAccountCoursesParams,
+    ) -> Result<CallToolResult, McpError> {
+        let account_id = self.admin_account("list_account_courses")?;
+
+        let mut query = QueryBuilder::new();
+        query.include("term");
+        if let Some(term) = params.search_term.as_deref().map(str::trim) {
+            if term.chars().count() < 2 {
+                return Err(McpError::from(CanvasError::InvalidParameter(
+                    "search_term must be at least 2 characters".to_string(),
+                )));
+            }
+            query.param("search_term", term);
+        }
+        let term_id = match (&params.enrollment_term_id, &params.term) {
+            (Some(_), Some(_)) => {
+                return Err(McpError::from(CanvasError::InvalidParameter(
+                    "Give enrollment_term_id or term, not both".to_string(),
+                )))
+            }
+            (Some(term_id), None) => {
+                validate_id("enrollment_term_id", term_id)?;
+                Some(term_id.clone())
+            }
+            (None, Some(name)) => Some(self.resolve_term("list_account_courses", name).await?),
+            (None, None) => None,
+        };
+        if let Some(term_id) = term_id {
+            query.param("enrollment_term_id", term_id);
+        }
+        if let Some(blueprint) = params.blueprint {
+            query.param("blueprint", blueprint);
+        }
+        if let Some(published) = params.published {
+            query.param("published", published);
+        }
+        let path = query.to_path(&format!("/accounts/{}/courses", account_id));
+
+        let courses: Vec<Course> = self
+            .reader(params.cache_bypass)
+            .get_all(&path)
+            .await
+            .map_err(|e| match e {
+                CanvasError::Auth(message) => CanvasError::auth(format!(
+                    "{}. Listing account {}'s courses requires an account admin token",
+                    message, account_id
+                )),
+                other => other,
+            })?;
+
+        let mut summary = format!(
+            "Found {} course(s) in account {}:",
+            courses.len(),
+            account_id
+        );
+        for course in &courses {
+            summary.push_str(&format!("\n- {} (ID {})", course.name, course.id));
+            if let Some(code) = &course.course_code {
+                summary.push_str(&format!(" [{}]", code));
+            }
+            if let Some(term) = course.term.as_ref().and_then(|t| t.name.as_deref()) {
+                summary.push_str(&format!(" — {}", term));
+            }
+            if course.blueprint == Some(true) {
+                summary.push_str(" (blueprint)");
+            }
+            if course.workflow_state.as_deref() == Some("unpublished") {
+                summary.push_str(" (unpublished)");
+            }
+        }
+
+        tool_result(summary, &courses)
+    }
+
+    #[tool(
+        description = "Get a single Canvas course, optionally with its syllabus, teachers, term, or student count"
+    )]
+    pub(crate) async fn get_course(
+        &self,
+        #[tool(aggr)] params: GetCourseParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+
+        let mut query = QueryBuilder::new();
+        for include in params.include.iter().flatten() {
+            query.include(include.as_str());
+        }
+        let path = query.to_path(&format!("/courses/{}", params.course_id.path_segment()));
+
+        // Keep the raw JSON so includes that aren't modeled still reach the caller
+        let raw: serde_json::Value = self.reader(params.cache_bypass).get(&path).await?;
+
+        let summary = match serde_json::from_value::<Course>(raw.clone()) {
+            Ok(course) => {
+                let mut summary = format!("{} (ID {})", course.name, course.id);
+                if let Some(code) = &course.course_code {
+                    summary.push_str(&format!("\nCode: {}", code));
+                }
+                if let Some(state) = &course.workflow_state {
+                    summary.push_str(&format!("\nState: {}", state));
+                }
+                if let Some(term) = course.term.as_ref().and_then(|t| t.name.as_deref()) {
+                    summary.push_str(&format!("\nTerm: {}", term));
+                }
+                if let Some(total) = raw.get("total_students").and_then(|v| v.as_u64()) {
+                    summary.push_str(&format!("\nStudents: {}", total));
+                }
+                if let Some(teachers) = raw.get("teachers").and_then(|v| v.as_array()) {
+                    let names: Vec<_> = teachers
+                        .iter()
+                        .filter_map(|t| t.get("display_name").and_then(|n| n.as_str()))
+                        .collect();
+                    summary.push_str(&format!("\nTeachers: {}", names.join(", ")));
+                }
+                summary
+            }
+            Err(_) => format!("Course {}", params.course_id),
+        };
+
+        tool_result(summary, &raw)
+    }
+
+    #[tool(
+        description = "Get a course's syllabus, as Markdown by default, or as plain text or the original HTML"
+    )]
+    pub(crate) async fn get_syllabus(
+        &self,
+        #[tool(aggr)] params: GetSyllabusParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        let format = content_format(params.format.as_deref(), "markdown")?;
+
+        let course = self
+            .fetch_syllabus(&params.course_id, params.cache_bypass)
+            .await?;
+        let body = course.syllabus_body.as_deref().unwrap_or("");
+        let syllabus = render(body, &format);
+
+        let summary = if syllabus.trim().is_empty() {
+            format!("{} has no syllabus", course.name)
+        } else {
+            format!("# {} syllabus\n\n{}", course.name, syllabus)
+        };
+
+        tool_result(
+            summary,
+            &serde_json::json!({"course_id": course.id, "name": course.name, "syllabus": syllabus}),
+        )
+    }
+
+    #[tool(
+        description = "Replace a course's syllabus, or append HTML to the end of the existing one"
+    )]
+    pub(crate) async fn update_syllabus(
+        &self,
+        #[tool(aggr)] params: UpdateSyllabusParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        let mode = params.mode.as_deref().unwrap_or("replace");
+        check_choice("mode", mode, &["replace", "append"])?;
+        if ends_mid_tag(&params.body) {
+            return Err(McpError::from(CanvasError::InvalidParameter(
+                "body ends in the middle of an HTML tag; it may have been truncated".to_string(),
+            )));
+        }
+
+        let body = if mode == "append" {
+            let existing = self
+                .fetch_syllabus(&params.course_id, None)
+                .await?
+                .syllabus_body
+                .unwrap_or_default();
+            if ends_mid_tag(&existing) {
+                return Err(McpError::from(CanvasError::InvalidParameter(
+                    "The current syllabus ends in the middle of an HTML tag; replace it instead of appending".to_string(),
+                )));
+            }
+            format!("{}\n{}", existing.trim_end(), params.body)
+        } else {
+            params.body.clone()
+        };
+
+        let path = QueryBuilder::new()
+            .include("syllabus_body")
+            .to_path(&format!("/courses/{}", params.course_id.path_segment()));
+        let course: Course = self
+            .client
+            .put_form(&path, &[("course[syllabus_body]", body.as_str())])
+            .await?;
+
+        let summary = format!(
+            "{} the syllabus for {} ({} characters)",
+            if mode == "append" {
+                "Appended to"
+            } else {
+                "Replaced"
+            },
+            course.name,
+            course
+                .syllabus_body
+                .as_deref()
+                .map(|b| b.chars().count())
+                .unwrap_or_else(|| body.chars().count())
+        );
+
+        self.write_result(summary, &course)
+    }
+
+    /// Fetch a course with its syllabus body
+    async fn fetch_syllabus(
+        &self,
+        course_id: &FlexibleId,
+        cache_bypass: Option<bool>,
+    ) -> crate::Result<Course> {
+        let path = QueryBuilder::new()
+            .include("syllabus_body")
+            .to_path(&format!("/courses/{}", course_id.path_segment()));
+        self.reader(cache_bypass).get(&path).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_list_courses() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock(
+                "GET",
+                "/api/v1/courses?enrollment_state=active&include[]=term&per_page=100",
+            )
+            .with_body(
+                r#"[{"id": 1, "name": "Biology", "course_code": "BIOL101",
+                     "term": {"id": 5, "name": "Fall 2025"}}]"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .list_courses(ListCoursesParams {
+                include_term: Some(true),
+                ..Default::default()
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains("Biology (ID 1) [BIOL101] — Fall 2025"));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_list_courses_rejects_unknown_state() {
+        let config = Arc::new(CanvasConfig::new(
+            "token".to_string(),
+            "https://example.instructure.com".to_string(),
+        ));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .list_courses(ListCoursesParams {
+                enrollment_state: Some("archived".to_string()),
+                ..Default::default()
+            })
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_course_with_includes() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock(
+                "GET",
+                "/api/v1/courses/1?include[]=total_students&include[]=teachers",
+            )
+            .with_body(
+                r#"{"id": 1, "name": "Biology", "total_students": 30,
+                    "teachers": [{"id": 9, "display_name": "Dr. Smith"}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .get_course(GetCourseParams {
+                course_id: FlexibleId::from(1),
+                include: Some(vec!["total_students".into(), "teachers".into()]),
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains("Students: 30"));
+        assert!(summary.contains("Teachers: Dr. Smith"));
+    }
+
+    #[test]
+    fn test_ends_mid_tag() {
+        assert!(!ends_mid_tag("<p>Office hours</p>"));
+        assert!(!ends_mid_tag("plain text"));
+        assert!(ends_mid_tag("<p>See <a href=\"https://exa"));
+        assert!(ends_mid_tag("<p"));
+    }
+
+    #[tokio::test]
+    async fn test_update_syllabus_appends() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/courses/1?include[]=syllabus_body")
+            .with_body(r#"{"id": 1, "name": "Biology", "syllabus_body": "<p>Week 1</p>"}"#)
+            .create_async()
+            .await;
+        let put = server
+            .mock("PUT", "/api/v1/courses/1?include[]=syllabus_body")
+            .match_body(mockito::Matcher::UrlEncoded(
+                "course[syllabus_body]".into(),
+                "<p>Week 1</p>\n<p>Week 2</p>".into(),
+            ))
+            .with_body(
+                r#"{"id": 1, "name": "Biology", "syllabus_body": "<p>Week 1</p>\n<p>Week 2</p>"}"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .update_syllabus(UpdateSyllabusParams {
+                course_id: FlexibleId::from(1),
+                body: "<p>Week 2</p>".to_string(),
+                mode: Some("append".to_string()),
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.starts_with("Appended to the syllabus for Biology"));
+        put.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_course_rejects_invalid_id() {
+        let config = Arc::new(CanvasConfig::new(
+            "token".to_string(),
+            "https://example.instructure.com".to_string(),
+        ));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .get_course(GetCourseParams {
+                course_id: FlexibleId::from("biology"),
+                include: None,
+                cache_bypass: None,
+            })
+            .await;
+        assert!(result.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_list_account_courses_requires_account_and_filters() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock(
+                "GET",
+                "/api/v1/accounts/1/courses?include[]=term&search_term=bio&enrollment_term_id=5&blueprint=true&per_page=100",
+            )
+            .with_body(
+                r#"[{"id": 7, "name": "Biology Template", "course_code": "BIO-BP", "blueprint": true,
+                     "workflow_state": "unpublished", "term": {"id": 5, "name": "Fall 2025"}}]"#,
+            )
+            .create_async()
+            .await;
+
+        let mut config = CanvasConfig::new("token".to_string(), server.url());
+        let params = || ListAccountCoursesParams {
+            search_term: Some("bio".to_string()),
+            enrollment_term_id: Some(FlexibleId::from(5)),
+            blueprint: Some(true),
+            ..Default::default()
+        };
+
+        let canvas = CanvasServer::new(Arc::new(config.clone())).unwrap();
+        let error = canvas.list_account_courses(params()).await.unwrap_err();
+        assert!(error.message.contains("CANVAS_ACCOUNT_ID"));
+
+        config.account_id = Some("1".to_string());
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+        let result = canvas.list_account_courses(params()).await.unwrap();
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary
+            .contains("- Biology Template (ID 7) [BIO-BP] — Fall 2025 (blueprint) (unpublished)"));
+    }
+}
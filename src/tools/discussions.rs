@@ -0,0 +1,508 @@
+use super::{content_format, tool_result, validate_id};
+use crate::client::QueryBuilder;
+use crate::datetime::{format_local, parse_datetime};
+use crate::error::CanvasError;
+use crate::models::{DiscussionEntry, DiscussionTopic, FlexibleId};
+use crate::render::render;
+use crate::server::CanvasServer;
+use chrono::{DateTime, Utc};
+use rmcp::model::CallToolResult;
+use rmcp::{schemars, tool, Error as McpError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct PostDiscussionEntryParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Discussion topic ID
+    pub topic_id: FlexibleId,
+
+    /// Reply to this entry instead of posting a new top-level entry
+    pub parent_entry_id: Option<FlexibleId>,
+
+    /// Message body (HTML or plain text)
+    pub message: String,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct CreateAnnouncementParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Announcement title
+    pub title: String,
+
+    /// Announcement body (HTML or plain text)
+    pub message: String,
+
+    /// Publish later instead of now, as an RFC 3339 timestamp in the future
+    /// (e.g. "2025-10-06T08:00:00-06:00")
+    pub delayed_post_at: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListDiscussionTopicsParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Only return announcements
+    pub only_announcements: Option<bool>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetDiscussionParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Discussion topic ID
+    pub topic_id: FlexibleId,
+
+    /// How to return the topic and entry messages: "text" (default),
+    /// "markdown", or "html"
+    pub format: Option<String>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+/// Full thread as returned by the `/view` endpoint
+#[derive(Debug, Deserialize)]
+struct DiscussionView {
+    #[serde(default)]
+    participants: Vec<Participant>,
+    #[serde(default)]
+    view: Vec<ViewEntry>,
+}
+
+#[derive(Debug, Deserialize)]
+struct Participant {
+    id: FlexibleId,
+    display_name: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ViewEntry {
+    id: FlexibleId,
+    user_id: Option<FlexibleId>,
+    message: Option<String>,
+    created_at: Option<DateTime<Utc>>,
+    #[serde(default)]
+    deleted: bool,
+    #[serde(default)]
+    replies: Vec<ViewEntry>,
+}
+
+/// A discussion entry with its author resolved and replies nested beneath it
+#[derive(Debug, Serialize)]
+pub struct ThreadEntry {
+    pub id: FlexibleId,
+    pub user_id: Option<FlexibleId>,
+    pub author: Option<String>,
+    pub created_at: Option<DateTime<Utc>>,
+    pub message: Option<String>,
+    pub deleted: bool,
+    pub replies: Vec<ThreadEntry>,
+}
+
+/// A topic together with its reply tree
+#[derive(Debug, Serialize)]
+pub struct Discussion {
+    pub topic: DiscussionTopic,
+    pub entries: Vec<ThreadEntry>,
+}
+
+impl ThreadEntry {
+    fn from_view(entry: ViewEntry, authors: &HashMap<FlexibleId, String>, format: &str) -> Self {
+        Self {
+            author: entry
+                .user_id
+                .as_ref()
+                .and_then(|id| authors.get(id))
+                .cloned(),
+            user_id: entry.user_id,
+            id: entry.id,
+            created_at: entry.created_at,
+            message: entry.message.map(|m| render(&m, format)),
+            deleted: entry.deleted,
+            replies: entry
+                .replies
+                .into_iter()
+                .map(|reply| Self::from_view(reply, authors, format))
+                .collect(),
+        }
+    }
+
+    /// Append this entry and its replies to `out`, indented by depth
+    fn render(&self, depth: usize, out: &mut String) {
+        let indent = "  ".repeat(depth);
+        let author = self.author.as_deref().unwrap_or("unknown");
+        let message = if self.deleted {
+            "[deleted]".to_string()
+        } else {
+            self.message.as_deref().unwrap_or("").replace('\n', " ")
+        };
+        out.push_str(&format!("\n{}- {}: {}", indent, author, message));
+        for reply in &self.replies {
+            reply.render(depth + 1, out);
+        }
+    }
+}
+
+impl CanvasServer {
+    #[tool(
+        description = "Create a course announcement, published now or scheduled for a later time"
+    )]
+    pub(crate) async fn create_announcement(
+        &self,
+        #[tool(aggr)] params: CreateAnnouncementParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        if params.title.trim().is_empty() {
+            return Err(McpError::from(CanvasError::InvalidParameter(
+                "title must not be empty".to_string(),
+            )));
+        }
+        let delayed_post_at = params
+            .delayed_post_at
+            .as_deref()
+            .map(parse_future_time)
+            .transpose()?;
+
+        let path = format!(
+            "/courses/{}/discussion_topics",
+            params.course_id.path_segment()
+        );
+        let mut form = vec![
+            ("title", params.title.clone()),
+            ("message", params.message.clone()),
+            ("is_announcement", "true".to_string()),
+        ];
+        if let Some(at) = delayed_post_at {
+            form.push(("delayed_post_at", at.to_rfc3339()));
+        }
+
+        let topic: DiscussionTopic = self.client.post_form(&path, &form).await?;
+
+        let mut summary = match topic.delayed_post_at {
+            Some(at) => format!(
+                "Scheduled announcement {} for {}",
+                topic.id,
+                format_local(at, self.timezone())
+            ),
+            None => format!("Posted announcement {}", topic.id),
+        };
+        if let Some(url) = &topic.html_url {
+            summary.push_str(&format!(": {}", url));
+        }
+
+        self.write_result(summary, &topic)
+    }
+
+    #[tool(description = "List a course's discussion topics, or only its announcements")]
+    pub(crate) async fn list_discussion_topics(
+        &self,
+        #[tool(aggr)] params: ListDiscussionTopicsParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+
+        let mut query = QueryBuilder::new();
+        if params.only_announcements.unwrap_or(false) {
+            query.param("only_announcements", true);
+        }
+        let path = query.to_path(&format!(
+            "/courses/{}/discussion_topics",
+            params.course_id.path_segment()
+        ));
+
+        let topics: Vec<DiscussionTopic> = self.reader(params.cache_bypass).get_all(&path).await?;
+
+        let mut summary = format!("Found {} topic(s):", topics.len());
+        for topic in &topics {
+            summary.push_str(&format!("\n- {} (ID {})", topic.title, topic.id));
+            if let Some(count) = topic.discussion_subentry_count {
+                summary.push_str(&format!(", {} entries", count));
+            }
+            if let Some(posted) = topic.posted_at {
+                summary.push_str(&format!(
+                    ", posted {}",
+                    format_local(posted, self.timezone())
+                ));
+            }
+        }
+
+        tool_result(summary, &topics)
+    }
+
+    #[tool(description = "Get a discussion topic and all of its entries as a threaded reply tree")]
+    pub(crate) async fn get_discussion(
+        &self,
+        #[tool(aggr)] params: GetDiscussionParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        validate_id("topic_id", &params.topic_id)?;
+        let format = content_format(params.format.as_deref(), "text")?;
+
+        let path = format!(
+            "/courses/{}/discussion_topics/{}",
+            params.course_id.path_segment(),
+            params.topic_id.path_segment()
+        );
+        let view_path = format!("{}/view", path);
+        let client = self.reader(params.cache_bypass);
+        let (mut topic, view) = tokio::try_join!(
+            client.get::<DiscussionTopic>(&path),
+            client.get::<DiscussionView>(&view_path),
+        )?;
+
+        // Participants are user objects, so their names are already anonymized
+        let authors: HashMap<FlexibleId, String> = view
+            .participants
+            .into_iter()
+            .filter_map(|p| Some((p.id, p.display_name?)))
+            .collect();
+        let entries: Vec<ThreadEntry> = view
+            .view
+            .into_iter()
+            .map(|entry| ThreadEntry::from_view(entry, &authors, &format))
+            .collect();
+        topic.message = topic.message.as_deref().map(|m| render(m, &format));
+
+        let mut summary = format!("{} (ID {})", topic.title, topic.id);
+        if let Some(message) = &topic.message {
+            summary.push_str(&format!("\n\n{}\n", message));
+        }
+        for entry in &entries {
+            entry.render(0, &mut summary);
+        }
+
+        tool_result(summary, &Discussion { topic, entries })
+    }
+
+    #[tool(description = "Post a new entry to a discussion topic, or reply to an existing entry")]
+    pub(crate) async fn post_discussion_entry(
+        &self,
+        #[tool(aggr)] params: PostDiscussionEntryParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        validate_id("topic_id", &params.topic_id)?;
+        // Canvas answers an empty message with a bare 400
+        if params.message.trim().is_empty() {
+            return Err(McpError::from(CanvasError::InvalidParameter(
+                "message must not be empty".to_string(),
+            )));
+        }
+
+        let mut path = format!(
+            "/courses/{}/discussion_topics/{}/entries",
+            params.course_id.path_segment(),
+            params.topic_id.path_segment()
+        );
+        if let Some(parent) = &params.parent_entry_id {
+            validate_id("parent_entry_id", parent)?;
+            path.push_str(&format!("/{}/replies", parent.path_segment()));
+        }
+
+        let entry: DiscussionEntry = self
+            .client
+            .post_form(&path, &[("message", params.message.as_str())])
+            .await?;
+
+        let summary = format!(
+            "Posted {} {} to topic {} at {}",
+            if params.parent_entry_id.is_some() {
+                "reply"
+            } else {
+                "entry"
+            },
+            entry.id,
+            params.topic_id,
+            entry
+                .created_at
+                .map(|t| format_local(t, self.timezone()))
+                .unwrap_or_else(|| "unknown time".to_string()),
+        );
+
+        self.write_result(summary, &entry)
+    }
+}
+
+/// Parse an RFC 3339 timestamp that must lie in the future
+fn parse_future_time(value: &str) -> Result<DateTime<Utc>, McpError> {
+    let at = parse_datetime("delayed_post_at", value)?;
+    if at <= Utc::now() {
+        return Err(McpError::from(CanvasError::InvalidParameter(format!(
+            "delayed_post_at must be in the future (got {})",
+            value
+        ))));
+    }
+    Ok(at)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_post_reply_to_entry() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock(
+                "POST",
+                "/api/v1/courses/1/discussion_topics/5/entries/9/replies",
+            )
+            .match_body(mockito::Matcher::UrlEncoded(
+                "message".into(),
+                "Good point!".into(),
+            ))
+            .with_body(r#"{"id": 12, "parent_id": 9, "message": "Good point!", "created_at": "2025-09-01T12:00:00Z"}"#)
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .post_discussion_entry(PostDiscussionEntryParams {
+                course_id: FlexibleId::from(1),
+                topic_id: FlexibleId::from(5),
+                parent_entry_id: Some(FlexibleId::from(9)),
+                message: "Good point!".to_string(),
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains("reply 12"));
+        assert!(summary.ends_with("at Mon, Sep 1 at 12:00 PM"));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_get_discussion_builds_anonymized_tree() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/courses/1/discussion_topics/5")
+            .with_body(
+                r#"{"id": 5, "title": "Week 1", "message": "<p>Introduce yourself</p>",
+                    "user_name": "Jane Doe", "author": {"id": 42, "display_name": "Jane Doe"}}"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/api/v1/courses/1/discussion_topics/5/view")
+            .with_body(
+                r#"{"participants": [
+                        {"id": 42, "display_name": "Jane Doe", "avatar_image_url": null},
+                        {"id": 7, "display_name": "Pat Teacher"}],
+                    "view": [{"id": 20, "user_id": 42, "message": "<p>Hi, I'm <b>Jane</b></p>",
+                              "replies": [{"id": 21, "user_id": 7, "parent_id": 20,
+                                           "message": "<p>Welcome!</p>"}]}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let mut config = CanvasConfig::new("token".to_string(), server.url());
+        config.enable_anonymization = true;
+        config.anonymization_salt = "salt".to_string();
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+
+        let result = canvas
+            .get_discussion(GetDiscussionParams {
+                course_id: FlexibleId::from(1),
+                topic_id: FlexibleId::from(5),
+                format: None,
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        let jane = crate::anonymize::pseudonym("42", "salt");
+        let teacher = crate::anonymize::pseudonym("7", "salt");
+        assert!(summary.contains("Introduce yourself"));
+        assert!(summary.contains(&format!("\n- {}: Hi, I'm Jane", jane)));
+        assert!(summary.contains(&format!("\n  - {}: Welcome!", teacher)));
+        assert!(!summary.contains("Jane Doe"));
+        let data = crate::tools::result_json(&result);
+        assert_eq!(data["topic"]["user_name"], jane.as_str());
+        assert_eq!(data["topic"]["author"]["display_name"], jane.as_str());
+        assert!(!data.to_string().contains("Jane Doe"));
+    }
+
+    #[tokio::test]
+    async fn test_create_scheduled_announcement() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("POST", "/api/v1/courses/1/discussion_topics")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("title".into(), "Exam moved".into()),
+                mockito::Matcher::UrlEncoded("is_announcement".into(), "true".into()),
+                mockito::Matcher::UrlEncoded(
+                    "delayed_post_at".into(),
+                    "2099-01-05T15:00:00+00:00".into(),
+                ),
+            ]))
+            .with_body(
+                r#"{"id": 77, "title": "Exam moved", "delayed_post_at": "2099-01-05T15:00:00Z",
+                    "html_url": "https://canvas.example.edu/courses/1/discussion_topics/77"}"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .create_announcement(CreateAnnouncementParams {
+                course_id: FlexibleId::from(1),
+                title: "Exam moved".to_string(),
+                message: "<p>The exam is now on Friday.</p>".to_string(),
+                delayed_post_at: Some("2099-01-05T08:00:00-07:00".to_string()),
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.starts_with("Scheduled announcement 77"));
+        assert!(summary.ends_with("/discussion_topics/77"));
+        mock.assert_async().await;
+
+        let past = canvas
+            .create_announcement(CreateAnnouncementParams {
+                course_id: FlexibleId::from(1),
+                title: "Old".to_string(),
+                message: "Too late".to_string(),
+                delayed_post_at: Some("2001-01-01T00:00:00Z".to_string()),
+            })
+            .await
+            .unwrap_err();
+        assert!(past.message.contains("must be in the future"));
+    }
+
+    #[tokio::test]
+    async fn test_empty_message_is_rejected() {
+        let config = Arc::new(CanvasConfig::new(
+            "token".to_string(),
+            "http://localhost:1".to_string(),
+        ));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let error = canvas
+            .post_discussion_entry(PostDiscussionEntryParams {
+                course_id: FlexibleId::from(1),
+                topic_id: FlexibleId::from(5),
+                parent_entry_id: None,
+                message: "  ".to_string(),
+            })
+            .await
+            .unwrap_err();
+        assert!(error.message.contains("message must not be empty"));
+    }
+}
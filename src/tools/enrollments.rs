@@ -0,0 +1,175 @@
+use super::{check_choice, tool_result, validate_id};
+use crate::client::QueryBuilder;
+use crate::datetime::format_local;
+use crate::models::{Enrollment, FlexibleId};
+use crate::server::CanvasServer;
+use chrono::{DateTime, Utc};
+use rmcp::model::CallToolResult;
+use rmcp::{schemars, tool, Error as McpError};
+use serde::{Deserialize, Serialize};
+
+/// Enrollment types accepted by the `type[]` filter
+const ENROLLMENT_TYPES: &[&str] = &["StudentEnrollment", "TeacherEnrollment", "TaEnrollment"];
+
+/// Enrollment states accepted by the `state[]` filter
+const ENROLLMENT_STATES: &[&str] = &["active", "invited", "completed"];
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListEnrollmentsParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Only these enrollment types: "StudentEnrollment", "TeacherEnrollment", "TaEnrollment"
+    #[serde(rename = "type")]
+    pub types: Option<Vec<String>>,
+
+    /// Only these states: "active", "invited", "completed" (Canvas defaults to
+    /// active and invited)
+    pub state: Option<Vec<String>>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+/// One roster entry as returned by `list_enrollments`
+#[derive(Debug, Serialize)]
+pub struct EnrollmentRow {
+    pub user_id: Option<FlexibleId>,
+    pub name: Option<String>,
+    pub role: String,
+    pub enrollment_state: Option<String>,
+    pub last_activity_at: Option<DateTime<Utc>>,
+}
+
+impl From<Enrollment> for EnrollmentRow {
+    fn from(enrollment: Enrollment) -> Self {
+        Self {
+            user_id: enrollment
+                .user_id
+                .or_else(|| enrollment.user.as_ref().map(|u| u.id.clone())),
+            name: enrollment.user.map(|u| u.name),
+            role: enrollment.role.unwrap_or(enrollment.enrollment_type),
+            enrollment_state: enrollment.enrollment_state,
+            last_activity_at: enrollment.last_activity_at,
+        }
+    }
+}
+
+impl CanvasServer {
+    #[tool(
+        description = "List the people enrolled in a course with their role and last activity, optionally filtered by enrollment type and state"
+    )]
+    pub(crate) async fn list_enrollments(
+        &self,
+        #[tool(aggr)] params: ListEnrollmentsParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+
+        let mut query = QueryBuilder::new();
+        for enrollment_type in params.types.iter().flatten() {
+            check_choice("type", enrollment_type, ENROLLMENT_TYPES)?;
+            query.param("type[]", enrollment_type);
+        }
+        for state in params.state.iter().flatten() {
+            check_choice("state", state, ENROLLMENT_STATES)?;
+            query.param("state[]", state);
+        }
+        let path = query.to_path(&format!(
+            "/courses/{}/enrollments",
+            params.course_id.path_segment()
+        ));
+
+        let enrollments: Vec<Enrollment> = self.reader(params.cache_bypass).get_all(&path).await?;
+        let rows: Vec<EnrollmentRow> = enrollments.into_iter().map(EnrollmentRow::from).collect();
+
+        let mut summary = format!(
+            "Found {} enrollment(s) in course {}:",
+            rows.len(),
+            params.course_id
+        );
+        for row in &rows {
+            summary.push_str(&format!(
+                "\n- {} (ID {}) — {}, last active {}",
+                row.name.as_deref().unwrap_or("unknown user"),
+                row.user_id
+                    .as_ref()
+                    .map(|id| id.to_string())
+                    .unwrap_or_else(|| "?".to_string()),
+                row.role,
+                row.last_activity_at
+                    .map(|at| format_local(at, self.timezone()))
+                    .unwrap_or_else(|| "never".to_string()),
+            ));
+        }
+
+        tool_result(summary, &rows)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::anonymize::pseudonym;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_list_enrollments_filters_and_anonymizes() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock(
+                "GET",
+                "/api/v1/courses/1/enrollments?type[]=StudentEnrollment&state[]=active&per_page=100",
+            )
+            .with_body(
+                r#"[{"id": 5, "user_id": 42, "type": "StudentEnrollment",
+                     "role": "StudentEnrollment", "enrollment_state": "active",
+                     "last_activity_at": "2025-09-01T12:00:00Z",
+                     "user": {"id": 42, "name": "Jane Doe", "sortable_name": "Doe, Jane"}}]"#,
+            )
+            .create_async()
+            .await;
+
+        let mut config = CanvasConfig::new("token".to_string(), server.url());
+        config.enable_anonymization = true;
+        config.anonymization_salt = "salt".to_string();
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+
+        let result = canvas
+            .list_enrollments(ListEnrollmentsParams {
+                course_id: FlexibleId::from(1),
+                types: Some(vec!["StudentEnrollment".to_string()]),
+                state: Some(vec!["active".to_string()]),
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains(&format!(
+            "{} (ID 42) — StudentEnrollment",
+            pseudonym("42", "salt")
+        )));
+        assert!(!summary.contains("Jane"));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_list_enrollments_rejects_unknown_type() {
+        let config = Arc::new(CanvasConfig::new(
+            "token".to_string(),
+            "https://example.instructure.com".to_string(),
+        ));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .list_enrollments(ListEnrollmentsParams {
+                course_id: FlexibleId::from(1),
+                types: Some(vec!["ObserverEnrollment".to_string()]),
+                state: None,
+                cache_bypass: None,
+            })
+            .await;
+        assert!(result.is_err());
+    }
+}
@@ -0,0 +1,495 @@
+use super::{tool_result, validate_id};
+use crate::client::QueryBuilder;
+use crate::error::CanvasError;
+use crate::models::{CanvasFile, FlexibleId, Folder};
+use crate::server::CanvasServer;
+use base64::Engine;
+use rmcp::model::CallToolResult;
+use rmcp::{schemars, tool, Error as McpError};
+use serde::{Deserialize, Serialize};
+use std::path::{Component, Path, PathBuf};
+
+/// Files up to this size can be returned inline as base64 instead of saved to disk
+const INLINE_DOWNLOAD_LIMIT: u64 = 1024 * 1024;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListFilesParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Only files in this folder (from list_folders) instead of the whole course
+    pub folder_id: Option<FlexibleId>,
+
+    /// Only files whose name contains this text (at least 2 characters)
+    pub search_term: Option<String>,
+
+    /// Only these content types, e.g. "application/pdf" or "image" for all images
+    pub content_types: Option<Vec<String>>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListFoldersParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct DownloadFileParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Canvas file ID (from list_files)
+    pub file_id: FlexibleId,
+
+    /// File or existing directory to save to, relative to CANVAS_DOWNLOAD_DIR
+    /// ("." for the directory itself). When omitted, files up to 1 MB are
+    /// returned inline as base64.
+    pub target_path: Option<String>,
+
+    /// Replace the target file if it already exists (default false)
+    pub overwrite: Option<bool>,
+}
+
+/// Result of `download_file`
+#[derive(Debug, Serialize)]
+struct Download {
+    file: CanvasFile,
+    /// Where the file was written, when saved to disk
+    path: Option<PathBuf>,
+    bytes: u64,
+    /// File contents, when returned inline
+    base64: Option<String>,
+}
+
+/// Resolve where a download should be written inside `root`, refusing
+/// absolute paths, `..` components, anything that resolves outside `root`
+/// (e.g. through a symlink), and existing files unless `overwrite` is set
+///
+/// If `target` is a directory the file's own name is used inside it, reduced
+/// to a single path component so a crafted Canvas filename can't escape it.
+fn resolve_target(
+    root: &Path,
+    target: &str,
+    file_name: &str,
+    overwrite: bool,
+) -> crate::Result<PathBuf> {
+    let root = root.canonicalize().map_err(|e| {
+        CanvasError::config(format!(
+            "CANVAS_DOWNLOAD_DIR {} is not usable: {}",
+            root.display(),
+            e
+        ))
+    })?;
+    let target = Path::new(target.trim());
+    if target.as_os_str().is_empty() {
+        return Err(CanvasError::InvalidParameter(
+            "target_path must not be empty (use \".\" for the download directory)".to_string(),
+        ));
+    }
+    if target
+        .components()
+        .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir))
+    {
+        return Err(CanvasError::InvalidParameter(format!(
+            "target_path must be relative to the download directory and must not contain '..' (got {})",
+            target.display()
+        )));
+    }
+
+    let path = root.join(target);
+    let path = if path.is_dir() {
+        let name = Path::new(file_name)
+            .file_name()
+            .and_then(|n| n.to_str())
+            .map(|n| n.replace(['/', '\\'], "_"))
+            .filter(|n| !n.is_empty() && n != "." && n != "..")
+            .unwrap_or_else(|| "download".to_string());
+        path.join(name)
+    } else {
+        path
+    };
+
+    let (Some(parent), Some(name)) = (path.parent(), path.file_name()) else {
+        return Err(CanvasError::InvalidParameter(format!(
+            "target_path {} does not name a file",
+            target.display()
+        )));
+    };
+    let parent = parent.canonicalize().map_err(|_| {
+        CanvasError::InvalidParameter(format!("Directory {} does not exist", parent.display()))
+    })?;
+    if !parent.starts_with(&root) {
+        return Err(CanvasError::InvalidParameter(format!(
+            "target_path {} resolves outside the download directory",
+            target.display()
+        )));
+    }
+
+    let path = parent.join(name);
+    if path.is_dir() {
+        return Err(CanvasError::InvalidParameter(format!(
+            "{} is a directory",
+            path.display()
+        )));
+    }
+    if path.symlink_metadata().is_ok() && !overwrite {
+        return Err(CanvasError::InvalidParameter(format!(
+            "{} already exists; set overwrite to replace it",
+            path.display()
+        )));
+    }
+    Ok(path)
+}
+
+/// Human-readable file size, e.g. "2.4 MB"
+fn format_size(bytes: u64) -> String {
+    const UNITS: &[&str] = &["KB", "MB", "GB"];
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+impl CanvasServer {
+    /// Download `url` to `path` via a temporary file in the same directory,
+    /// renamed into place only once the download completes, so a failed
+    /// download never clobbers an existing file
+    async fn download_to_path(&self, url: &str, path: &Path) -> crate::Result<u64> {
+        let name = path
+            .file_name()
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_default();
+        let partial = path.with_file_name(format!(".{}.{}.part", name, uuid::Uuid::new_v4()));
+        let writer = tokio::fs::OpenOptions::new()
+            .write(true)
+            .create_new(true)
+            .open(&partial)
+            .await
+            .map_err(|e| {
+                CanvasError::internal(format!("Failed to create {}: {}", partial.display(), e))
+            })?;
+
+        let result = match self.client.download_to(url, writer, None).await {
+            Ok(bytes) => tokio::fs::rename(&partial, path)
+                .await
+                .map(|_| bytes)
+                .map_err(|e| {
+                    CanvasError::internal(format!("Failed to save {}: {}", path.display(), e))
+                }),
+            Err(e) => Err(e),
+        };
+        if result.is_err() {
+            let _ = tokio::fs::remove_file(&partial).await;
+        }
+        result
+    }
+
+    #[tool(
+        description = "Find files in a course (or one of its folders) by name or content type, with size and download URL; use download_file to fetch one"
+    )]
+    pub(crate) async fn list_files(
+        &self,
+        #[tool(aggr)] params: ListFilesParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+
+        let mut query = QueryBuilder::new();
+        if let Some(term) = params.search_term.as_deref().map(str::trim) {
+            // Canvas rejects shorter search terms with a 400
+            if term.chars().count() < 2 {
+                return Err(McpError::from(CanvasError::InvalidParameter(
+                    "search_term must be at least 2 characters".to_string(),
+                )));
+            }
+            query.param("search_term", term);
+        }
+        for content_type in params.content_types.iter().flatten() {
+            query.param("content_types[]", content_type);
+        }
+        query.param("sort", "name");
+        let path = match &params.folder_id {
+            Some(folder_id) => {
+                validate_id("folder_id", folder_id)?;
+                format!("/folders/{}/files", folder_id.path_segment())
+            }
+            None => format!("/courses/{}/files", params.course_id.path_segment()),
+        };
+
+        let files: Vec<CanvasFile> = self
+            .reader(params.cache_bypass)
+            .get_all(&query.to_path(&path))
+            .await?;
+
+        let mut summary = format!("Found {} file(s):", files.len());
+        for file in &files {
+            summary.push_str(&format!("\n- {} (ID {}", file.display_name, file.id));
+            if let Some(size) = file.size {
+                summary.push_str(&format!(", {}", format_size(size)));
+            }
+            if let Some(content_type) = &file.content_type {
+                summary.push_str(&format!(", {}", content_type));
+            }
+            summary.push(')');
+            if file.locked_for_user == Some(true) {
+                summary.push_str(" [locked]");
+            }
+        }
+
+        tool_result(summary, &files)
+    }
+
+    #[tool(
+        description = "List every folder in a course's files area with its path and contents count"
+    )]
+    pub(crate) async fn list_folders(
+        &self,
+        #[tool(aggr)] params: ListFoldersParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+
+        let path = format!("/courses/{}/folders", params.course_id.path_segment());
+        let mut folders: Vec<Folder> = self.reader(params.cache_bypass).get_all(&path).await?;
+        folders.sort_by(|a, b| a.full_name.cmp(&b.full_name));
+
+        let mut summary = format!("Found {} folder(s):", folders.len());
+        for folder in &folders {
+            summary.push_str(&format!(
+                "\n- {} (ID {}): {} file(s), {} subfolder(s)",
+                folder.full_name.as_deref().unwrap_or(&folder.name),
+                folder.id,
+                folder.files_count.unwrap_or(0),
+                folder.folders_count.unwrap_or(0)
+            ));
+        }
+
+        tool_result(summary, &folders)
+    }
+
+    #[tool(
+        description = "Download a course file into the configured download directory (CANVAS_DOWNLOAD_DIR), or return it inline as base64 if it is 1 MB or smaller"
+    )]
+    pub(crate) async fn download_file(
+        &self,
+        #[tool(aggr)] params: DownloadFileParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        validate_id("file_id", &params.file_id)?;
+
+        let file: CanvasFile = self
+            .client
+            .get(&format!(
+                "/courses/{}/files/{}",
+                params.course_id.path_segment(),
+                params.file_id.path_segment()
+            ))
+            .await?;
+        let url = file.url.clone().filter(|u| !u.is_empty()).ok_or_else(|| {
+            McpError::from(CanvasError::auth(format!(
+                "{} is locked or has no download URL for this user",
+                file.display_name
+            )))
+        })?;
+
+        let download = match &params.target_path {
+            Some(target) => {
+                let root = self.config.download_dir.as_deref().ok_or_else(|| {
+                    McpError::from(CanvasError::config(
+                        "download_file can only save to disk when CANVAS_DOWNLOAD_DIR is set; omit target_path to get small files inline",
+                    ))
+                })?;
+                let name = file.filename.as_deref().unwrap_or(&file.display_name);
+                let path = resolve_target(root, target, name, params.overwrite.unwrap_or(false))?;
+                let bytes = self.download_to_path(&url, &path).await?;
+                Download {
+                    file,
+                    path: Some(path),
+                    bytes,
+                    base64: None,
+                }
+            }
+            None => {
+                if let Some(size) = file.size.filter(|s| *s > INLINE_DOWNLOAD_LIMIT) {
+                    return Err(McpError::from(CanvasError::InvalidParameter(format!(
+                        "{} is {}, too large to return inline; set target_path to save it to disk",
+                        file.display_name,
+                        format_size(size)
+                    ))));
+                }
+                let mut buffer = Vec::new();
+                let bytes = self
+                    .client
+                    .download_to(&url, &mut buffer, Some(INLINE_DOWNLOAD_LIMIT))
+                    .await?;
+                Download {
+                    file,
+                    path: None,
+                    bytes,
+                    base64: Some(base64::engine::general_purpose::STANDARD.encode(&buffer)),
+                }
+            }
+        };
+
+        let summary = match &download.path {
+            Some(path) => format!(
+                "Saved {} ({}) to {}",
+                download.file.display_name,
+                format_size(download.bytes),
+                path.display()
+            ),
+            None => format!(
+                "Downloaded {} ({}); contents are base64-encoded in the data below",
+                download.file.display_name,
+                format_size(download.bytes)
+            ),
+        };
+
+        tool_result(summary, &download)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(2048), "2.0 KB");
+        assert_eq!(format_size(5 * 1024 * 1024 + 512 * 1024), "5.5 MB");
+    }
+
+    #[test]
+    fn test_resolve_target_guards_paths() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path().canonicalize().unwrap();
+
+        let inside = resolve_target(&root, ".", "../../etc/passwd", false).unwrap();
+        assert_eq!(inside, root.join("passwd"));
+        std::fs::create_dir(root.join("week1")).unwrap();
+        let nested = resolve_target(&root, "week1/notes.pdf", "x.pdf", false).unwrap();
+        assert_eq!(nested, root.join("week1").join("notes.pdf"));
+
+        assert!(resolve_target(&root, "../escape.pdf", "x.pdf", false).is_err());
+        assert!(resolve_target(&root, "/root/.ssh/authorized_keys", "x", true).is_err());
+        let outside = tempfile::tempdir().unwrap();
+        #[cfg(unix)]
+        {
+            std::os::unix::fs::symlink(outside.path(), root.join("link")).unwrap();
+            assert!(resolve_target(&root, "link/x.pdf", "x.pdf", true).is_err());
+        }
+
+        std::fs::write(root.join("notes.pdf"), b"old").unwrap();
+        assert!(resolve_target(&root, "notes.pdf", "notes.pdf", false).is_err());
+        assert!(resolve_target(&root, "notes.pdf", "notes.pdf", true).is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_download_file_to_directory() {
+        let mut server = mockito::Server::new_async().await;
+        let download_url = format!("{}/files/9/download", server.url());
+        server
+            .mock("GET", "/api/v1/courses/1/files/9")
+            .with_body(format!(
+                r#"{{"id": 9, "display_name": "Syllabus.pdf", "filename": "syllabus.pdf",
+                     "size": 5, "url": "{}"}}"#,
+                download_url
+            ))
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/files/9/download")
+            .with_body("%PDF-")
+            .create_async()
+            .await;
+
+        server
+            .mock("GET", "/api/v1/courses/1/files/10")
+            .with_body(format!(
+                r#"{{"id": 10, "display_name": "Gone.pdf", "filename": "syllabus.pdf",
+                     "url": "{}/files/10/download"}}"#,
+                server.url()
+            ))
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/files/10/download")
+            .with_status(500)
+            .create_async()
+            .await;
+
+        let dir = tempfile::tempdir().unwrap();
+        let mut config = CanvasConfig::new("token".to_string(), server.url());
+        config.download_dir = Some(dir.path().to_path_buf());
+        config.max_retries = 0;
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+        let params = |file_id: u64, overwrite: Option<bool>| DownloadFileParams {
+            course_id: FlexibleId::from(1),
+            file_id: FlexibleId::from(file_id),
+            target_path: Some(".".to_string()),
+            overwrite,
+        };
+
+        canvas.download_file(params(9, None)).await.unwrap();
+        assert_eq!(
+            std::fs::read(dir.path().join("syllabus.pdf")).unwrap(),
+            b"%PDF-"
+        );
+
+        // A failed download leaves the existing file as it was
+        assert!(canvas.download_file(params(10, Some(true))).await.is_err());
+        assert_eq!(
+            std::fs::read(dir.path().join("syllabus.pdf")).unwrap(),
+            b"%PDF-"
+        );
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_list_files_with_filters() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock(
+                "GET",
+                "/api/v1/courses/1/files?search_term=slides&content_types[]=application%2Fpdf&sort=name&per_page=100",
+            )
+            .with_body(
+                r#"[{"id": 9, "display_name": "Lecture 1 slides.pdf", "size": 2097152,
+                     "content-type": "application/pdf",
+                     "url": "https://canvas.example.edu/files/9/download"}]"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .list_files(ListFilesParams {
+                course_id: FlexibleId::from(1),
+                folder_id: None,
+                search_term: Some("slides".to_string()),
+                content_types: Some(vec!["application/pdf".to_string()]),
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains("Lecture 1 slides.pdf (ID 9, 2.0 MB, application/pdf)"));
+        mock.assert_async().await;
+    }
+}
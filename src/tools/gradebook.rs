@@ -0,0 +1,328 @@
+use super::{tool_result, validate_id};
+use crate::client::QueryBuilder;
+use crate::error::CanvasError;
+use crate::models::{Assignment, FlexibleId, Submission, User};
+use crate::server::CanvasServer;
+use rmcp::model::{CallToolResult, Content};
+use rmcp::{schemars, tool, Error as McpError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetGradebookParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+/// Student × assignment score matrix for a course
+#[derive(Debug, Serialize)]
+pub struct Gradebook {
+    /// Column order for every row's `scores`
+    pub assignments: Vec<GradebookColumn>,
+    pub rows: Vec<GradebookRow>,
+}
+
+/// An assignment column in the gradebook
+#[derive(Debug, Serialize)]
+pub struct GradebookColumn {
+    pub id: FlexibleId,
+    pub name: String,
+    pub points_possible: Option<f64>,
+}
+
+/// One student's scores, aligned with `Gradebook::assignments`
+#[derive(Debug, Serialize)]
+pub struct GradebookRow {
+    pub user_id: FlexibleId,
+    pub name: String,
+    /// `None` where the submission is ungraded or missing
+    pub scores: Vec<Option<f64>>,
+    /// Sum of the student's scores
+    pub total: f64,
+    /// Sum of points possible over the assignments the student has a score on
+    pub points_possible: f64,
+}
+
+impl Gradebook {
+    /// Assemble the matrix from a course's assignments, students, and submissions
+    ///
+    /// Submissions from users who aren't in `students` (e.g. the test student)
+    /// are ignored.
+    pub fn build(
+        assignments: Vec<Assignment>,
+        students: Vec<User>,
+        submissions: &[Submission],
+    ) -> Self {
+        let columns: HashMap<&FlexibleId, usize> = assignments
+            .iter()
+            .enumerate()
+            .map(|(index, assignment)| (&assignment.id, index))
+            .collect();
+
+        let mut rows: Vec<GradebookRow> = students
+            .into_iter()
+            .map(|student| GradebookRow {
+                user_id: student.id,
+                name: student.name,
+                scores: vec![None; assignments.len()],
+                total: 0.0,
+                points_possible: 0.0,
+            })
+            .collect();
+        let row_index: HashMap<FlexibleId, usize> = rows
+            .iter()
+            .enumerate()
+            .map(|(index, row)| (row.user_id.clone(), index))
+            .collect();
+
+        for submission in submissions {
+            let (Some(user_id), Some(assignment_id), Some(score)) = (
+                submission.user_id.as_ref(),
+                submission.assignment_id.as_ref(),
+                submission.score,
+            ) else {
+                continue;
+            };
+            let (Some(&row), Some(&column)) = (row_index.get(user_id), columns.get(assignment_id))
+            else {
+                continue;
+            };
+            rows[row].scores[column] = Some(score);
+        }
+
+        for row in &mut rows {
+            for (score, assignment) in row.scores.iter().zip(&assignments) {
+                if let Some(score) = score {
+                    row.total += score;
+                    row.points_possible += assignment.points_possible.unwrap_or(0.0);
+                }
+            }
+        }
+
+        Self {
+            assignments: assignments
+                .into_iter()
+                .map(|a| GradebookColumn {
+                    id: a.id,
+                    name: a.name,
+                    points_possible: a.points_possible,
+                })
+                .collect(),
+            rows,
+        }
+    }
+}
+
+impl Gradebook {
+    /// Serialize as CSV: a header row of assignment names, then one row per student
+    ///
+    /// Blank cells are ungraded or missing work.
+    pub fn to_csv(&self) -> crate::Result<String> {
+        let mut writer = csv::Writer::from_writer(Vec::new());
+
+        let mut header = vec!["Student".to_string(), "ID".to_string()];
+        header.extend(self.assignments.iter().map(|a| match a.points_possible {
+            Some(points) => format!("{} ({})", a.name, points),
+            None => a.name.clone(),
+        }));
+        header.extend(["Total".to_string(), "Points Possible".to_string()]);
+        writer.write_record(&header).map_err(csv_error)?;
+
+        for row in &self.rows {
+            let mut record = vec![row.name.clone(), row.user_id.to_string()];
+            record.extend(
+                row.scores
+                    .iter()
+                    .map(|score| score.map(|s| s.to_string()).unwrap_or_default()),
+            );
+            record.extend([row.total.to_string(), row.points_possible.to_string()]);
+            writer.write_record(&record).map_err(csv_error)?;
+        }
+
+        let bytes = writer
+            .into_inner()
+            .map_err(|e| CanvasError::internal(format!("Failed to write CSV: {}", e)))?;
+        String::from_utf8(bytes)
+            .map_err(|e| CanvasError::internal(format!("CSV is not valid UTF-8: {}", e)))
+    }
+}
+
+fn csv_error(e: csv::Error) -> CanvasError {
+    CanvasError::internal(format!("Failed to write CSV: {}", e))
+}
+
+impl CanvasServer {
+    /// Fetch assignments, students, and submissions concurrently and build the gradebook
+    pub(crate) async fn fetch_gradebook(
+        &self,
+        course_id: &FlexibleId,
+        cache_bypass: Option<bool>,
+    ) -> crate::Result<Gradebook> {
+        let course = course_id.path_segment();
+        let assignments_path = format!("/courses/{}/assignments", course);
+        let students_path = QueryBuilder::new()
+            .param("enrollment_type[]", "student")
+            .to_path(&format!("/courses/{}/users", course));
+        let submissions_path = QueryBuilder::new()
+            .param("student_ids[]", "all")
+            .to_path(&format!("/courses/{}/students/submissions", course));
+
+        let client = self.reader(cache_bypass);
+        let (assignments, students, submissions) = tokio::try_join!(
+            client.get_all::<Assignment>(&assignments_path),
+            client.get_all::<User>(&students_path),
+            client.get_all::<Submission>(&submissions_path),
+        )?;
+
+        Ok(Gradebook::build(assignments, students, &submissions))
+    }
+
+    #[tool(
+        description = "Get a course gradebook: every student's score on every assignment, with points possible and per-student totals"
+    )]
+    pub(crate) async fn get_gradebook(
+        &self,
+        #[tool(aggr)] params: GetGradebookParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+
+        let gradebook = self
+            .fetch_gradebook(&params.course_id, params.cache_bypass)
+            .await?;
+
+        let mut summary = format!(
+            "Gradebook for course {}: {} student(s) × {} assignment(s)",
+            params.course_id,
+            gradebook.rows.len(),
+            gradebook.assignments.len()
+        );
+        for row in &gradebook.rows {
+            summary.push_str(&format!(
+                "\n- {} (ID {}): {} / {}",
+                row.name, row.user_id, row.total, row.points_possible
+            ));
+        }
+
+        tool_result(summary, &gradebook)
+    }
+
+    #[tool(
+        description = "Export a course gradebook as CSV (one row per student, one column per assignment) ready to save or paste into a spreadsheet"
+    )]
+    pub(crate) async fn export_gradebook_csv(
+        &self,
+        #[tool(aggr)] params: GetGradebookParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+
+        let gradebook = self
+            .fetch_gradebook(&params.course_id, params.cache_bypass)
+            .await?;
+        let csv = gradebook.to_csv()?;
+
+        Ok(CallToolResult::success(vec![
+            Content::text(format!(
+                "Gradebook CSV for course {}: {} student(s) × {} assignment(s)",
+                params.course_id,
+                gradebook.rows.len(),
+                gradebook.assignments.len()
+            )),
+            Content::text(csv),
+        ]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::anonymize::pseudonym;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_get_gradebook_builds_matrix() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/courses/1/assignments?per_page=100")
+            .with_body(
+                r#"[{"id": 10, "name": "Essay", "points_possible": 10.0},
+                    {"id": 11, "name": "Quiz", "points_possible": 5.0}]"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock(
+                "GET",
+                "/api/v1/courses/1/users?enrollment_type[]=student&per_page=100",
+            )
+            .with_body(
+                r#"[{"id": 42, "name": "Jane Doe", "sortable_name": "Doe, Jane"},
+                    {"id": 43, "name": "John Roe", "sortable_name": "Roe, John"}]"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock(
+                "GET",
+                "/api/v1/courses/1/students/submissions?student_ids[]=all&per_page=100",
+            )
+            .with_body(
+                r#"[{"user_id": 42, "assignment_id": 10, "score": 8.5},
+                    {"user_id": 42, "assignment_id": 11, "score": null},
+                    {"user_id": 43, "assignment_id": 11, "score": 5.0},
+                    {"user_id": 99, "assignment_id": 10, "score": 1.0}]"#,
+            )
+            .create_async()
+            .await;
+
+        let mut config = CanvasConfig::new("token".to_string(), server.url());
+        config.enable_anonymization = true;
+        config.anonymization_salt = "salt".to_string();
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+
+        let gradebook = canvas
+            .fetch_gradebook(&FlexibleId::from(1), None)
+            .await
+            .unwrap();
+        assert_eq!(gradebook.assignments.len(), 2);
+        assert_eq!(gradebook.rows.len(), 2);
+
+        let jane = &gradebook.rows[0];
+        assert_eq!(jane.name, pseudonym("42", "salt"));
+        assert_eq!(jane.scores, vec![Some(8.5), None]);
+        assert_eq!((jane.total, jane.points_possible), (8.5, 10.0));
+
+        let john = &gradebook.rows[1];
+        assert_eq!(john.scores, vec![None, Some(5.0)]);
+        assert_eq!((john.total, john.points_possible), (5.0, 5.0));
+    }
+
+    #[test]
+    fn test_gradebook_csv_escapes_names() {
+        let gradebook = Gradebook {
+            assignments: vec![GradebookColumn {
+                id: FlexibleId::from(10),
+                name: r#"Essay, "Final""#.to_string(),
+                points_possible: Some(10.0),
+            }],
+            rows: vec![GradebookRow {
+                user_id: FlexibleId::from(42),
+                name: "Doe, Jane".to_string(),
+                scores: vec![None],
+                total: 0.0,
+                points_possible: 0.0,
+            }],
+        };
+
+        let csv = gradebook.to_csv().unwrap();
+        let mut lines = csv.lines();
+        assert_eq!(
+            lines.next(),
+            Some(r#"Student,ID,"Essay, ""Final"" (10)",Total,Points Possible"#)
+        );
+        assert_eq!(lines.next(), Some(r#""Doe, Jane",42,,0,0"#));
+    }
+}
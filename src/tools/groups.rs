@@ -0,0 +1,166 @@
+use super::{tool_result, validate_id};
+use crate::client::QueryBuilder;
+use crate::models::{FlexibleId, Group, GroupCategory, User};
+use crate::server::CanvasServer;
+use rmcp::model::CallToolResult;
+use rmcp::{schemars, tool, Error as McpError};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListGroupsParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Only groups in this group set (category)
+    pub group_category_id: Option<FlexibleId>,
+
+    /// Include each group's members
+    pub include_members: Option<bool>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListGroupMembersParams {
+    /// Canvas group ID
+    pub group_id: FlexibleId,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+impl CanvasServer {
+    #[tool(
+        description = "List a course's student groups with their group set and size, optionally with each group's members"
+    )]
+    pub(crate) async fn list_groups(
+        &self,
+        #[tool(aggr)] params: ListGroupsParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+
+        let mut query = QueryBuilder::new();
+        if params.include_members.unwrap_or(false) {
+            query.include("users");
+        }
+        // Courses don't filter groups by category, but each category lists its own
+        let path = query.to_path(&match &params.group_category_id {
+            Some(category_id) => {
+                validate_id("group_category_id", category_id)?;
+                format!("/group_categories/{}/groups", category_id.path_segment())
+            }
+            None => format!("/courses/{}/groups", params.course_id.path_segment()),
+        });
+        let categories_path = format!(
+            "/courses/{}/group_categories",
+            params.course_id.path_segment()
+        );
+
+        let client = self.reader(params.cache_bypass);
+        let (groups, categories) = tokio::try_join!(
+            client.get_all::<Group>(&path),
+            client.get_all::<GroupCategory>(&categories_path),
+        )?;
+        let category_names: HashMap<&FlexibleId, &str> = categories
+            .iter()
+            .map(|c| (&c.id, c.name.as_str()))
+            .collect();
+
+        let mut summary = format!("Found {} group(s):", groups.len());
+        for group in &groups {
+            summary.push_str(&format!(
+                "\n- {} (ID {}), {} member(s)",
+                group.name,
+                group.id,
+                group.members_count.unwrap_or(0)
+            ));
+            if let Some(category) = group
+                .group_category_id
+                .as_ref()
+                .and_then(|id| category_names.get(id))
+            {
+                summary.push_str(&format!(" in {}", category));
+            }
+            if let Some(users) = &group.users {
+                let names: Vec<&str> = users.iter().map(|u| u.name.as_str()).collect();
+                summary.push_str(&format!(": {}", names.join(", ")));
+            }
+        }
+
+        tool_result(
+            summary,
+            &serde_json::json!({"groups": groups, "group_categories": categories}),
+        )
+    }
+
+    #[tool(description = "List the members of a student group")]
+    pub(crate) async fn list_group_members(
+        &self,
+        #[tool(aggr)] params: ListGroupMembersParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("group_id", &params.group_id)?;
+
+        let path = format!("/groups/{}/users", params.group_id.path_segment());
+        let members: Vec<User> = self.reader(params.cache_bypass).get_all(&path).await?;
+
+        let mut summary = format!("Group {} has {} member(s):", params.group_id, members.len());
+        for member in &members {
+            summary.push_str(&format!("\n- {} (ID {})", member.name, member.id));
+        }
+
+        tool_result(summary, &members)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::anonymize::pseudonym;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_list_groups_in_category_with_members() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock(
+                "GET",
+                "/api/v1/group_categories/4/groups?include[]=users&per_page=100",
+            )
+            .with_body(
+                r#"[{"id": 20, "name": "Team 1", "group_category_id": 4, "members_count": 1,
+                     "users": [{"id": 42, "name": "Jane Doe", "sortable_name": "Doe, Jane"}]}]"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock("GET", "/api/v1/courses/1/group_categories?per_page=100")
+            .with_body(r#"[{"id": 4, "name": "Project Teams"}]"#)
+            .create_async()
+            .await;
+
+        let mut config = CanvasConfig::new("token".to_string(), server.url());
+        config.enable_anonymization = true;
+        config.anonymization_salt = "salt".to_string();
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+
+        let result = canvas
+            .list_groups(ListGroupsParams {
+                course_id: FlexibleId::from(1),
+                group_category_id: Some(FlexibleId::from(4)),
+                include_members: Some(true),
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains(&format!(
+            "Team 1 (ID 20), 1 member(s) in Project Teams: {}",
+            pseudonym("42", "salt")
+        )));
+        mock.assert_async().await;
+    }
+}
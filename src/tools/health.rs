@@ -0,0 +1,120 @@
+use super::tool_result;
+use crate::models::FlexibleId;
+use crate::server::CanvasServer;
+use rmcp::model::CallToolResult;
+use rmcp::{tool, Error as McpError};
+use serde::Serialize;
+use std::time::Instant;
+
+/// Result of a connectivity check
+#[derive(Debug, Serialize)]
+pub struct HealthStatus {
+    pub user_id: FlexibleId,
+    pub user_name: String,
+    pub api_url: String,
+    pub anonymization_enabled: bool,
+    /// Round-trip time of the `/users/self` request
+    pub latency_ms: u64,
+}
+
+impl CanvasServer {
+    #[tool(
+        description = "Check the connection to Canvas: who the token authenticates as, the API URL, and request latency. Call this first if other tools fail."
+    )]
+    pub(crate) async fn health_check(&self) -> Result<CallToolResult, McpError> {
+        let started = Instant::now();
+        let user = self.client.without_cache().get_current_user().await?;
+        let latency_ms = started.elapsed().as_millis() as u64;
+
+        let status = HealthStatus {
+            user_id: user.id,
+            user_name: user.name,
+            api_url: self.config.api_url.clone(),
+            anonymization_enabled: self.config.enable_anonymization,
+            latency_ms,
+        };
+
+        let summary = format!(
+            "OK: connected to {} as {} (ID {}) in {} ms{}",
+            status.api_url,
+            status.user_name,
+            status.user_id,
+            status.latency_ms,
+            if status.anonymization_enabled {
+                ", anonymization on"
+            } else {
+                ""
+            },
+        );
+
+        tool_result(summary, &status)
+    }
+
+    #[tool(
+        description = "Canvas API usage since the server started: request and retry counts, status classes, and latency"
+    )]
+    pub(crate) async fn get_metrics(&self) -> Result<CallToolResult, McpError> {
+        let metrics = self.client.metrics_snapshot();
+        let summary = format!(
+            "{} request(s), {} retr{}, {} 4xx, {} 5xx, {} network error(s), {} cache hit(s), average latency {}",
+            metrics.requests,
+            metrics.retries,
+            if metrics.retries == 1 { "y" } else { "ies" },
+            metrics.status_4xx,
+            metrics.status_5xx,
+            metrics.network_errors,
+            metrics.cache_hits,
+            metrics
+                .average_latency_ms
+                .map(|ms| format!("{:.0} ms", ms))
+                .unwrap_or_else(|| "n/a".to_string()),
+        );
+        tool_result(summary, &metrics)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{MockBackend, MockResponse};
+    use crate::config::CanvasConfig;
+    use reqwest::Method;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_health_check_reports_user() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/users/self")
+            .with_body(r#"{"id": 7, "name": "Pat Teacher"}"#)
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas.health_check().await.unwrap();
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.starts_with("OK: connected to"));
+        assert!(summary.contains("Pat Teacher (ID 7)"));
+    }
+
+    #[tokio::test]
+    async fn test_health_check_with_mock_backend() {
+        let backend = Arc::new(MockBackend::new().on(
+            Method::GET,
+            "/users/self",
+            MockResponse::json(r#"{"id": 7, "name": "Pat Teacher"}"#),
+        ));
+        let config = Arc::new(CanvasConfig::new(
+            "token".to_string(),
+            "https://canvas.test".to_string(),
+        ));
+        let canvas = CanvasServer::with_backend(config, backend.clone()).unwrap();
+
+        let result = canvas.health_check().await.unwrap();
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains("https://canvas.test/api/v1 as Pat Teacher"));
+        assert_eq!(backend.requests().len(), 1);
+    }
+}
@@ -0,0 +1,169 @@
+/// MCP tool implementations, grouped by Canvas area
+///
+/// Each submodule adds `#[tool]` methods to `CanvasServer`; they are
+/// registered in the tool box in `server.rs`.
+pub mod analytics;
+pub mod assignments;
+pub mod calendar;
+pub mod conversations;
+pub mod courses;
+pub mod discussions;
+pub mod enrollments;
+pub mod files;
+pub mod gradebook;
+pub mod groups;
+pub mod health;
+pub mod modules;
+pub mod pages;
+pub mod quizzes;
+pub mod raw;
+pub mod reports;
+pub mod rubrics;
+pub mod sections;
+pub mod submissions;
+pub mod terms;
+pub mod todo;
+pub mod users;
+
+use crate::datetime::format_local;
+use crate::error::CanvasError;
+use crate::models::FlexibleId;
+use crate::server::CanvasServer;
+use chrono::{DateTime, Utc};
+use rmcp::model::{CallToolResult, Content, ResourceContents};
+use rmcp::Error as McpError;
+use serde::Serialize;
+
+/// Tools that change data in Canvas or on local disk, disabled by
+/// `CANVAS_READ_ONLY`
+///
+/// `raw_api_request` is included since it can send any method, and
+/// `download_file` since it can save files.
+pub const WRITE_TOOLS: &[&str] = &[
+    "grade_submission",
+    "grade_submission_with_rubric",
+    "bulk_grade",
+    "comment_on_submission",
+    "submit_assignment",
+    "create_assignment",
+    "update_assignment",
+    "create_calendar_event",
+    "send_message",
+    "update_syllabus",
+    "create_announcement",
+    "post_discussion_entry",
+    "mark_module_item_done",
+    "create_page",
+    "update_page",
+    "raw_api_request",
+    "download_file",
+];
+
+/// URI of the JSON resource attached to every tool result
+pub(crate) const RESULT_URI: &str = "canvas://result.json";
+
+/// Build a tool result: a human-readable summary for chat UIs, followed by
+/// the data as an embedded `application/json` resource for clients that parse it
+pub(crate) fn tool_result(
+    summary: String,
+    data: &impl Serialize,
+) -> Result<CallToolResult, McpError> {
+    let json = serde_json::to_string(data).map_err(CanvasError::Json)?;
+    Ok(CallToolResult::success(vec![
+        Content::text(summary),
+        Content::resource(ResourceContents::TextResourceContents {
+            uri: RESULT_URI.to_string(),
+            mime_type: Some("application/json".to_string()),
+            text: json,
+        }),
+    ]))
+}
+
+/// The JSON attached to a tool result by `tool_result`
+#[cfg(test)]
+pub(crate) fn result_json(result: &CallToolResult) -> serde_json::Value {
+    match &result.content[1]
+        .as_resource()
+        .expect("JSON resource")
+        .resource
+    {
+        ResourceContents::TextResourceContents { text, .. } => {
+            serde_json::from_str(text).expect("valid JSON")
+        }
+        other => panic!("expected a text resource, got {:?}", other),
+    }
+}
+
+impl CanvasServer {
+    /// Tool result for a write, flagged "[DRY RUN]" when `CANVAS_DRY_RUN`
+    /// means nothing was actually sent to Canvas
+    pub(crate) fn write_result(
+        &self,
+        summary: String,
+        data: &impl Serialize,
+    ) -> Result<CallToolResult, McpError> {
+        if self.config.dry_run {
+            tool_result(format!("[DRY RUN] {}", summary), data)
+        } else {
+            tool_result(summary, data)
+        }
+    }
+
+    /// Account searched by admin tools, from `CANVAS_ACCOUNT_ID`, encoded
+    /// for use in a path
+    pub(crate) fn admin_account(&self, tool: &str) -> Result<String, McpError> {
+        self.config
+            .account_id
+            .as_deref()
+            .map(crate::models::encode_path_segment)
+            .ok_or_else(|| {
+                McpError::from(CanvasError::config(format!(
+                    "{} needs CANVAS_ACCOUNT_ID, the admin account to use (often 1, the root account)",
+                    tool
+                )))
+            })
+    }
+}
+
+/// Due date for the middle of a summary line: "due Fri, Oct 3 at 11:59 PM"
+/// or "no due date"
+pub(crate) fn due_label(due_at: Option<DateTime<Utc>>, tz: &str) -> String {
+    match due_at {
+        Some(due) => format!("due {}", format_local(due, tz)),
+        None => "no due date".to_string(),
+    }
+}
+
+/// Resolve a tool's `format` parameter for rich content, defaulting to
+/// `default` and rejecting anything outside `render::FORMATS`
+pub(crate) fn content_format(format: Option<&str>, default: &str) -> Result<String, McpError> {
+    let format = format.unwrap_or(default);
+    check_choice("format", format, crate::render::FORMATS)?;
+    Ok(format.to_string())
+}
+
+/// Validate that an ID parameter is numeric or an SIS-style prefixed reference
+pub(crate) fn validate_id(field: &str, id: &FlexibleId) -> Result<(), McpError> {
+    if id.is_valid() {
+        Ok(())
+    } else {
+        Err(McpError::from(CanvasError::InvalidParameter(format!(
+            "{} must be a numeric Canvas ID or an SIS reference like sis_course_id:ABC123 (got \"{}\")",
+            field, id
+        ))))
+    }
+}
+
+/// Reject values outside a fixed set of Canvas options
+pub(crate) fn check_choice(field: &str, value: &str, allowed: &[&str]) -> Result<(), McpError> {
+    if allowed.contains(&value) {
+        Ok(())
+    } else {
+        Err(McpError::from(CanvasError::InvalidParameter(format!(
+            "{} must be one of {} (got {})",
+            field,
+            allowed.join(", "),
+            value
+        ))))
+    }
+}
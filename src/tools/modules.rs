@@ -0,0 +1,276 @@
+use super::{check_choice, tool_result, validate_id};
+use crate::client::QueryBuilder;
+use crate::error::CanvasError;
+use crate::models::{FlexibleId, Module, ModuleItem};
+use crate::server::CanvasServer;
+use reqwest::Method;
+use rmcp::model::CallToolResult;
+use rmcp::{schemars, tool, Error as McpError};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListModulesParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Fetch each module's items in the same call to get the whole tree
+    pub include_items: Option<bool>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListModuleItemsParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Canvas module ID
+    pub module_id: FlexibleId,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct MarkModuleItemParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Canvas module ID
+    pub module_id: FlexibleId,
+
+    /// Module item ID
+    pub item_id: FlexibleId,
+
+    /// "done" (default) for items that must be marked done, or "read" for
+    /// items that must be viewed
+    pub action: Option<String>,
+}
+
+impl CanvasServer {
+    #[tool(
+        description = "List a course's modules in order with their progress state, optionally including every module item and its completion requirement"
+    )]
+    pub(crate) async fn list_modules(
+        &self,
+        #[tool(aggr)] params: ListModulesParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+
+        let mut query = QueryBuilder::new();
+        if params.include_items.unwrap_or(false) {
+            query.include("items");
+        }
+        let path = query.to_path(&format!(
+            "/courses/{}/modules",
+            params.course_id.path_segment()
+        ));
+
+        let modules: Vec<Module> = self.reader(params.cache_bypass).get_all(&path).await?;
+
+        let mut summary = format!("Found {} module(s):", modules.len());
+        for module in &modules {
+            summary.push_str(&format!("\n{} (ID {})", module.name, module.id));
+            if let Some(state) = &module.state {
+                summary.push_str(&format!(" [{}]", state));
+            }
+            for item in module.items.iter().flatten() {
+                summary.push_str(&format!("\n  {}", describe_item(item)));
+            }
+        }
+
+        tool_result(summary, &modules)
+    }
+
+    #[tool(
+        description = "List the items in one course module (pages, assignments, quizzes, links) with what each requires for completion"
+    )]
+    pub(crate) async fn list_module_items(
+        &self,
+        #[tool(aggr)] params: ListModuleItemsParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        validate_id("module_id", &params.module_id)?;
+
+        let path = format!(
+            "/courses/{}/modules/{}/items",
+            params.course_id.path_segment(),
+            params.module_id.path_segment()
+        );
+        let items: Vec<ModuleItem> = self.reader(params.cache_bypass).get_all(&path).await?;
+
+        let mut summary = format!("Module {} has {} item(s):", params.module_id, items.len());
+        for item in &items {
+            summary.push_str(&format!("\n{}", describe_item(item)));
+        }
+
+        tool_result(summary, &items)
+    }
+
+    #[tool(
+        description = "Mark a module item as done (or as read) for the current user, for items whose completion requirement is to mark them done or view them"
+    )]
+    pub(crate) async fn mark_module_item_done(
+        &self,
+        #[tool(aggr)] params: MarkModuleItemParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        validate_id("module_id", &params.module_id)?;
+        validate_id("item_id", &params.item_id)?;
+        let action = params.action.as_deref().unwrap_or("done");
+        check_choice("action", action, &["done", "read"])?;
+
+        let item_path = format!(
+            "/courses/{}/modules/{}/items/{}",
+            params.course_id.path_segment(),
+            params.module_id.path_segment(),
+            params.item_id.path_segment()
+        );
+        let item: ModuleItem = self.client.get(&item_path).await?;
+
+        let (required, method, endpoint) = match action {
+            "done" => ("must_mark_done", Method::PUT, "done"),
+            _ => ("must_view", Method::POST, "mark_read"),
+        };
+        let requirement = item
+            .completion_requirement
+            .as_ref()
+            .map(|r| r.requirement_type.as_str());
+        if requirement != Some(required) {
+            return Err(McpError::from(CanvasError::InvalidParameter(format!(
+                "\"{}\" can't be marked {}: its completion requirement is {}",
+                item.title,
+                action,
+                requirement.unwrap_or("none")
+            ))));
+        }
+
+        self.client
+            .request(method, &format!("{}/{}", item_path, endpoint))
+            .await?;
+        let updated: ModuleItem = self.client.get(&item_path).await?;
+
+        let completed = updated
+            .completion_requirement
+            .as_ref()
+            .and_then(|r| r.completed)
+            .unwrap_or(false);
+        let summary = format!(
+            "Marked \"{}\" as {}; requirement {}",
+            updated.title,
+            action,
+            if completed {
+                "completed"
+            } else {
+                "not yet completed"
+            }
+        );
+
+        self.write_result(summary, &updated)
+    }
+}
+
+/// One-line description of a module item, e.g.
+/// `- [x] Read: Chapter 1 (Page, ID 5) — must_view`
+fn describe_item(item: &ModuleItem) -> String {
+    let checkbox = match item
+        .completion_requirement
+        .as_ref()
+        .and_then(|r| r.completed)
+    {
+        Some(true) => "[x] ",
+        Some(false) => "[ ] ",
+        None => "",
+    };
+    let mut line = format!(
+        "- {}{} ({}, ID {})",
+        checkbox, item.title, item.item_type, item.id
+    );
+    if let Some(requirement) = &item.completion_requirement {
+        line.push_str(&format!(" — {}", requirement.requirement_type));
+        if let Some(score) = requirement.min_score {
+            line.push_str(&format!(" {}", score));
+        }
+    }
+    line
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_list_modules_with_items() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock(
+                "GET",
+                "/api/v1/courses/1/modules?include[]=items&per_page=100",
+            )
+            .with_body(
+                r#"[{"id": 3, "name": "Week 3", "position": 3, "state": "started",
+                     "items": [
+                        {"id": 30, "title": "Chapter 3", "type": "Page", "page_url": "chapter-3",
+                         "completion_requirement": {"type": "must_view", "completed": true}},
+                        {"id": 31, "title": "Quiz 3", "type": "Quiz", "content_id": 7,
+                         "completion_requirement": {"type": "min_score", "min_score": 8.0, "completed": false}}
+                     ]}]"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .list_modules(ListModulesParams {
+                course_id: FlexibleId::from(1),
+                include_items: Some(true),
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains("Week 3 (ID 3) [started]"));
+        assert!(summary.contains("- [x] Chapter 3 (Page, ID 30) — must_view"));
+        assert!(summary.contains("- [ ] Quiz 3 (Quiz, ID 31) — min_score 8"));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_mark_module_item_done_checks_requirement() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/courses/1/modules/3/items/30")
+            .with_body(
+                r#"{"id": 30, "title": "Chapter 3", "type": "Page",
+                    "completion_requirement": {"type": "must_view", "completed": false}}"#,
+            )
+            .create_async()
+            .await;
+        let done = server
+            .mock("PUT", "/api/v1/courses/1/modules/3/items/30/done")
+            .expect(0)
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let error = canvas
+            .mark_module_item_done(MarkModuleItemParams {
+                course_id: FlexibleId::from(1),
+                module_id: FlexibleId::from(3),
+                item_id: FlexibleId::from(30),
+                action: None,
+            })
+            .await
+            .unwrap_err();
+        assert!(error.message.contains("requirement is must_view"));
+        done.assert_async().await;
+    }
+}
@@ -0,0 +1,384 @@
+use super::{check_choice, content_format, tool_result, validate_id};
+use crate::client::QueryBuilder;
+use crate::error::CanvasError;
+use crate::models::{encode_path_segment, FlexibleId, Page};
+use crate::render::render;
+use crate::server::CanvasServer;
+use rmcp::model::CallToolResult;
+use rmcp::{schemars, tool, Error as McpError};
+use serde::{Deserialize, Serialize};
+
+/// Values accepted in `editing_roles`
+const EDITING_ROLES: &[&str] = &["teachers", "students", "members", "public"];
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListPagesParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Only pages whose title contains this text
+    pub search_term: Option<String>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetPageParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Page URL slug as returned by list_pages (e.g. "week-1-overview"), or
+    /// "front_page" for the course front page
+    pub url: String,
+
+    /// How to return the page body: "markdown" (default), "text", or "html"
+    pub format: Option<String>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct CreatePageParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Page title
+    pub title: String,
+
+    /// Page body (HTML)
+    pub body: Option<String>,
+
+    /// Publish the page so students can see it (default false)
+    pub published: Option<bool>,
+
+    /// Who can edit the page: any of "teachers", "students", "members", "public"
+    pub editing_roles: Option<Vec<String>>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct UpdatePageParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// URL slug of the page to update
+    pub url: String,
+
+    /// New title (changing it also changes the slug)
+    pub title: Option<String>,
+
+    /// New body (HTML), replacing the current one
+    pub body: Option<String>,
+
+    /// Publish or unpublish the page
+    pub published: Option<bool>,
+
+    /// Who can edit the page: any of "teachers", "students", "members", "public"
+    pub editing_roles: Option<Vec<String>>,
+}
+
+/// Title and published state of a page before and after an update
+#[derive(Debug, Serialize)]
+struct PageUpdate {
+    before: PageState,
+    after: PageState,
+    page: Page,
+}
+
+#[derive(Debug, Serialize, PartialEq)]
+struct PageState {
+    title: String,
+    url: String,
+    published: Option<bool>,
+}
+
+impl From<&Page> for PageState {
+    fn from(page: &Page) -> Self {
+        Self {
+            title: page.title.clone(),
+            url: page.url.clone(),
+            published: page.published,
+        }
+    }
+}
+
+/// `wiki_page[...]` form fields shared by create and update
+fn page_form(
+    title: Option<&str>,
+    body: Option<&str>,
+    published: Option<bool>,
+    editing_roles: Option<&[String]>,
+) -> Result<Vec<(&'static str, String)>, McpError> {
+    let mut form = Vec::new();
+    if let Some(title) = title {
+        form.push(("wiki_page[title]", title.to_string()));
+    }
+    if let Some(body) = body {
+        form.push(("wiki_page[body]", body.to_string()));
+    }
+    if let Some(published) = published {
+        form.push(("wiki_page[published]", published.to_string()));
+    }
+    if let Some(roles) = editing_roles {
+        for role in roles {
+            check_choice("editing_roles", role, EDITING_ROLES)?;
+        }
+        form.push(("wiki_page[editing_roles]", roles.join(",")));
+    }
+    Ok(form)
+}
+
+/// Path of a page by slug, percent-encoded
+fn page_path(course_id: &FlexibleId, slug: &str) -> String {
+    format!(
+        "/courses/{}/pages/{}",
+        course_id.path_segment(),
+        encode_path_segment(slug)
+    )
+}
+
+impl CanvasServer {
+    #[tool(description = "List a course's wiki pages with their URL slugs")]
+    pub(crate) async fn list_pages(
+        &self,
+        #[tool(aggr)] params: ListPagesParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+
+        let mut query = QueryBuilder::new();
+        query.param("sort", "title");
+        if let Some(term) = params
+            .search_term
+            .as_deref()
+            .filter(|t| !t.trim().is_empty())
+        {
+            query.param("search_term", term.trim());
+        }
+        let path = query.to_path(&format!(
+            "/courses/{}/pages",
+            params.course_id.path_segment()
+        ));
+
+        let pages: Vec<Page> = self.reader(params.cache_bypass).get_all(&path).await?;
+
+        let mut summary = format!("Found {} page(s):", pages.len());
+        for page in &pages {
+            summary.push_str(&format!("\n- {} (url: {})", page.title, page.url));
+            if page.front_page {
+                summary.push_str(" [front page]");
+            }
+            if page.published == Some(false) {
+                summary.push_str(" [unpublished]");
+            }
+        }
+
+        tool_result(summary, &pages)
+    }
+
+    #[tool(
+        description = "Get a course wiki page's title and body, as Markdown by default, or as plain text or the original HTML"
+    )]
+    pub(crate) async fn get_page(
+        &self,
+        #[tool(aggr)] params: GetPageParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        let format = content_format(params.format.as_deref(), "markdown")?;
+        let slug = params.url.trim();
+        if slug.is_empty() {
+            return Err(McpError::from(CanvasError::InvalidParameter(
+                "url must be a page slug such as week-1-overview".to_string(),
+            )));
+        }
+
+        let path = if slug == "front_page" {
+            format!("/courses/{}/front_page", params.course_id.path_segment())
+        } else {
+            page_path(&params.course_id, slug)
+        };
+        let mut page: Page = self.reader(params.cache_bypass).get(&path).await?;
+
+        page.body = page.body.as_deref().map(|body| render(body, &format));
+
+        let summary = format!(
+            "# {}\n\n{}",
+            page.title,
+            page.body.as_deref().unwrap_or("_This page is empty._")
+        );
+
+        tool_result(summary, &page)
+    }
+
+    #[tool(description = "Create a wiki page in a course")]
+    pub(crate) async fn create_page(
+        &self,
+        #[tool(aggr)] params: CreatePageParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        if params.title.trim().is_empty() {
+            return Err(McpError::from(CanvasError::InvalidParameter(
+                "title must not be empty".to_string(),
+            )));
+        }
+
+        let form = page_form(
+            Some(params.title.trim()),
+            params.body.as_deref(),
+            params.published,
+            params.editing_roles.as_deref(),
+        )?;
+        let path = format!("/courses/{}/pages", params.course_id.path_segment());
+        let page: Page = self.client.post_form(&path, &form).await?;
+
+        let summary = format!(
+            "Created {} page \"{}\" (url: {})",
+            if page.published == Some(true) {
+                "published"
+            } else {
+                "unpublished"
+            },
+            page.title,
+            page.url
+        );
+
+        self.write_result(summary, &page)
+    }
+
+    #[tool(
+        description = "Update a course wiki page's title, body, published state, or editing roles, returning what changed"
+    )]
+    pub(crate) async fn update_page(
+        &self,
+        #[tool(aggr)] params: UpdatePageParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        let form = page_form(
+            params.title.as_deref().map(str::trim),
+            params.body.as_deref(),
+            params.published,
+            params.editing_roles.as_deref(),
+        )?;
+        if form.is_empty() {
+            return Err(McpError::from(CanvasError::InvalidParameter(
+                "Nothing to update: set at least one of title, body, published, editing_roles"
+                    .to_string(),
+            )));
+        }
+
+        let path = page_path(&params.course_id, params.url.trim());
+        let existing: Page = self.client.get(&path).await?;
+        let page: Page = self.client.put_form(&path, &form).await?;
+
+        let update = PageUpdate {
+            before: PageState::from(&existing),
+            after: PageState::from(&page),
+            page,
+        };
+        let mut changes = Vec::new();
+        if update.before.title != update.after.title {
+            changes.push(format!(
+                "title \"{}\" -> \"{}\"",
+                update.before.title, update.after.title
+            ));
+        }
+        if update.before.url != update.after.url {
+            changes.push(format!("url {} -> {}", update.before.url, update.after.url));
+        }
+        if update.before.published != update.after.published {
+            changes.push(format!(
+                "published {} -> {}",
+                update.before.published.unwrap_or(false),
+                update.after.published.unwrap_or(false)
+            ));
+        }
+        if params.body.is_some() {
+            changes.push("body replaced".to_string());
+        }
+        let summary = if changes.is_empty() {
+            format!("Updated page {} (no visible changes)", update.after.url)
+        } else {
+            format!("Updated page {}: {}", update.after.url, changes.join(", "))
+        };
+
+        self.write_result(summary, &update)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_get_page_encodes_slug_and_renders_markdown() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/api/v1/courses/1/pages/caf%C3%A9%20notes")
+            .with_body(
+                r#"{"url": "café notes", "title": "Café Notes",
+                    "body": "<p>See <strong>section 2</strong></p>"}"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .get_page(GetPageParams {
+                course_id: FlexibleId::from(1),
+                url: "café notes".to_string(),
+                format: None,
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert_eq!(summary, "# Café Notes\n\nSee **section 2**");
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_update_page_reports_changes() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/courses/1/pages/draft")
+            .with_body(r#"{"url": "draft", "title": "Draft", "published": false}"#)
+            .create_async()
+            .await;
+        let put = server
+            .mock("PUT", "/api/v1/courses/1/pages/draft")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("wiki_page[title]".into(), "Week 1".into()),
+                mockito::Matcher::UrlEncoded("wiki_page[published]".into(), "true".into()),
+            ]))
+            .with_body(r#"{"url": "week-1", "title": "Week 1", "published": true}"#)
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .update_page(UpdatePageParams {
+                course_id: FlexibleId::from(1),
+                url: "draft".to_string(),
+                title: Some("Week 1".to_string()),
+                body: None,
+                published: Some(true),
+                editing_roles: None,
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert_eq!(
+            summary,
+            "Updated page week-1: title \"Draft\" -> \"Week 1\", url draft -> week-1, published false -> true"
+        );
+        put.assert_async().await;
+    }
+}
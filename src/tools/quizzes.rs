@@ -0,0 +1,265 @@
+use super::{content_format, due_label, tool_result, validate_id};
+use crate::datetime::format_local;
+use crate::error::CanvasError;
+use crate::models::{FlexibleId, Quiz, QuizQuestion};
+use crate::render::render;
+use crate::server::CanvasServer;
+use chrono::{DateTime, Utc};
+use rmcp::model::CallToolResult;
+use rmcp::{schemars, tool, Error as McpError};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListQuizzesParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetQuizParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Canvas quiz ID
+    pub quiz_id: FlexibleId,
+
+    /// How to return the quiz description (or question text): "text"
+    /// (default), "markdown", or "html"
+    pub format: Option<String>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+/// Quiz with its dates rendered in the configured timezone
+#[derive(Debug, Serialize)]
+struct QuizRow {
+    #[serde(flatten)]
+    quiz: Quiz,
+    due_local: Option<String>,
+    unlock_local: Option<String>,
+    lock_local: Option<String>,
+}
+
+impl QuizRow {
+    fn new(quiz: Quiz, tz: &str) -> Self {
+        let local = |at: Option<DateTime<Utc>>| at.map(|at| format_local(at, tz));
+        Self {
+            due_local: local(quiz.due_at),
+            unlock_local: local(quiz.unlock_at),
+            lock_local: local(quiz.lock_at),
+            quiz,
+        }
+    }
+
+    /// One-line description: title, ID, points, time limit, attempts, and due date
+    fn describe(&self, tz: &str) -> String {
+        let quiz = &self.quiz;
+        let mut line = format!("{} (ID {})", quiz.title, quiz.id);
+        if let Some(points) = quiz.points_possible {
+            line.push_str(&format!(", {} pts", points));
+        }
+        if let Some(minutes) = quiz.time_limit {
+            line.push_str(&format!(", {} min", minutes));
+        }
+        match quiz.allowed_attempts {
+            Some(-1) => line.push_str(", unlimited attempts"),
+            Some(attempts) => line.push_str(&format!(", {} attempt(s)", attempts)),
+            None => {}
+        }
+        line.push_str(&format!(" — {}", due_label(quiz.due_at, tz)));
+        line
+    }
+}
+
+impl CanvasServer {
+    #[tool(
+        description = "List a course's quizzes with points, time limit, allowed attempts, and due dates"
+    )]
+    pub(crate) async fn list_quizzes(
+        &self,
+        #[tool(aggr)] params: ListQuizzesParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+
+        let path = format!("/courses/{}/quizzes", params.course_id.path_segment());
+        let quizzes: Vec<Quiz> = self.reader(params.cache_bypass).get_all(&path).await?;
+
+        let tz = self.timezone();
+        let rows: Vec<QuizRow> = quizzes.into_iter().map(|q| QuizRow::new(q, tz)).collect();
+
+        let mut summary = format!("Found {} quiz(zes):", rows.len());
+        for row in &rows {
+            summary.push_str(&format!("\n- {}", row.describe(tz)));
+        }
+
+        tool_result(summary, &rows)
+    }
+
+    #[tool(
+        description = "Get a quiz's settings: points, time limit, allowed attempts, availability dates, and instructions"
+    )]
+    pub(crate) async fn get_quiz(
+        &self,
+        #[tool(aggr)] params: GetQuizParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        validate_id("quiz_id", &params.quiz_id)?;
+        let format = content_format(params.format.as_deref(), "text")?;
+
+        let path = format!(
+            "/courses/{}/quizzes/{}",
+            params.course_id.path_segment(),
+            params.quiz_id.path_segment()
+        );
+        let mut quiz: Quiz = self.reader(params.cache_bypass).get(&path).await?;
+        quiz.description = quiz.description.as_deref().map(|d| render(d, &format));
+
+        let tz = self.timezone();
+        let row = QuizRow::new(quiz, tz);
+        let mut summary = row.describe(tz);
+        if let Some(count) = row.quiz.question_count {
+            summary.push_str(&format!("\nQuestions: {}", count));
+        }
+        if let Some(unlock) = &row.unlock_local {
+            summary.push_str(&format!("\nAvailable from: {}", unlock));
+        }
+        if let Some(lock) = &row.lock_local {
+            summary.push_str(&format!("\nAvailable until: {}", lock));
+        }
+        if let Some(description) = row
+            .quiz
+            .description
+            .as_deref()
+            .filter(|d| !d.trim().is_empty())
+        {
+            summary.push_str(&format!("\n\n{}", description.trim()));
+        }
+
+        tool_result(summary, &row)
+    }
+
+    #[tool(
+        description = "List a quiz's questions with their type, points, and question text (requires instructor access)"
+    )]
+    pub(crate) async fn list_quiz_questions(
+        &self,
+        #[tool(aggr)] params: GetQuizParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        validate_id("quiz_id", &params.quiz_id)?;
+        let format = content_format(params.format.as_deref(), "text")?;
+
+        let path = format!(
+            "/courses/{}/quizzes/{}/questions",
+            params.course_id.path_segment(),
+            params.quiz_id.path_segment()
+        );
+        let mut questions: Vec<QuizQuestion> =
+            self.reader(params.cache_bypass)
+                .get_all(&path)
+                .await
+                .map_err(|e| match e {
+                CanvasError::Auth(message) => CanvasError::auth(format!(
+                    "{}. Listing quiz questions requires permission to edit the quiz (teacher, TA, or designer)",
+                    message
+                )),
+                other => other,
+            })?;
+        for question in &mut questions {
+            question.question_text = question
+                .question_text
+                .as_deref()
+                .map(|t| render(t, &format));
+        }
+
+        let mut summary = format!(
+            "Quiz {} has {} question(s):",
+            params.quiz_id,
+            questions.len()
+        );
+        for (index, question) in questions.iter().enumerate() {
+            summary.push_str(&format!(
+                "\n{}. [{}, {} pts] {}",
+                question.position.map(|p| p as usize).unwrap_or(index + 1),
+                question.question_type.as_deref().unwrap_or("unknown type"),
+                question.points_possible.unwrap_or(0.0),
+                question
+                    .question_text
+                    .as_deref()
+                    .unwrap_or_default()
+                    .trim()
+                    .replace('\n', " "),
+            ));
+        }
+
+        tool_result(summary, &questions)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_list_quizzes_converts_due_dates() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/courses/1/quizzes?per_page=100")
+            .with_body(
+                r#"[{"id": 4, "title": "Quiz 1", "points_possible": 10, "time_limit": 30,
+                     "allowed_attempts": -1, "due_at": "2025-10-04T06:59:00Z"}]"#,
+            )
+            .create_async()
+            .await;
+
+        let mut config = CanvasConfig::new("token".to_string(), server.url());
+        config.timezone = Some("America/Los_Angeles".to_string());
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+
+        let result = canvas
+            .list_quizzes(ListQuizzesParams {
+                course_id: FlexibleId::from(1),
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains(
+            "Quiz 1 (ID 4), 10 pts, 30 min, unlimited attempts — due Fri, Oct 3 at 11:59 PM"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_list_quiz_questions_explains_forbidden() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/courses/1/quizzes/4/questions?per_page=100")
+            .with_status(403)
+            .with_body(r#"{"status": "unauthorized", "errors": [{"message": "user not authorized to perform that action"}]}"#)
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let error = canvas
+            .list_quiz_questions(GetQuizParams {
+                course_id: FlexibleId::from(1),
+                quiz_id: FlexibleId::from(4),
+                format: None,
+                cache_bypass: None,
+            })
+            .await
+            .unwrap_err();
+        assert!(error
+            .message
+            .contains("requires permission to edit the quiz"));
+    }
+}
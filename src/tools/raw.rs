@@ -0,0 +1,173 @@
+use super::{check_choice, tool_result};
+use crate::error::CanvasError;
+use crate::server::CanvasServer;
+use rmcp::model::CallToolResult;
+use rmcp::{schemars, tool, Error as McpError};
+use serde::Deserialize;
+use serde_json::Value;
+
+const METHODS: &[&str] = &["GET", "POST", "PUT", "DELETE"];
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct RawApiRequestParams {
+    /// HTTP method: "GET", "POST", "PUT", or "DELETE"
+    pub method: String,
+
+    /// Endpoint path relative to /api/v1, with any query string, e.g.
+    /// "/courses/123/rubrics?include[]=assessments"
+    pub path: String,
+
+    /// JSON request body for POST and PUT
+    pub body: Option<Value>,
+}
+
+/// Normalize a caller-supplied endpoint path, rejecting anything that could
+/// send the API token to another host, leave `/api/v1`, or override the
+/// configured masquerade
+///
+/// Checks run on the percent-decoded path, since the URL parser resolves an
+/// encoded `%2e%2e` just like a literal `..`.
+fn endpoint_path(path: &str) -> Result<String, McpError> {
+    let path = path.trim();
+    let decoded = percent_encoding::percent_decode_str(path).decode_utf8_lossy();
+    if decoded.is_empty() || decoded.contains("://") || decoded.starts_with("//") {
+        return Err(McpError::from(CanvasError::InvalidParameter(format!(
+            "path must be relative to the Canvas API, e.g. /courses/123 (got {})",
+            path
+        ))));
+    }
+    if decoded
+        .split(['/', '\\', '?', '&'])
+        .any(|segment| segment == ".." || segment == ".")
+    {
+        return Err(McpError::from(CanvasError::InvalidParameter(
+            "path must not contain \".\" or \"..\" segments".to_string(),
+        )));
+    }
+    if decoded.to_ascii_lowercase().contains("as_user_id") {
+        return Err(McpError::from(CanvasError::InvalidParameter(
+            "path must not set as_user_id; masquerading is configured with CANVAS_MASQUERADE_AS"
+                .to_string(),
+        )));
+    }
+    let path = path.trim_start_matches('/');
+    let path = path.strip_prefix("api/v1/").unwrap_or(path);
+    Ok(format!("/{}", path))
+}
+
+impl CanvasServer {
+    #[tool(
+        description = "Call any Canvas REST endpoint directly (only when CANVAS_ENABLE_RAW_API is set). Prefer the dedicated tools; use this for endpoints they don't cover."
+    )]
+    pub(crate) async fn raw_api_request(
+        &self,
+        #[tool(aggr)] params: RawApiRequestParams,
+    ) -> Result<CallToolResult, McpError> {
+        if !self.config.enable_raw_api {
+            return Err(McpError::from(CanvasError::config(
+                "raw_api_request is disabled; set CANVAS_ENABLE_RAW_API=true to allow it",
+            )));
+        }
+        let method = params.method.trim().to_ascii_uppercase();
+        check_choice("method", &method, METHODS)?;
+        let path = endpoint_path(&params.path)?;
+        let body = params.body.unwrap_or(Value::Object(Default::default()));
+
+        // Every response goes through the client's anonymizer like any other tool
+        let response: Value = match method.as_str() {
+            "GET" => self.client.get(&path).await?,
+            "POST" => self.client.post(&path, &body).await?,
+            "PUT" => self.client.put(&path, &body).await?,
+            _ => self.client.delete(&path).await?,
+        };
+
+        let shape = match &response {
+            Value::Array(items) => format!("{} item(s)", items.len()),
+            Value::Object(_) => "an object".to_string(),
+            other => other.to_string(),
+        };
+        let summary = format!("{} {} returned {}", method, path, shape);
+        if method == "GET" {
+            tool_result(summary, &response)
+        } else {
+            self.write_result(summary, &response)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::anonymize::pseudonym;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_raw_api_request_anonymizes_get() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/courses/1/users?enrollment_type[]=student")
+            .with_body(r#"[{"id": 42, "name": "Jane Doe", "sortable_name": "Doe, Jane"}]"#)
+            .create_async()
+            .await;
+
+        let mut config = CanvasConfig::new("token".to_string(), server.url());
+        config.enable_raw_api = true;
+        config.enable_anonymization = true;
+        config.anonymization_salt = "salt".to_string();
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+
+        let result = canvas
+            .raw_api_request(RawApiRequestParams {
+                method: "get".to_string(),
+                path: "/api/v1/courses/1/users?enrollment_type[]=student".to_string(),
+                body: None,
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert_eq!(
+            summary,
+            "GET /courses/1/users?enrollment_type[]=student returned 1 item(s)"
+        );
+        let data = crate::tools::result_json(&result).to_string();
+        assert!(data.contains(&pseudonym("42", "salt")));
+        assert!(!data.contains("Jane"));
+    }
+
+    #[tokio::test]
+    async fn test_raw_api_request_requires_opt_in_and_relative_path() {
+        let mut config = CanvasConfig::new(
+            "token".to_string(),
+            "https://example.instructure.com".to_string(),
+        );
+        let request = || RawApiRequestParams {
+            method: "GET".to_string(),
+            path: "https://evil.example.com/steal".to_string(),
+            body: None,
+        };
+
+        let canvas = CanvasServer::new(Arc::new(config.clone())).unwrap();
+        let error = canvas.raw_api_request(request()).await.unwrap_err();
+        assert!(error.message.contains("CANVAS_ENABLE_RAW_API"));
+
+        config.enable_raw_api = true;
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+        let error = canvas.raw_api_request(request()).await.unwrap_err();
+        assert!(error.message.contains("relative"));
+
+        for path in [
+            "/courses/%2e%2e/%2E%2E/login",
+            "/courses/1/..%2f..%2fusers",
+            "/users/self?as_user_id=5",
+            "/users/self?as%5Fuser%5Fid=5",
+        ] {
+            assert!(endpoint_path(path).is_err(), "{} was accepted", path);
+        }
+        assert_eq!(
+            endpoint_path("api/v1/courses/sis_course_id:BIO%20101").unwrap(),
+            "/courses/sis_course_id:BIO%20101"
+        );
+    }
+}
@@ -0,0 +1,805 @@
+use super::{check_choice, due_label, tool_result, validate_id};
+use crate::client::QueryBuilder;
+use crate::datetime::{format_duration, format_local};
+use crate::models::{Assignment, FlexibleId, Submission, User};
+use crate::server::CanvasServer;
+use chrono::{DateTime, Utc};
+use rmcp::model::CallToolResult;
+use rmcp::{schemars, tool, Error as McpError};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Ways to group the missing submissions report
+const GROUP_BY: &[&str] = &["student", "assignment"];
+
+/// Submission types that are never turned in through Canvas, so a lack of
+/// submission doesn't mean the work is missing
+const OFFLINE_SUBMISSION_TYPES: &[&str] = &["none", "on_paper", "not_graded"];
+
+/// Workflow states that count as turned in
+const SUBMITTED_STATES: &[&str] = &["submitted", "graded", "pending_review"];
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct MissingSubmissionsParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Group the report by "student" (default) or "assignment"
+    pub group_by: Option<String>,
+
+    /// Show students' real Canvas IDs even when anonymization is enabled,
+    /// for advising conversations (default false)
+    pub reveal_identity: Option<bool>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct LateSubmissionsParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Only report this assignment (default: every assignment in the course)
+    pub assignment_id: Option<FlexibleId>,
+
+    /// Show students' real Canvas IDs even when anonymization is enabled,
+    /// for advising conversations (default false)
+    pub reveal_identity: Option<bool>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GradeDistributionParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Assignment ID
+    pub assignment_id: FlexibleId,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+/// Number of histogram bins in `grade_distribution`
+const HISTOGRAM_BINS: usize = 10;
+
+/// Longest histogram bar, in characters
+const HISTOGRAM_WIDTH: usize = 40;
+
+/// A roster student as labeled in reports
+struct Student {
+    id: FlexibleId,
+    /// Canvas user ID with name, or a pseudonym when anonymized
+    label: String,
+    /// Sections the student is enrolled in, for section due date overrides
+    section_ids: Vec<FlexibleId>,
+}
+
+/// A past-due assignment a student hasn't turned in
+#[derive(Debug, Clone, Serialize)]
+struct MissingItem {
+    assignment_id: FlexibleId,
+    assignment: String,
+    due_at: Option<DateTime<Utc>>,
+    /// Canvas user ID with name, or a pseudonym when anonymized
+    student: String,
+}
+
+/// One group of the report: a student's missing assignments, or an
+/// assignment's missing students
+#[derive(Debug, Serialize)]
+struct MissingGroup {
+    label: String,
+    missing: Vec<MissingItem>,
+}
+
+/// A submission Canvas marked late
+#[derive(Debug, Serialize)]
+struct LateItem {
+    assignment_id: FlexibleId,
+    assignment: String,
+    /// Canvas user ID with name, or a pseudonym when anonymized
+    student: String,
+    submitted_at: Option<DateTime<Utc>>,
+    /// The student's own due date, including any override
+    due_at: Option<DateTime<Utc>>,
+    /// `submitted_at - due_at`, when both are known
+    late_by_secs: Option<i64>,
+    /// Points taken off by the late policy, if any
+    points_deducted: Option<f64>,
+}
+
+/// Collect roster students' late submissions, latest first
+///
+/// Lateness is measured against the submission's `cached_due_date`, which
+/// reflects overrides for the student, falling back to the assignment's
+/// overrides and `due_at`.
+fn find_late(
+    assignments: &[Assignment],
+    students: &[Student],
+    submissions: &[Submission],
+) -> Vec<LateItem> {
+    let assignments: HashMap<&FlexibleId, &Assignment> =
+        assignments.iter().map(|a| (&a.id, a)).collect();
+    let students: HashMap<&FlexibleId, &Student> = students
+        .iter()
+        .map(|student| (&student.id, student))
+        .collect();
+
+    let mut late: Vec<LateItem> = submissions
+        .iter()
+        .filter(|s| s.late)
+        .filter_map(|submission| {
+            let student = students.get(submission.user_id.as_ref()?)?;
+            let assignment_id = submission.assignment_id.clone()?;
+            let assignment = assignments.get(&assignment_id);
+            let due_at = submission
+                .cached_due_date
+                .or_else(|| assignment?.due_at_for(&student.id, &student.section_ids));
+            let late_by_secs = submission
+                .submitted_at
+                .zip(due_at)
+                .map(|(submitted, due)| (submitted - due).num_seconds());
+            Some(LateItem {
+                assignment: assignment
+                    .map(|a| a.name.clone())
+                    .unwrap_or_else(|| format!("assignment {}", assignment_id)),
+                assignment_id,
+                student: student.label.clone(),
+                submitted_at: submission.submitted_at,
+                due_at,
+                late_by_secs,
+                points_deducted: submission.points_deducted.filter(|p| *p > 0.0),
+            })
+        })
+        .collect();
+    late.sort_by_key(|item| std::cmp::Reverse(item.late_by_secs));
+    late
+}
+
+/// Summary statistics over graded scores
+#[derive(Debug, Serialize, PartialEq)]
+struct ScoreStats {
+    mean: f64,
+    median: f64,
+    /// Population standard deviation
+    stddev: f64,
+    min: f64,
+    q1: f64,
+    q3: f64,
+    max: f64,
+}
+
+impl ScoreStats {
+    /// Compute statistics for `scores`, or `None` if there are none
+    fn new(scores: &[f64]) -> Option<Self> {
+        let mut sorted = scores.to_vec();
+        sorted.sort_by(f64::total_cmp);
+        let n = sorted.len() as f64;
+        let mean = sorted.iter().sum::<f64>() / n;
+        let variance = sorted.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / n;
+        Some(Self {
+            mean,
+            median: percentile(&sorted, 0.5)?,
+            stddev: variance.sqrt(),
+            min: *sorted.first()?,
+            q1: percentile(&sorted, 0.25)?,
+            q3: percentile(&sorted, 0.75)?,
+            max: *sorted.last()?,
+        })
+    }
+}
+
+/// Percentile of sorted values, interpolating between neighbours
+fn percentile(sorted: &[f64], p: f64) -> Option<f64> {
+    let last = sorted.len().checked_sub(1)?;
+    let rank = p * last as f64;
+    let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+    Some(sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64))
+}
+
+/// One histogram bin, covering `low..high` (the last bin includes `high`)
+#[derive(Debug, Serialize)]
+struct HistogramBin {
+    low: f64,
+    high: f64,
+    count: usize,
+}
+
+/// Bin scores into equal ranges from 0 to points possible, or across the
+/// observed range when the assignment has no points
+///
+/// Scores outside the range (extra credit, penalties) go in the end bins.
+fn histogram(scores: &[f64], points_possible: Option<f64>) -> Vec<HistogramBin> {
+    let (low, high) = match points_possible.filter(|p| *p > 0.0) {
+        Some(points) => (0.0, points),
+        None => (
+            scores.iter().copied().fold(f64::INFINITY, f64::min),
+            scores.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+        ),
+    };
+    if scores.is_empty() {
+        return Vec::new();
+    }
+    let width = if high > low {
+        (high - low) / HISTOGRAM_BINS as f64
+    } else {
+        1.0
+    };
+
+    let mut bins: Vec<HistogramBin> = (0..HISTOGRAM_BINS)
+        .map(|i| HistogramBin {
+            low: low + width * i as f64,
+            high: low + width * (i + 1) as f64,
+            count: 0,
+        })
+        .collect();
+    for score in scores {
+        let index = ((score - low) / width).floor().max(0.0) as usize;
+        bins[index.min(HISTOGRAM_BINS - 1)].count += 1;
+    }
+    bins
+}
+
+/// Score distribution for one assignment
+#[derive(Debug, Serialize)]
+struct GradeDistribution {
+    assignment_id: FlexibleId,
+    assignment: String,
+    points_possible: Option<f64>,
+    graded: usize,
+    ungraded: usize,
+    excused: usize,
+    stats: Option<ScoreStats>,
+    histogram: Vec<HistogramBin>,
+}
+
+/// A number with at most two decimals and no trailing zeros
+fn trim_number(value: f64) -> String {
+    let text = format!("{:.2}", value);
+    text.trim_end_matches('0').trim_end_matches('.').to_string()
+}
+
+/// Pair every student with every past-due assignment they haven't submitted
+///
+/// Only assignments a student has a submission row for count: Canvas omits
+/// rows for students a differentiated assignment isn't assigned to. Each
+/// student's due date comes from their submission's `cached_due_date`, or
+/// else the assignment's overrides for the student and their sections.
+/// Unpublished assignments, those without a due date, and offline ones
+/// (`on_paper`, `none`) are skipped, as are excused submissions. Results
+/// follow roster order, then assignment order.
+fn find_missing(
+    assignments: &[Assignment],
+    students: &[Student],
+    submissions: &[Submission],
+    now: DateTime<Utc>,
+) -> Vec<MissingItem> {
+    let expected: Vec<&Assignment> = assignments
+        .iter()
+        .filter(|a| a.published)
+        .filter(|a| {
+            !a.submission_types
+                .iter()
+                .any(|t| OFFLINE_SUBMISSION_TYPES.contains(&t.as_str()))
+        })
+        .collect();
+    let submissions: HashMap<(&FlexibleId, &FlexibleId), &Submission> = submissions
+        .iter()
+        .filter_map(|s| Some(((s.user_id.as_ref()?, s.assignment_id.as_ref()?), s)))
+        .collect();
+
+    let mut missing = Vec::new();
+    for student in students {
+        for assignment in &expected {
+            let Some(submission) = submissions.get(&(&student.id, &assignment.id)) else {
+                continue;
+            };
+            let turned_in = submission.excused == Some(true)
+                || submission
+                    .workflow_state
+                    .as_deref()
+                    .is_some_and(|state| SUBMITTED_STATES.contains(&state));
+            let due_at = submission
+                .cached_due_date
+                .or_else(|| assignment.due_at_for(&student.id, &student.section_ids));
+            if !turned_in && due_at.is_some_and(|due| due < now) {
+                missing.push(MissingItem {
+                    assignment_id: assignment.id.clone(),
+                    assignment: assignment.name.clone(),
+                    due_at,
+                    student: student.label.clone(),
+                });
+            }
+        }
+    }
+    missing
+}
+
+/// Group report items by student or assignment, keeping first-seen order
+fn group(items: Vec<MissingItem>, by_assignment: bool) -> Vec<MissingGroup> {
+    let mut groups: Vec<MissingGroup> = Vec::new();
+    for item in items {
+        let label = if by_assignment {
+            item.assignment.clone()
+        } else {
+            item.student.clone()
+        };
+        // Assignment names aren't unique, so those groups are keyed on ID
+        let same_group = |group: &MissingGroup| {
+            if by_assignment {
+                group.missing[0].assignment_id == item.assignment_id
+            } else {
+                group.label == label
+            }
+        };
+        match groups.iter_mut().find(|g| same_group(g)) {
+            Some(group) => group.missing.push(item),
+            None => groups.push(MissingGroup {
+                label,
+                missing: vec![item],
+            }),
+        }
+    }
+    if by_assignment {
+        groups.sort_by_key(|g| g.missing[0].due_at);
+    }
+    groups
+}
+
+impl CanvasServer {
+    /// Label each student for a report: name and ID, or a pseudonym when
+    /// anonymized unless `reveal` asks for the real Canvas ID
+    fn student_labels(&self, students: Vec<User>, reveal: bool) -> Vec<Student> {
+        students
+            .into_iter()
+            .map(|student| {
+                let label = match self.client.anonymizer() {
+                    Some(anonymizer) if !reveal => anonymizer.pseudonym(&student.id.to_string()),
+                    Some(_) => format!("user {}", student.id),
+                    None => format!("{} (user {})", student.name, student.id),
+                };
+                let section_ids = student
+                    .enrollments
+                    .into_iter()
+                    .filter_map(|enrollment| enrollment.course_section_id)
+                    .collect();
+                Student {
+                    id: student.id,
+                    label,
+                    section_ids,
+                }
+            })
+            .collect()
+    }
+
+    /// Fetch a course's assignments, student roster, and submissions
+    /// concurrently, limited to one assignment's submissions if given
+    async fn fetch_course_work(
+        &self,
+        course_id: &FlexibleId,
+        assignment_id: Option<&FlexibleId>,
+        cache_bypass: Option<bool>,
+    ) -> crate::Result<(Vec<Assignment>, Vec<User>, Vec<Submission>)> {
+        let course = course_id.path_segment();
+        let assignments_path = QueryBuilder::new()
+            .include("overrides")
+            .to_path(&format!("/courses/{}/assignments", course));
+        let students_path = QueryBuilder::new()
+            .param("enrollment_type[]", "student")
+            .include("enrollments")
+            .to_path(&format!("/courses/{}/users", course));
+        let mut submissions_query = QueryBuilder::new();
+        submissions_query.param("student_ids[]", "all");
+        if let Some(assignment_id) = assignment_id {
+            submissions_query.param("assignment_ids[]", assignment_id);
+        }
+        let submissions_path =
+            submissions_query.to_path(&format!("/courses/{}/students/submissions", course));
+
+        let client = self.reader(cache_bypass);
+        tokio::try_join!(
+            client.get_all::<Assignment>(&assignments_path),
+            client.get_all::<User>(&students_path),
+            client.get_all::<Submission>(&submissions_path),
+        )
+    }
+
+    #[tool(
+        description = "Report students who haven't submitted past-due assignments in a course, grouped by student or by assignment, for early intervention"
+    )]
+    pub(crate) async fn missing_submissions(
+        &self,
+        #[tool(aggr)] params: MissingSubmissionsParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        let group_by = params.group_by.as_deref().unwrap_or("student");
+        check_choice("group_by", group_by, GROUP_BY)?;
+
+        let (assignments, students, submissions) = self
+            .fetch_course_work(&params.course_id, None, params.cache_bypass)
+            .await?;
+        let students = self.student_labels(students, params.reveal_identity.unwrap_or(false));
+
+        let items = find_missing(&assignments, &students, &submissions, Utc::now());
+        let total = items.len();
+        let groups = group(items, group_by == "assignment");
+
+        let tz = self.timezone();
+        let mut summary = format!(
+            "{} missing submission(s) in course {}",
+            total, params.course_id
+        );
+        for group in &groups {
+            summary.push_str(&format!(
+                "\n- {} ({} missing)",
+                group.label,
+                group.missing.len()
+            ));
+            for item in &group.missing {
+                let detail = if group_by == "assignment" {
+                    item.student.clone()
+                } else {
+                    format!("{}, {}", item.assignment, due_label(item.due_at, tz))
+                };
+                summary.push_str(&format!("\n  - {}", detail));
+            }
+        }
+
+        tool_result(summary, &groups)
+    }
+
+    #[tool(
+        description = "List late submissions in a course with how late each was (against the student's own due date, including overrides) and any late-policy deduction, latest first"
+    )]
+    pub(crate) async fn late_submissions(
+        &self,
+        #[tool(aggr)] params: LateSubmissionsParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        if let Some(assignment_id) = &params.assignment_id {
+            validate_id("assignment_id", assignment_id)?;
+        }
+
+        let (assignments, students, submissions) = self
+            .fetch_course_work(
+                &params.course_id,
+                params.assignment_id.as_ref(),
+                params.cache_bypass,
+            )
+            .await?;
+        let students = self.student_labels(students, params.reveal_identity.unwrap_or(false));
+        let late = find_late(&assignments, &students, &submissions);
+
+        let tz = self.timezone();
+        let mut summary = format!(
+            "{} late submission(s) in course {}",
+            late.len(),
+            params.course_id
+        );
+        for item in &late {
+            summary.push_str(&format!("\n- {}: {}", item.student, item.assignment));
+            match (item.submitted_at, item.late_by_secs) {
+                (Some(submitted), Some(secs)) => summary.push_str(&format!(
+                    ", submitted {} ({} late, {})",
+                    format_local(submitted, tz),
+                    format_duration(chrono::Duration::seconds(secs)),
+                    due_label(item.due_at, tz)
+                )),
+                _ => summary.push_str(&format!(", {}", due_label(item.due_at, tz))),
+            }
+            match item.points_deducted {
+                Some(points) => summary.push_str(&format!("; {} point(s) deducted", points)),
+                None => summary.push_str("; no deduction"),
+            }
+        }
+
+        tool_result(summary, &late)
+    }
+
+    #[tool(
+        description = "Score distribution for an assignment: mean, median, standard deviation, quartiles, and an ASCII histogram, with counts of graded, ungraded, and excused submissions"
+    )]
+    pub(crate) async fn grade_distribution(
+        &self,
+        #[tool(aggr)] params: GradeDistributionParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        validate_id("assignment_id", &params.assignment_id)?;
+
+        let submissions_path = format!(
+            "/courses/{}/assignments/{}/submissions",
+            params.course_id.path_segment(),
+            params.assignment_id.path_segment()
+        );
+        let client = self.reader(params.cache_bypass);
+        let (assignment, submissions) = tokio::try_join!(
+            client.get_assignment(&params.course_id, &params.assignment_id),
+            client.get_all::<Submission>(&submissions_path),
+        )?;
+
+        let (mut ungraded, mut excused) = (0, 0);
+        let mut scores = Vec::new();
+        for submission in &submissions {
+            match submission.score {
+                _ if submission.excused == Some(true) => excused += 1,
+                Some(score) => scores.push(score),
+                None => ungraded += 1,
+            }
+        }
+        let distribution = GradeDistribution {
+            assignment_id: assignment.id,
+            assignment: assignment.name,
+            points_possible: assignment.points_possible,
+            graded: scores.len(),
+            ungraded,
+            excused,
+            stats: ScoreStats::new(&scores),
+            histogram: histogram(&scores, assignment.points_possible),
+        };
+
+        let mut summary = format!(
+            "Grade distribution for {}: {} graded, {} ungraded, {} excused",
+            distribution.assignment, distribution.graded, ungraded, excused
+        );
+        if let Some(points) = distribution.points_possible {
+            summary.push_str(&format!(" (out of {})", trim_number(points)));
+        }
+        if let Some(stats) = &distribution.stats {
+            summary.push_str(&format!(
+                "\nMean {}, median {}, std dev {}\nMin {}, Q1 {}, Q3 {}, max {}\n",
+                trim_number(stats.mean),
+                trim_number(stats.median),
+                trim_number(stats.stddev),
+                trim_number(stats.min),
+                trim_number(stats.q1),
+                trim_number(stats.q3),
+                trim_number(stats.max)
+            ));
+            let largest = distribution
+                .histogram
+                .iter()
+                .map(|bin| bin.count)
+                .max()
+                .unwrap_or(0)
+                .max(1);
+            let labels: Vec<String> = distribution
+                .histogram
+                .iter()
+                .map(|bin| format!("{}-{}", trim_number(bin.low), trim_number(bin.high)))
+                .collect();
+            let label_width = labels.iter().map(String::len).max().unwrap_or(0);
+            for (bin, label) in distribution.histogram.iter().zip(&labels) {
+                let mut hashes = "#".repeat((bin.count * HISTOGRAM_WIDTH).div_ceil(largest));
+                if !hashes.is_empty() {
+                    hashes.push(' ');
+                }
+                summary.push_str(&format!(
+                    "\n{:>width$} | {}{}",
+                    label,
+                    hashes,
+                    bin.count,
+                    width = label_width
+                ));
+            }
+        }
+
+        tool_result(summary, &distribution)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::anonymize::pseudonym;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_score_stats_and_histogram() {
+        let scores = [2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0];
+        let stats = ScoreStats::new(&scores).unwrap();
+        assert_eq!(stats.mean, 5.0);
+        assert_eq!(stats.stddev, 2.0);
+        assert_eq!((stats.min, stats.q1, stats.median), (2.0, 4.0, 4.5));
+        assert_eq!((stats.q3, stats.max), (5.5, 9.0));
+        assert!(ScoreStats::new(&[]).is_none());
+
+        let bins = histogram(&[0.0, 9.9, 10.0, 12.0], Some(10.0));
+        let counts: Vec<usize> = bins.iter().map(|b| b.count).collect();
+        assert_eq!(counts, vec![1, 0, 0, 0, 0, 0, 0, 0, 0, 3]);
+    }
+
+    #[tokio::test]
+    async fn test_grade_distribution_excludes_ungraded_and_excused() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/courses/1/assignments/7")
+            .with_body(r#"{"id": 7, "name": "Midterm", "points_possible": 10}"#)
+            .create_async()
+            .await;
+        server
+            .mock(
+                "GET",
+                "/api/v1/courses/1/assignments/7/submissions?per_page=100",
+            )
+            .with_body(
+                r#"[{"user_id": 1, "score": 9}, {"user_id": 2, "score": 7},
+                    {"user_id": 3, "score": 8}, {"user_id": 4, "score": null},
+                    {"user_id": 5, "score": 0, "excused": true}]"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .grade_distribution(GradeDistributionParams {
+                course_id: FlexibleId::from(1),
+                assignment_id: FlexibleId::from(7),
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.starts_with(
+            "Grade distribution for Midterm: 3 graded, 1 ungraded, 1 excused (out of 10)\nMean 8, median 8, std dev 0.82\nMin 7, Q1 7.5, Q3 8.5, max 9"
+        ));
+        let full = "#".repeat(HISTOGRAM_WIDTH);
+        assert!(summary.ends_with(&format!(
+            "\n 6-7 | 0\n 7-8 | {full} 1\n 8-9 | {full} 1\n9-10 | {full} 1"
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_late_submissions_use_student_due_date() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock(
+                "GET",
+                "/api/v1/courses/1/assignments?include[]=overrides&per_page=100",
+            )
+            .with_body(r#"[{"id": 10, "name": "Essay", "due_at": "2025-01-10T12:00:00Z"}]"#)
+            .create_async()
+            .await;
+        server
+            .mock(
+                "GET",
+                "/api/v1/courses/1/users?enrollment_type[]=student&include[]=enrollments&per_page=100",
+            )
+            .with_body(r#"[{"id": 42, "name": "Jane Doe"}, {"id": 43, "name": "John Roe"}]"#)
+            .create_async()
+            .await;
+        server
+            .mock(
+                "GET",
+                "/api/v1/courses/1/students/submissions?student_ids[]=all&assignment_ids[]=10&per_page=100",
+            )
+            .with_body(
+                r#"[{"user_id": 42, "assignment_id": 10, "late": true, "points_deducted": 1.5,
+                     "submitted_at": "2025-01-11T13:30:00Z", "cached_due_date": "2025-01-10T12:00:00Z"},
+                    {"user_id": 43, "assignment_id": 10, "late": true, "points_deducted": 0,
+                     "submitted_at": "2025-01-13T12:05:00Z", "cached_due_date": "2025-01-13T12:00:00Z"},
+                    {"user_id": 43, "assignment_id": 11, "late": false}]"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .late_submissions(LateSubmissionsParams {
+                course_id: FlexibleId::from(1),
+                assignment_id: Some(FlexibleId::from(10)),
+                reveal_identity: None,
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        let lines: Vec<&str> = summary.lines().collect();
+        assert_eq!(lines[0], "2 late submission(s) in course 1");
+        assert_eq!(
+            lines[1],
+            "- Jane Doe (user 42): Essay, submitted Sat, Jan 11 at 1:30 PM (1d 1h 30m late, due Fri, Jan 10 at 12:00 PM); 1.5 point(s) deducted"
+        );
+        // John's extension moved his due date, so he was only 5 minutes late
+        assert!(lines[2].contains("(5m late, due Mon, Jan 13 at 12:00 PM); no deduction"));
+    }
+
+    #[tokio::test]
+    async fn test_missing_submissions_grouping_and_anonymization() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/courses/1/assignments?include[]=overrides&per_page=100")
+            .expect(2)
+            .with_body(
+                r#"[{"id": 10, "name": "Essay", "published": true, "due_at": "2025-01-10T23:59:00Z",
+                     "submission_types": ["online_upload"]},
+                    {"id": 11, "name": "Quiz", "published": true, "due_at": "2025-01-05T23:59:00Z",
+                     "submission_types": ["online_quiz"],
+                     "overrides": [{"id": 1, "student_ids": [44], "due_at": "2999-01-01T00:00:00Z"}]},
+                    {"id": 12, "name": "Poster", "published": true, "due_at": "2025-01-05T23:59:00Z",
+                     "submission_types": ["on_paper"]},
+                    {"id": 13, "name": "Final", "published": true, "due_at": "2999-01-01T00:00:00Z",
+                     "submission_types": ["online_upload"]},
+                    {"id": 14, "name": "Lab", "published": true, "due_at": "2025-01-05T23:59:00Z",
+                     "submission_types": ["online_upload"], "only_visible_to_overrides": true,
+                     "overrides": [{"id": 2, "course_section_id": 2, "due_at": "2999-01-01T00:00:00Z"}]}]"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock(
+                "GET",
+                "/api/v1/courses/1/users?enrollment_type[]=student&include[]=enrollments&per_page=100",
+            )
+            .expect(2)
+            .with_body(
+                r#"[{"id": 42, "name": "Jane Doe"},
+                    {"id": 43, "name": "John Roe", "enrollments": [
+                        {"id": 7, "type": "StudentEnrollment", "course_section_id": 2}]},
+                    {"id": 44, "name": "Ann Poe"}]"#,
+            )
+            .create_async()
+            .await;
+        server
+            .mock(
+                "GET",
+                "/api/v1/courses/1/students/submissions?student_ids[]=all&per_page=100",
+            )
+            .expect(2)
+            .with_body(
+                r#"[{"user_id": 42, "assignment_id": 10, "workflow_state": "graded"},
+                    {"user_id": 42, "assignment_id": 11, "workflow_state": "unsubmitted"},
+                    {"user_id": 43, "assignment_id": 10, "workflow_state": "unsubmitted", "excused": true},
+                    {"user_id": 43, "assignment_id": 11, "workflow_state": "unsubmitted"},
+                    {"user_id": 43, "assignment_id": 14, "workflow_state": "unsubmitted"},
+                    {"user_id": 44, "assignment_id": 10, "workflow_state": "submitted"},
+                    {"user_id": 44, "assignment_id": 11, "workflow_state": "unsubmitted"}]"#,
+            )
+            .create_async()
+            .await;
+
+        let mut config = CanvasConfig::new("token".to_string(), server.url());
+        config.enable_anonymization = true;
+        config.anonymization_salt = "salt".to_string();
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+
+        let result = canvas
+            .missing_submissions(MissingSubmissionsParams {
+                course_id: FlexibleId::from(1),
+                group_by: None,
+                reveal_identity: None,
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.starts_with("2 missing submission(s) in course 1"));
+        assert!(summary.contains(&format!("- {} (1 missing)", pseudonym("42", "salt"))));
+        assert!(summary.contains(&format!("- {} (1 missing)", pseudonym("43", "salt"))));
+        assert!(!summary.contains("Essay") && !summary.contains("Poster"));
+        // Student 44's extension on the quiz hasn't run out
+        assert!(!summary.contains(&pseudonym("44", "salt")));
+        // The lab is only assigned to section 2, whose due date is later
+        assert!(!summary.contains("Lab"));
+
+        let result = canvas
+            .missing_submissions(MissingSubmissionsParams {
+                course_id: FlexibleId::from(1),
+                group_by: Some("assignment".to_string()),
+                reveal_identity: Some(true),
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains("- Quiz (2 missing)\n  - user 42\n  - user 43"));
+    }
+}
@@ -0,0 +1,194 @@
+use super::{tool_result, validate_id};
+use crate::error::CanvasError;
+use crate::models::{FlexibleId, Rubric};
+use crate::server::CanvasServer;
+use rmcp::model::CallToolResult;
+use rmcp::{schemars, tool, Error as McpError};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListRubricsParams {
+    /// Canvas course ID (give this or account_id)
+    pub course_id: Option<FlexibleId>,
+
+    /// Canvas account ID, for rubrics shared at the account level
+    pub account_id: Option<FlexibleId>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetRubricParams {
+    /// Canvas course ID (give this or account_id)
+    pub course_id: Option<FlexibleId>,
+
+    /// Canvas account ID, for rubrics shared at the account level
+    pub account_id: Option<FlexibleId>,
+
+    /// Rubric ID
+    pub rubric_id: FlexibleId,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+/// API path of the course or account that owns a rubric
+fn rubric_context(
+    course_id: Option<&FlexibleId>,
+    account_id: Option<&FlexibleId>,
+) -> Result<String, McpError> {
+    match (course_id, account_id) {
+        (Some(course_id), None) => {
+            validate_id("course_id", course_id)?;
+            Ok(format!("/courses/{}", course_id.path_segment()))
+        }
+        (None, Some(account_id)) => {
+            validate_id("account_id", account_id)?;
+            Ok(format!("/accounts/{}", account_id.path_segment()))
+        }
+        _ => Err(McpError::from(CanvasError::InvalidParameter(
+            "Give exactly one of course_id or account_id".to_string(),
+        ))),
+    }
+}
+
+/// Point total for a summary line, e.g. "10 pts" or "unscored"
+fn points_label(points: Option<f64>) -> String {
+    points
+        .map(|p| format!("{} pts", p))
+        .unwrap_or_else(|| "unscored".to_string())
+}
+
+impl CanvasServer {
+    #[tool(description = "List the rubrics in a course, or shared in an account")]
+    pub(crate) async fn list_rubrics(
+        &self,
+        #[tool(aggr)] params: ListRubricsParams,
+    ) -> Result<CallToolResult, McpError> {
+        let context = rubric_context(params.course_id.as_ref(), params.account_id.as_ref())?;
+
+        let rubrics: Vec<Rubric> = self
+            .reader(params.cache_bypass)
+            .get_all(&format!("{}/rubrics", context))
+            .await?;
+
+        let mut summary = format!("Found {} rubric(s):", rubrics.len());
+        for rubric in &rubrics {
+            summary.push_str(&format!(
+                "\n- {} (ID {}): {}, {} criteria",
+                rubric.title,
+                rubric.id,
+                points_label(rubric.points_possible),
+                rubric.criteria.len()
+            ));
+        }
+
+        tool_result(summary, &rubrics)
+    }
+
+    #[tool(
+        description = "Get a rubric's criteria with their rating levels, points, and descriptions, plus completed assessments"
+    )]
+    pub(crate) async fn get_rubric(
+        &self,
+        #[tool(aggr)] params: GetRubricParams,
+    ) -> Result<CallToolResult, McpError> {
+        let context = rubric_context(params.course_id.as_ref(), params.account_id.as_ref())?;
+        validate_id("rubric_id", &params.rubric_id)?;
+
+        let path = format!(
+            "{}/rubrics/{}?include[]=assessments&style=full",
+            context,
+            params.rubric_id.path_segment()
+        );
+        let rubric: Rubric = self.reader(params.cache_bypass).get(&path).await?;
+
+        let mut summary = format!(
+            "{} (ID {}): {}, {} criteria, {} assessment(s)",
+            rubric.title,
+            rubric.id,
+            points_label(rubric.points_possible),
+            rubric.criteria.len(),
+            rubric.assessments.len()
+        );
+        for criterion in &rubric.criteria {
+            summary.push_str(&format!(
+                "\n- {} [{}]: {} pts",
+                criterion.description, criterion.id, criterion.points
+            ));
+            if let Some(long) = criterion
+                .long_description
+                .as_deref()
+                .filter(|d| !d.is_empty())
+            {
+                summary.push_str(&format!(" — {}", long));
+            }
+            for rating in &criterion.ratings {
+                summary.push_str(&format!("\n  - {}: {}", rating.points, rating.description));
+            }
+        }
+
+        tool_result(summary, &rubric)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[test]
+    fn test_rubric_context_requires_one_owner() {
+        assert_eq!(
+            rubric_context(Some(&FlexibleId::from(1)), None).unwrap(),
+            "/courses/1"
+        );
+        assert_eq!(
+            rubric_context(None, Some(&FlexibleId::from(2))).unwrap(),
+            "/accounts/2"
+        );
+        assert!(rubric_context(None, None).is_err());
+        assert!(rubric_context(Some(&FlexibleId::from(1)), Some(&FlexibleId::from(2))).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_account_rubric() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock(
+                "GET",
+                "/api/v1/accounts/2/rubrics/5?include[]=assessments&style=full",
+            )
+            .with_body(
+                r#"{"id": 5, "title": "Essay rubric", "context_type": "Account", "points_possible": 10,
+                    "data": [{"id": "_1", "description": "Thesis", "points": 10,
+                              "ratings": [{"id": "r1", "description": "Clear", "points": 10},
+                                          {"id": "r2", "description": "Missing", "points": 0}]}],
+                    "assessments": [{"id": 9, "score": 8, "artifact_type": "Submission",
+                                     "data": [{"criterion_id": "_1", "points": 8, "comments": "Good"}]}]}"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .get_rubric(GetRubricParams {
+                course_id: None,
+                account_id: Some(FlexibleId::from(2)),
+                rubric_id: FlexibleId::from(5),
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert_eq!(
+            summary,
+            "Essay rubric (ID 5): 10 pts, 1 criteria, 1 assessment(s)\n\
+             - Thesis [_1]: 10 pts\n  - 10: Clear\n  - 0: Missing"
+        );
+    }
+}
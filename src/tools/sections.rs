@@ -0,0 +1,113 @@
+use super::{tool_result, validate_id};
+use crate::client::QueryBuilder;
+use crate::models::{FlexibleId, Section};
+use crate::server::CanvasServer;
+use rmcp::model::CallToolResult;
+use rmcp::{schemars, tool, Error as McpError};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListSectionsParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Include each section's student roster
+    pub include_students: Option<bool>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+impl CanvasServer {
+    #[tool(
+        description = "List a course's sections with SIS IDs, student counts, cross-listing, and optionally each section's students"
+    )]
+    pub(crate) async fn list_sections(
+        &self,
+        #[tool(aggr)] params: ListSectionsParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+
+        let mut query = QueryBuilder::new();
+        query.include("total_students");
+        if params.include_students.unwrap_or(false) {
+            query.include("students");
+        }
+        let path = query.to_path(&format!(
+            "/courses/{}/sections",
+            params.course_id.path_segment()
+        ));
+
+        let sections: Vec<Section> = self.reader(params.cache_bypass).get_all(&path).await?;
+
+        let mut summary = format!("Found {} section(s):", sections.len());
+        for section in &sections {
+            summary.push_str(&format!(
+                "\n- {} (ID {}): {} student(s)",
+                section.name,
+                section.id,
+                section.total_students.unwrap_or(0)
+            ));
+            if let Some(sis_id) = &section.sis_section_id {
+                summary.push_str(&format!(", SIS {}", sis_id));
+            }
+            if let Some(original) = &section.nonxlist_course_id {
+                summary.push_str(&format!(", cross-listed from course {}", original));
+            }
+            if let Some(students) = &section.students {
+                let names: Vec<&str> = students.iter().map(|s| s.name.as_str()).collect();
+                summary.push_str(&format!("\n  {}", names.join(", ")));
+            }
+        }
+
+        tool_result(summary, &sections)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::anonymize::pseudonym;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_list_sections_with_anonymized_students() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock(
+                "GET",
+                "/api/v1/courses/1/sections?include[]=total_students&include[]=students&per_page=100",
+            )
+            .with_body(
+                r#"[{"id": 10, "name": "Section A", "sis_section_id": "BIOL101-A",
+                     "nonxlist_course_id": 2, "total_students": 1,
+                     "students": [{"id": 42, "name": "Jane Doe", "sortable_name": "Doe, Jane"}]},
+                    {"id": 11, "name": "Section B", "total_students": 0, "students": null}]"#,
+            )
+            .create_async()
+            .await;
+
+        let mut config = CanvasConfig::new("token".to_string(), server.url());
+        config.enable_anonymization = true;
+        config.anonymization_salt = "salt".to_string();
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+
+        let result = canvas
+            .list_sections(ListSectionsParams {
+                course_id: FlexibleId::from(1),
+                include_students: Some(true),
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains(
+            "Section A (ID 10): 1 student(s), SIS BIOL101-A, cross-listed from course 2"
+        ));
+        assert!(summary.contains(&format!("\n  {}", pseudonym("42", "salt"))));
+        assert!(summary.contains("Section B (ID 11): 0 student(s)"));
+        mock.assert_async().await;
+    }
+}
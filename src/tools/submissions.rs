@@ -0,0 +1,1278 @@
+use super::{check_choice, tool_result, validate_id};
+use crate::client::QueryBuilder;
+use crate::datetime::format_local;
+use crate::error::CanvasError;
+use crate::models::{FlexibleId, Progress, RubricCriterion, Submission, User};
+use crate::server::CanvasServer;
+use rmcp::model::CallToolResult;
+use rmcp::{schemars, tool, Error as McpError};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
+use std::time::Duration;
+
+/// How often to poll a bulk grading job when waiting for it
+const BULK_GRADE_POLL_INTERVAL: Duration = Duration::from_secs(2);
+
+/// Give up waiting for a bulk grading job after this long
+const BULK_GRADE_WAIT_TIMEOUT: Duration = Duration::from_secs(300);
+
+/// Submission types `submit_assignment` can make on a student's behalf
+const SUBMIT_TYPES: &[&str] = &["online_text_entry", "online_url", "online_upload"];
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GradeSubmissionParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Canvas assignment ID
+    pub assignment_id: FlexibleId,
+
+    /// Canvas user ID of the student
+    pub user_id: FlexibleId,
+
+    /// Grade to post: points ("9.5"), a percentage ("95%"), a letter grade
+    /// ("A-"), or "pass"/"fail" / "complete"/"incomplete"
+    pub grade: String,
+
+    /// Optional text comment to add alongside the grade
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GradeWithRubricParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Canvas assignment ID (must have a rubric attached)
+    pub assignment_id: FlexibleId,
+
+    /// Canvas user ID of the student
+    pub user_id: FlexibleId,
+
+    /// Points and comments for each rubric criterion being assessed
+    pub criteria: Vec<RubricScore>,
+
+    /// Overall grade to post as well; when omitted, Canvas derives the score
+    /// from the rubric if the rubric is used for grading
+    pub grade: Option<String>,
+
+    /// Optional text comment on the submission as a whole
+    pub comment: Option<String>,
+}
+
+/// Assessment of one rubric criterion
+#[derive(Debug, Deserialize, Serialize, schemars::JsonSchema)]
+pub struct RubricScore {
+    /// Criterion ID from get_rubric, e.g. "_1234"
+    pub criterion_id: String,
+
+    /// Points awarded, between 0 and the criterion's maximum
+    pub points: Option<f64>,
+
+    /// Feedback for this criterion
+    pub comments: Option<String>,
+}
+
+/// Result of `grade_submission_with_rubric`
+#[derive(Debug, Serialize)]
+struct RubricGrade {
+    /// Sum of the points given in this assessment
+    rubric_total: f64,
+    points_possible: f64,
+    criteria: Vec<RubricScore>,
+    submission: Submission,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct CommentOnSubmissionParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Canvas assignment ID
+    pub assignment_id: FlexibleId,
+
+    /// Canvas user ID of the student
+    pub user_id: FlexibleId,
+
+    /// Comment text
+    pub comment: String,
+
+    /// Send the comment to every member of the student's group, for group
+    /// assignments (default false)
+    pub group_comment: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SubmitAssignmentParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Canvas assignment ID
+    pub assignment_id: FlexibleId,
+
+    /// "online_text_entry", "online_url", or "online_upload"; must be one
+    /// the assignment accepts
+    pub submission_type: String,
+
+    /// Answer text (HTML allowed), for online_text_entry
+    pub body: Option<String>,
+
+    /// Link being submitted, for online_url
+    pub url: Option<String>,
+
+    /// IDs of files already uploaded for this submission, for online_upload
+    pub file_ids: Option<Vec<FlexibleId>>,
+
+    /// Optional comment to the instructor alongside the submission
+    pub comment: Option<String>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct BulkGradeParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Canvas assignment ID
+    pub assignment_id: FlexibleId,
+
+    /// Map of student user ID to grade (points, percentage, letter, or pass/fail)
+    pub grades: BTreeMap<String, String>,
+
+    /// Optional map of student user ID to a text comment
+    pub comments: Option<BTreeMap<String, String>>,
+
+    /// Wait for the grading job to finish before returning (default false)
+    pub wait: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct GetSubmissionParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Canvas assignment ID
+    pub assignment_id: FlexibleId,
+
+    /// Canvas user ID of the student
+    pub user_id: FlexibleId,
+
+    /// Include every prior attempt (default true)
+    pub include_history: Option<bool>,
+
+    /// Include the comment thread (default true)
+    pub include_comments: Option<bool>,
+
+    /// Include the rubric assessment (default true)
+    pub include_rubric_assessment: Option<bool>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListSubmissionsParams {
+    /// Canvas course ID
+    pub course_id: FlexibleId,
+
+    /// Canvas assignment ID
+    pub assignment_id: FlexibleId,
+
+    /// Only return submissions in this state: "submitted", "graded", or "unsubmitted"
+    pub workflow_state: Option<String>,
+
+    /// Include the comment thread on each submission
+    pub include_comments: Option<bool>,
+
+    /// Include rubric assessments
+    pub include_rubric_assessment: Option<bool>,
+
+    /// Extra data to include, e.g. "user", "assignment", "visibility"
+    pub include: Option<Vec<String>>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+/// Build the `submission[...]` form for `submit_assignment`, checking that
+/// the content its type needs was given
+fn submission_form(params: &SubmitAssignmentParams) -> Result<Vec<(String, String)>, McpError> {
+    check_choice("submission_type", &params.submission_type, SUBMIT_TYPES)?;
+    let missing = |field: &str| {
+        McpError::from(CanvasError::InvalidParameter(format!(
+            "{} is required for {} submissions",
+            field, params.submission_type
+        )))
+    };
+
+    let mut form = vec![(
+        "submission[submission_type]".to_string(),
+        params.submission_type.clone(),
+    )];
+    match params.submission_type.as_str() {
+        "online_text_entry" => {
+            let body = params
+                .body
+                .as_deref()
+                .filter(|b| !b.trim().is_empty())
+                .ok_or_else(|| missing("body"))?;
+            form.push(("submission[body]".to_string(), body.to_string()));
+        }
+        "online_url" => {
+            let url = params
+                .url
+                .as_deref()
+                .map(str::trim)
+                .filter(|u| !u.is_empty())
+                .ok_or_else(|| missing("url"))?;
+            form.push(("submission[url]".to_string(), url.to_string()));
+        }
+        _ => {
+            let file_ids = params
+                .file_ids
+                .as_deref()
+                .filter(|ids| !ids.is_empty())
+                .ok_or_else(|| missing("file_ids"))?;
+            for id in file_ids {
+                validate_id("file_ids", id)?;
+                form.push(("submission[file_ids][]".to_string(), id.to_string()));
+            }
+        }
+    }
+    if let Some(comment) = params.comment.as_deref().filter(|c| !c.trim().is_empty()) {
+        form.push(("comment[text_comment]".to_string(), comment.to_string()));
+    }
+    Ok(form)
+}
+
+/// Put the authenticated user's real name back on their own comments, which
+/// the anonymizer pseudonymizes along with everyone else's
+fn restore_own_name(submission: &mut Submission, me: &User) {
+    let my_id = me.id.to_string();
+    for comment in &mut submission.submission_comments {
+        if comment.author_id.as_ref().map(|id| id.to_string()) == Some(my_id.clone()) {
+            comment.author_name = Some(me.name.clone());
+        }
+    }
+    for attempt in &mut submission.submission_history {
+        restore_own_name(attempt, me);
+    }
+}
+
+/// Check rubric scores against the assignment's criteria: every ID must
+/// exist, appear once, and get points within the criterion's range
+fn check_rubric_scores(
+    rubric: &[RubricCriterion],
+    scores: &[RubricScore],
+) -> Result<(), CanvasError> {
+    if rubric.is_empty() {
+        return Err(CanvasError::InvalidParameter(
+            "This assignment has no rubric attached".to_string(),
+        ));
+    }
+    if scores.is_empty() {
+        return Err(CanvasError::InvalidParameter(
+            "criteria must assess at least one rubric criterion".to_string(),
+        ));
+    }
+    let mut seen = BTreeSet::new();
+    for score in scores {
+        let Some(criterion) = rubric.iter().find(|c| c.id == score.criterion_id) else {
+            let ids: Vec<&str> = rubric.iter().map(|c| c.id.as_str()).collect();
+            return Err(CanvasError::InvalidParameter(format!(
+                "Unknown rubric criterion {} (expected one of {})",
+                score.criterion_id,
+                ids.join(", ")
+            )));
+        };
+        if !seen.insert(score.criterion_id.as_str()) {
+            return Err(CanvasError::InvalidParameter(format!(
+                "Rubric criterion {} is assessed more than once",
+                score.criterion_id
+            )));
+        }
+        if let Some(points) = score.points {
+            if !(0.0..=criterion.points).contains(&points) {
+                return Err(CanvasError::InvalidParameter(format!(
+                    "Points for \"{}\" must be between 0 and {} (got {})",
+                    criterion.description, criterion.points, points
+                )));
+            }
+        }
+    }
+    Ok(())
+}
+
+impl CanvasServer {
+    #[tool(
+        description = "List all submissions for an assignment, with graded/ungraded counts and who hasn't submitted"
+    )]
+    pub(crate) async fn list_submissions(
+        &self,
+        #[tool(aggr)] params: ListSubmissionsParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        validate_id("assignment_id", &params.assignment_id)?;
+        if let Some(state) = &params.workflow_state {
+            check_choice(
+                "workflow_state",
+                state,
+                &["submitted", "graded", "unsubmitted"],
+            )?;
+        }
+
+        let mut query = QueryBuilder::new();
+        // Names for the "Not submitted" line
+        query.include("user");
+        if params.include_comments.unwrap_or(false) {
+            query.include("submission_comments");
+        }
+        if params.include_rubric_assessment.unwrap_or(false) {
+            query.include("rubric_assessment");
+        }
+        for include in params.include.iter().flatten() {
+            query.include(include.as_str());
+        }
+        let path = query.to_path(&format!(
+            "/courses/{}/assignments/{}/submissions",
+            params.course_id.path_segment(),
+            params.assignment_id.path_segment()
+        ));
+
+        let all: Vec<Value> = self.reader(params.cache_bypass).get_all(&path).await?;
+
+        let state_of = |s: &Value| {
+            s.get("workflow_state")
+                .and_then(|v| v.as_str())
+                .unwrap_or("unsubmitted")
+                .to_string()
+        };
+        let graded = all.iter().filter(|s| state_of(s) == "graded").count();
+        let unsubmitted = all.iter().filter(|s| state_of(s) == "unsubmitted").count();
+        let awaiting = all.len() - graded - unsubmitted;
+
+        let submissions: Vec<Value> = match &params.workflow_state {
+            Some(state) => all.into_iter().filter(|s| &state_of(s) == state).collect(),
+            None => all,
+        };
+
+        let mut summary = format!(
+            "{} graded, {} submitted but ungraded, {} not submitted",
+            graded, awaiting, unsubmitted
+        );
+        let anonymizer = self.client.anonymizer();
+        let missing: Vec<String> = submissions
+            .iter()
+            .filter(|s| state_of(s) == "unsubmitted")
+            .filter_map(|s| {
+                let id = s.get("user_id").map(display_id)?;
+                let name = s.pointer("/user/name").and_then(Value::as_str);
+                Some(match (anonymizer, name) {
+                    (Some(anonymizer), _) => anonymizer.pseudonym(&id),
+                    (None, Some(name)) => format!("{} (user {})", name, id),
+                    (None, None) => format!("user {}", id),
+                })
+            })
+            .collect();
+        if !missing.is_empty() {
+            summary.push_str(&format!("\nNot submitted: {}", missing.join(", ")));
+        }
+
+        tool_result(summary, &submissions)
+    }
+
+    #[tool(
+        description = "Get one student's submission for an assignment with its grade, every prior attempt, the comment thread, and the rubric assessment"
+    )]
+    pub(crate) async fn get_submission(
+        &self,
+        #[tool(aggr)] params: GetSubmissionParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        validate_id("assignment_id", &params.assignment_id)?;
+        validate_id("user_id", &params.user_id)?;
+
+        let mut query = QueryBuilder::new();
+        if params.include_history.unwrap_or(true) {
+            query.include("submission_history");
+        }
+        if params.include_comments.unwrap_or(true) {
+            query.include("submission_comments");
+        }
+        if params.include_rubric_assessment.unwrap_or(true) {
+            query.include("rubric_assessment");
+        }
+        let path = query.to_path(&format!(
+            "/courses/{}/assignments/{}/submissions/{}",
+            params.course_id.path_segment(),
+            params.assignment_id.path_segment(),
+            params.user_id.path_segment()
+        ));
+        let mut submission: Submission = self.reader(params.cache_bypass).get(&path).await?;
+
+        // Only students are pseudonymized; the caller's own comments keep
+        // their name. The raw body of /users/self isn't anonymized.
+        if self.client.anonymizer().is_some() && !submission.submission_comments.is_empty() {
+            let me: User = serde_json::from_str(&self.client.get_text("/users/self").await?)
+                .map_err(|e| {
+                    CanvasError::internal(format!("Failed to parse current user: {}", e))
+                })?;
+            restore_own_name(&mut submission, &me);
+        }
+
+        let tz = self.timezone();
+        let score = |s: &Submission| match (s.score, s.grade.as_deref()) {
+            (Some(score), Some(grade)) if grade != score.to_string() => {
+                format!("score {} (grade {})", score, grade)
+            }
+            (Some(score), _) => format!("score {}", score),
+            (None, _) => "not graded".to_string(),
+        };
+        let submitted = |s: &Submission| match s.submitted_at {
+            Some(at) => format!("submitted {}", format_local(at, tz)),
+            None => "not submitted".to_string(),
+        };
+
+        let mut summary = format!(
+            "Submission by user {} for assignment {}: {}, {}, {}",
+            params.user_id,
+            params.assignment_id,
+            submission.workflow_state.as_deref().unwrap_or("unknown"),
+            score(&submission),
+            submitted(&submission),
+        );
+        if let Some(attempt) = submission.attempt {
+            summary.push_str(&format!(", attempt {}", attempt));
+        }
+        if submission.late {
+            summary.push_str(" (late)");
+        }
+
+        let attempts: Vec<&Submission> = submission
+            .submission_history
+            .iter()
+            .filter(|s| s.submitted_at.is_some())
+            .collect();
+        if !attempts.is_empty() {
+            summary.push_str("\nAttempts:");
+            for attempt in attempts {
+                summary.push_str(&format!(
+                    "\n- {}: {}, {}",
+                    attempt
+                        .attempt
+                        .map(|a| format!("Attempt {}", a))
+                        .unwrap_or_else(|| "Attempt".to_string()),
+                    submitted(attempt),
+                    score(attempt)
+                ));
+            }
+        }
+
+        if let Some(rubric) = submission
+            .rubric_assessment
+            .as_ref()
+            .filter(|r| !r.is_empty())
+        {
+            summary.push_str("\nRubric:");
+            for (criterion, row) in rubric {
+                summary.push_str(&format!(
+                    "\n- {}: {}",
+                    criterion,
+                    row.points
+                        .map(|p| p.to_string())
+                        .unwrap_or_else(|| "no points".to_string())
+                ));
+                if let Some(comments) = row.comments.as_deref().filter(|c| !c.is_empty()) {
+                    summary.push_str(&format!(" — {}", comments));
+                }
+            }
+        }
+
+        if !submission.submission_comments.is_empty() {
+            summary.push_str("\nComments:");
+            for comment in &submission.submission_comments {
+                summary.push_str(&format!(
+                    "\n- {}{}: {}",
+                    comment.author_name.as_deref().unwrap_or("unknown author"),
+                    comment
+                        .created_at
+                        .map(|at| format!(" ({})", format_local(at, tz)))
+                        .unwrap_or_default(),
+                    comment.comment
+                ));
+            }
+        }
+
+        tool_result(summary, &submission)
+    }
+
+    #[tool(description = "Post a grade (and optional comment) for a student's submission")]
+    pub(crate) async fn grade_submission(
+        &self,
+        #[tool(aggr)] params: GradeSubmissionParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        validate_id("assignment_id", &params.assignment_id)?;
+        validate_id("user_id", &params.user_id)?;
+
+        let path = format!(
+            "/courses/{}/assignments/{}/submissions/{}",
+            params.course_id.path_segment(),
+            params.assignment_id.path_segment(),
+            params.user_id.path_segment()
+        );
+
+        let mut form = vec![("submission[posted_grade]", params.grade.as_str())];
+        if let Some(comment) = params.comment.as_deref().filter(|c| !c.trim().is_empty()) {
+            form.push(("comment[text_comment]", comment));
+        }
+
+        let submission: Submission =
+            self.client
+                .put_form(&path, &form)
+                .await
+                .map_err(|e| match e {
+                    CanvasError::Auth(message) => CanvasError::auth(format!(
+                        "{}. The API token may lack permission to grade in this course",
+                        message
+                    )),
+                    other => other,
+                })?;
+
+        let summary = format!(
+            "Graded user {} on assignment {}: score {}, grade {}, state {}",
+            params.user_id,
+            params.assignment_id,
+            submission
+                .score
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| "none".to_string()),
+            submission.grade.as_deref().unwrap_or("none"),
+            submission.workflow_state.as_deref().unwrap_or("unknown"),
+        );
+
+        self.write_result(summary, &submission)
+    }
+
+    #[tool(
+        description = "Grade a submission with the assignment's rubric, giving points and comments per criterion (criterion IDs come from get_rubric or the assignment)"
+    )]
+    pub(crate) async fn grade_submission_with_rubric(
+        &self,
+        #[tool(aggr)] params: GradeWithRubricParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        validate_id("assignment_id", &params.assignment_id)?;
+        validate_id("user_id", &params.user_id)?;
+
+        let assignment = self
+            .client
+            .get_assignment(&params.course_id, &params.assignment_id)
+            .await?;
+        check_rubric_scores(&assignment.rubric, &params.criteria)?;
+
+        let mut form = Vec::new();
+        for score in &params.criteria {
+            let key = |field: &str| format!("rubric_assessment[{}][{}]", score.criterion_id, field);
+            if let Some(points) = score.points {
+                form.push((key("points"), points.to_string()));
+            }
+            if let Some(comments) = score.comments.as_deref().filter(|c| !c.trim().is_empty()) {
+                form.push((key("comments"), comments.to_string()));
+            }
+        }
+        if let Some(grade) = &params.grade {
+            form.push(("submission[posted_grade]".to_string(), grade.clone()));
+        }
+        if let Some(comment) = params.comment.as_deref().filter(|c| !c.trim().is_empty()) {
+            form.push(("comment[text_comment]".to_string(), comment.to_string()));
+        }
+
+        let path = format!(
+            "/courses/{}/assignments/{}/submissions/{}",
+            params.course_id.path_segment(),
+            params.assignment_id.path_segment(),
+            params.user_id.path_segment()
+        );
+        let submission: Submission =
+            self.client
+                .put_form(&path, &form)
+                .await
+                .map_err(|e| match e {
+                    CanvasError::Auth(message) => CanvasError::auth(format!(
+                        "{}. The API token may lack permission to grade in this course",
+                        message
+                    )),
+                    other => other,
+                })?;
+
+        let rubric_total: f64 = params.criteria.iter().filter_map(|s| s.points).sum();
+        let points_possible: f64 = assignment.rubric.iter().map(|c| c.points).sum();
+        let mut summary = format!(
+            "Assessed user {} on assignment {} with the rubric: {} / {} (score {}, grade {})",
+            params.user_id,
+            params.assignment_id,
+            rubric_total,
+            points_possible,
+            submission
+                .score
+                .map(|s| s.to_string())
+                .unwrap_or_else(|| "none".to_string()),
+            submission.grade.as_deref().unwrap_or("none"),
+        );
+        for score in &params.criteria {
+            let description = assignment
+                .rubric
+                .iter()
+                .find(|c| c.id == score.criterion_id)
+                .map(|c| c.description.as_str())
+                .unwrap_or_default();
+            summary.push_str(&format!(
+                "\n- {}: {}",
+                description,
+                score
+                    .points
+                    .map(|p| p.to_string())
+                    .unwrap_or_else(|| "no points".to_string())
+            ));
+            if let Some(comments) = &score.comments {
+                summary.push_str(&format!(" — {}", comments));
+            }
+        }
+
+        self.write_result(
+            summary,
+            &RubricGrade {
+                rubric_total,
+                points_possible,
+                criteria: params.criteria,
+                submission,
+            },
+        )
+    }
+
+    #[tool(
+        description = "Leave a feedback comment on a student's submission without changing its grade"
+    )]
+    pub(crate) async fn comment_on_submission(
+        &self,
+        #[tool(aggr)] params: CommentOnSubmissionParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        validate_id("assignment_id", &params.assignment_id)?;
+        validate_id("user_id", &params.user_id)?;
+        let comment = params.comment.trim();
+        if comment.is_empty() {
+            return Err(McpError::from(CanvasError::InvalidParameter(
+                "comment must not be empty".to_string(),
+            )));
+        }
+
+        let path = format!(
+            "/courses/{}/assignments/{}/submissions/{}",
+            params.course_id.path_segment(),
+            params.assignment_id.path_segment(),
+            params.user_id.path_segment()
+        );
+
+        // Deliberately no submission[...] fields, so the grade is never touched
+        let mut form = vec![("comment[text_comment]", comment)];
+        if params.group_comment.unwrap_or(false) {
+            form.push(("comment[group_comment]", "true"));
+        }
+
+        let submission: Submission =
+            self.client
+                .put_form(&path, &form)
+                .await
+                .map_err(|e| match e {
+                    CanvasError::Auth(message) => CanvasError::auth(format!(
+                        "{}. The API token may lack permission to comment in this course",
+                        message
+                    )),
+                    other => other,
+                })?;
+
+        // Canvas returns the whole thread; the newest comment is ours
+        let posted = submission
+            .submission_comments
+            .iter()
+            .max_by_key(|c| c.created_at)
+            .cloned();
+        let summary = match &posted {
+            Some(posted) => format!(
+                "Comment posted on user {}'s submission for assignment {} by {}: {}",
+                params.user_id,
+                params.assignment_id,
+                posted.author_name.as_deref().unwrap_or("unknown author"),
+                posted.comment
+            ),
+            None => format!(
+                "Comment posted on user {}'s submission for assignment {}",
+                params.user_id, params.assignment_id
+            ),
+        };
+
+        self.write_result(
+            summary,
+            &serde_json::json!({"comment": posted, "submission": submission}),
+        )
+    }
+
+    #[tool(
+        description = "Submit an assignment as the current user (a student): a text entry, a URL, or previously uploaded files"
+    )]
+    pub(crate) async fn submit_assignment(
+        &self,
+        #[tool(aggr)] params: SubmitAssignmentParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        validate_id("assignment_id", &params.assignment_id)?;
+        let form = submission_form(&params)?;
+
+        let assignment = self
+            .client
+            .get_assignment(&params.course_id, &params.assignment_id)
+            .await?;
+        if !assignment
+            .submission_types
+            .contains(&params.submission_type)
+        {
+            return Err(McpError::from(CanvasError::InvalidParameter(format!(
+                "\"{}\" doesn't accept {} submissions (it accepts: {})",
+                assignment.name,
+                params.submission_type,
+                if assignment.submission_types.is_empty() {
+                    "none".to_string()
+                } else {
+                    assignment.submission_types.join(", ")
+                }
+            ))));
+        }
+
+        let path = format!(
+            "/courses/{}/assignments/{}/submissions",
+            params.course_id.path_segment(),
+            params.assignment_id.path_segment()
+        );
+        let submission: Submission =
+            self.client
+                .post_form(&path, &form)
+                .await
+                .map_err(|e| match e {
+                    CanvasError::Auth(message) => CanvasError::auth(format!(
+                        "{}. Only students enrolled in the course can submit",
+                        message
+                    )),
+                    other => other,
+                })?;
+
+        let summary = format!(
+            "Submitted \"{}\" as {}: submission {}, attempt {}",
+            assignment.name,
+            params.submission_type,
+            submission
+                .id
+                .as_ref()
+                .map(|id| id.to_string())
+                .unwrap_or_else(|| "unknown".to_string()),
+            submission
+                .attempt
+                .map(|a| a.to_string())
+                .unwrap_or_else(|| "unknown".to_string()),
+        );
+
+        self.write_result(summary, &submission)
+    }
+
+    #[tool(
+        description = "Grade many students on one assignment at once using Canvas's asynchronous bulk update"
+    )]
+    pub(crate) async fn bulk_grade(
+        &self,
+        #[tool(aggr)] params: BulkGradeParams,
+    ) -> Result<CallToolResult, McpError> {
+        validate_id("course_id", &params.course_id)?;
+        validate_id("assignment_id", &params.assignment_id)?;
+
+        let comments = params.comments.unwrap_or_default();
+        if params.grades.is_empty() && comments.is_empty() {
+            return Err(McpError::from(CanvasError::InvalidParameter(
+                "grades must contain at least one user_id -> grade entry".to_string(),
+            )));
+        }
+
+        let mut form = Vec::new();
+        for (user_id, grade) in &params.grades {
+            validate_id("user_id", &FlexibleId::from(user_id.as_str()))?;
+            form.push((
+                format!("grade_data[{}][posted_grade]", user_id),
+                grade.clone(),
+            ));
+        }
+        for (user_id, comment) in &comments {
+            validate_id("user_id", &FlexibleId::from(user_id.as_str()))?;
+            form.push((
+                format!("grade_data[{}][text_comment]", user_id),
+                comment.clone(),
+            ));
+        }
+
+        let path = format!(
+            "/courses/{}/assignments/{}/submissions/update_grades",
+            params.course_id.path_segment(),
+            params.assignment_id.path_segment()
+        );
+        let mut progress: Progress = self.client.post_form(&path, &form).await?;
+
+        // A dry-run job was never queued, so there is nothing to wait for
+        if params.wait.unwrap_or(false) && !self.config.dry_run {
+            let url = progress
+                .url
+                .clone()
+                .unwrap_or_else(|| format!("/progress/{}", progress.id));
+            let finished = self
+                .client
+                .wait_for_progress(&url, BULK_GRADE_POLL_INTERVAL, BULK_GRADE_WAIT_TIMEOUT)
+                .await?;
+            progress = serde_json::from_value(finished).map_err(CanvasError::Json)?;
+        }
+
+        let summary = format!(
+            "Bulk grading job {} for {} student(s): {} ({}% complete){}",
+            progress.id,
+            params
+                .grades
+                .keys()
+                .chain(comments.keys())
+                .collect::<BTreeSet<_>>()
+                .len(),
+            progress.workflow_state,
+            progress.completion.unwrap_or(0.0),
+            progress
+                .url
+                .as_deref()
+                .map(|url| format!("\nProgress URL: {}", url))
+                .unwrap_or_default(),
+        );
+
+        self.write_result(summary, &progress)
+    }
+}
+
+/// Render a JSON ID (number or string) without quotes
+fn display_id(value: &Value) -> String {
+    match value {
+        Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::anonymize::pseudonym;
+    use crate::backend::{MockBackend, MockResponse};
+    use crate::config::CanvasConfig;
+    use reqwest::Method;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_grade_submission_sends_form() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("PUT", "/api/v1/courses/1/assignments/7/submissions/42")
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("submission[posted_grade]".into(), "9.5".into()),
+                mockito::Matcher::UrlEncoded("comment[text_comment]".into(), "Nice work".into()),
+            ]))
+            .with_body(r#"{"id": 100, "user_id": 42, "score": 9.5, "grade": "9.5", "workflow_state": "graded"}"#)
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .grade_submission(GradeSubmissionParams {
+                course_id: FlexibleId::from(1),
+                assignment_id: FlexibleId::from(7),
+                user_id: FlexibleId::from(42),
+                grade: "9.5".to_string(),
+                comment: Some("Nice work".to_string()),
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains("score 9.5"));
+        assert!(summary.contains("state graded"));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_grade_submission_dry_run_sends_nothing() {
+        let backend = Arc::new(MockBackend::new().on(
+            Method::GET,
+            "/courses/1/assignments/7/submissions/42",
+            MockResponse::json(r#"{"id": 100, "user_id": 42, "workflow_state": "submitted"}"#),
+        ));
+        let mut config = CanvasConfig::new("token".to_string(), "https://canvas.test".to_string());
+        config.dry_run = true;
+        let canvas = CanvasServer::with_backend(Arc::new(config), backend.clone()).unwrap();
+
+        let result = canvas
+            .grade_submission(GradeSubmissionParams {
+                course_id: FlexibleId::from(1),
+                assignment_id: FlexibleId::from(7),
+                user_id: FlexibleId::from(42),
+                grade: "9.5".to_string(),
+                comment: None,
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.starts_with("[DRY RUN] Graded user 42"));
+        assert!(backend.requests().iter().all(|r| r.method == Method::GET));
+    }
+
+    #[tokio::test]
+    async fn test_grade_submission_with_rubric_validates_and_sends_rows() {
+        let backend = Arc::new(
+            MockBackend::new()
+                .on(
+                    Method::GET,
+                    "/courses/1/assignments/7",
+                    MockResponse::json(
+                        r#"{"id": 7, "name": "Essay", "rubric": [
+                            {"id": "_1", "description": "Thesis", "points": 5},
+                            {"id": "_2", "description": "Evidence", "points": 5}]}"#,
+                    ),
+                )
+                .on(
+                    Method::PUT,
+                    "/courses/1/assignments/7/submissions/42",
+                    MockResponse::json(
+                        r#"{"id": 100, "user_id": 42, "score": 8, "grade": "8", "workflow_state": "graded"}"#,
+                    ),
+                ),
+        );
+        let config = CanvasConfig::new("token".to_string(), "https://canvas.test".to_string());
+        let canvas = CanvasServer::with_backend(Arc::new(config), backend.clone()).unwrap();
+        let params = |criteria: Vec<(&str, f64)>| GradeWithRubricParams {
+            course_id: FlexibleId::from(1),
+            assignment_id: FlexibleId::from(7),
+            user_id: FlexibleId::from(42),
+            criteria: criteria
+                .into_iter()
+                .map(|(id, points)| RubricScore {
+                    criterion_id: id.to_string(),
+                    points: Some(points),
+                    comments: (id == "_1").then(|| "Clear".to_string()),
+                })
+                .collect(),
+            grade: None,
+            comment: None,
+        };
+
+        let error = canvas
+            .grade_submission_with_rubric(params(vec![("_1", 6.0)]))
+            .await
+            .unwrap_err();
+        assert!(error.message.contains("between 0 and 5"));
+        let error = canvas
+            .grade_submission_with_rubric(params(vec![("_9", 1.0)]))
+            .await
+            .unwrap_err();
+        assert!(error.message.contains("expected one of _1, _2"));
+
+        let result = canvas
+            .grade_submission_with_rubric(params(vec![("_1", 5.0), ("_2", 3.0)]))
+            .await
+            .unwrap();
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.starts_with(
+            "Assessed user 42 on assignment 7 with the rubric: 8 / 10 (score 8, grade 8)\n- Thesis: 5 — Clear\n- Evidence: 3"
+        ));
+        let put = backend.requests().pop().unwrap();
+        assert_eq!(put.method, Method::PUT);
+        assert_eq!(
+            put.body.as_deref(),
+            Some(
+                "rubric_assessment%5B_1%5D%5Bpoints%5D=5&rubric_assessment%5B_1%5D%5Bcomments%5D=Clear\
+                 &rubric_assessment%5B_2%5D%5Bpoints%5D=3"
+            )
+        );
+    }
+
+    #[tokio::test]
+    async fn test_grade_submission_explains_permission_error() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("PUT", "/api/v1/courses/1/assignments/7/submissions/42")
+            .with_status(401)
+            .with_body(r#"{"errors": [{"message": "user not authorized to perform that action"}], "status": "unauthorized"}"#)
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let error = canvas
+            .grade_submission(GradeSubmissionParams {
+                course_id: FlexibleId::from(1),
+                assignment_id: FlexibleId::from(7),
+                user_id: FlexibleId::from(42),
+                grade: "A".to_string(),
+                comment: None,
+            })
+            .await
+            .unwrap_err();
+        assert!(error.message.contains("permission to grade"));
+    }
+
+    #[tokio::test]
+    async fn test_comment_on_submission_sends_only_comment() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("PUT", "/api/v1/courses/1/assignments/7/submissions/42")
+            .match_body("comment%5Btext_comment%5D=Great+start&comment%5Bgroup_comment%5D=true")
+            .with_body(
+                r#"{"id": 100, "user_id": 42, "workflow_state": "submitted",
+                    "submission_comments": [
+                        {"id": 1, "author_id": 7, "author_name": "Pat Teacher",
+                         "comment": "Draft received", "created_at": "2025-10-01T10:00:00Z"},
+                        {"id": 2, "author_id": 7, "author_name": "Pat Teacher",
+                         "comment": "Great start", "created_at": "2025-10-02T10:00:00Z"}
+                    ]}"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .comment_on_submission(CommentOnSubmissionParams {
+                course_id: FlexibleId::from(1),
+                assignment_id: FlexibleId::from(7),
+                user_id: FlexibleId::from(42),
+                comment: "Great start".to_string(),
+                group_comment: Some(true),
+            })
+            .await
+            .unwrap();
+
+        mock.assert_async().await;
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.ends_with("by Pat Teacher: Great start"));
+    }
+
+    #[tokio::test]
+    async fn test_bulk_grade_returns_progress() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock(
+                "POST",
+                "/api/v1/courses/1/assignments/7/submissions/update_grades",
+            )
+            .match_body(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("grade_data[42][posted_grade]".into(), "8".into()),
+                mockito::Matcher::UrlEncoded("grade_data[43][posted_grade]".into(), "10".into()),
+                mockito::Matcher::UrlEncoded(
+                    "grade_data[43][text_comment]".into(),
+                    "Perfect".into(),
+                ),
+            ]))
+            .with_body(
+                r#"{"id": 5, "workflow_state": "queued", "completion": 0,
+                    "url": "https://example.instructure.com/api/v1/progress/5"}"#,
+            )
+            .create_async()
+            .await;
+
+        let config = Arc::new(CanvasConfig::new("token".to_string(), server.url()));
+        let canvas = CanvasServer::new(config).unwrap();
+
+        let result = canvas
+            .bulk_grade(BulkGradeParams {
+                course_id: FlexibleId::from(1),
+                assignment_id: FlexibleId::from(7),
+                grades: BTreeMap::from([
+                    ("42".to_string(), "8".to_string()),
+                    ("43".to_string(), "10".to_string()),
+                ]),
+                comments: Some(BTreeMap::from([("43".to_string(), "Perfect".to_string())])),
+                wait: None,
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains("queued"));
+        assert!(summary.contains("/progress/5"));
+        mock.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn test_list_submissions_anonymizes_students() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock(
+                "GET",
+                "/api/v1/courses/1/assignments/7/submissions?include[]=user&include[]=submission_comments&per_page=100",
+            )
+            .with_body(
+                r#"[{"id": 1, "user_id": 42, "workflow_state": "graded", "score": 9,
+                     "submission_comments": [{"author_id": 42, "author_name": "Jane Doe", "comment": "Thanks"}]},
+                    {"id": 2, "user_id": 43, "workflow_state": "unsubmitted",
+                     "user": {"id": 43, "name": "John Roe", "sortable_name": "Roe, John"}},
+                    {"id": 3, "user_id": 44, "workflow_state": "submitted"}]"#,
+            )
+            .create_async()
+            .await;
+
+        let mut config = CanvasConfig::new("token".to_string(), server.url());
+        config.enable_anonymization = true;
+        config.anonymization_salt = "salt".to_string();
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+
+        let result = canvas
+            .list_submissions(ListSubmissionsParams {
+                course_id: FlexibleId::from(1),
+                assignment_id: FlexibleId::from(7),
+                workflow_state: None,
+                include_comments: Some(true),
+                include_rubric_assessment: None,
+                include: None,
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains("1 graded, 1 submitted but ungraded, 1 not submitted"));
+        assert!(summary.contains(&format!("Not submitted: {}", pseudonym("43", "salt"))));
+        assert!(!summary.contains("John Roe"));
+
+        let json = crate::tools::result_json(&result).to_string();
+        assert!(!json.contains("Jane Doe"));
+        assert!(!json.contains("John Roe"));
+        assert!(json.contains(&pseudonym("42", "salt")));
+    }
+
+    #[tokio::test]
+    async fn test_submit_assignment_checks_allowed_types() {
+        let backend = Arc::new(
+            MockBackend::new()
+                .on(
+                    Method::GET,
+                    "/courses/1/assignments/7",
+                    MockResponse::json(
+                        r#"{"id": 7, "name": "Reflection", "submission_types": ["online_text_entry"]}"#,
+                    ),
+                )
+                .on(
+                    Method::POST,
+                    "/courses/1/assignments/7/submissions",
+                    MockResponse::json(
+                        r#"{"id": 300, "assignment_id": 7, "attempt": 2, "workflow_state": "submitted", "submission_type": "online_text_entry"}"#,
+                    ),
+                ),
+        );
+        let config = Arc::new(CanvasConfig::new(
+            "token".to_string(),
+            "https://canvas.test".to_string(),
+        ));
+        let canvas = CanvasServer::with_backend(config, backend.clone()).unwrap();
+        let params = |submission_type: &str| SubmitAssignmentParams {
+            course_id: FlexibleId::from(1),
+            assignment_id: FlexibleId::from(7),
+            submission_type: submission_type.to_string(),
+            body: Some("<p>I learned a lot.</p>".to_string()),
+            url: Some("https://example.com/essay".to_string()),
+            file_ids: None,
+            comment: None,
+        };
+
+        let result = canvas
+            .submit_assignment(params("online_text_entry"))
+            .await
+            .unwrap();
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains("submission 300, attempt 2"));
+        let requests = backend.requests();
+        assert_eq!(
+            requests[1].body.as_deref(),
+            Some("submission%5Bsubmission_type%5D=online_text_entry&submission%5Bbody%5D=%3Cp%3EI+learned+a+lot.%3C%2Fp%3E")
+        );
+
+        let error = canvas
+            .submit_assignment(params("online_url"))
+            .await
+            .unwrap_err();
+        assert!(error.message.contains("accepts: online_text_entry"));
+        let error = canvas
+            .submit_assignment(params("online_upload"))
+            .await
+            .unwrap_err();
+        assert!(error.message.contains("file_ids is required"));
+        assert_eq!(backend.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_get_submission_keeps_own_name_when_anonymized() {
+        let backend = Arc::new(
+            MockBackend::new()
+                .on(
+                    Method::GET,
+                    "/courses/1/assignments/7/submissions/42",
+                    MockResponse::json(
+                        r#"{"id": 300, "user_id": 42, "workflow_state": "graded", "score": 8.0, "grade": "8",
+                            "attempt": 2, "submitted_at": "2025-10-03T20:00:00Z",
+                            "submission_history": [
+                                {"id": 300, "attempt": 1, "submitted_at": "2025-10-01T20:00:00Z", "score": 6.0},
+                                {"id": 300, "attempt": 2, "submitted_at": "2025-10-03T20:00:00Z", "score": 8.0}],
+                            "rubric_assessment": {"_1": {"points": 4.0, "comments": "Clear thesis"}},
+                            "submission_comments": [
+                                {"id": 1, "author_id": 42, "author_name": "Jane Doe", "comment": "Why an 8?"},
+                                {"id": 2, "author_id": 5, "author_name": "Prof Lee", "comment": "See rubric"}]}"#,
+                    ),
+                )
+                .on(
+                    Method::GET,
+                    "/users/self",
+                    MockResponse::json(r#"{"id": 5, "name": "Prof Lee"}"#),
+                ),
+        );
+        let mut config = CanvasConfig::new("token".to_string(), "https://canvas.test".to_string());
+        config.enable_anonymization = true;
+        config.anonymization_salt = "salt".to_string();
+        let canvas = CanvasServer::with_backend(Arc::new(config), backend).unwrap();
+
+        let result = canvas
+            .get_submission(GetSubmissionParams {
+                course_id: FlexibleId::from(1),
+                assignment_id: FlexibleId::from(7),
+                user_id: FlexibleId::from(42),
+                include_history: None,
+                include_comments: None,
+                include_rubric_assessment: None,
+                cache_bypass: None,
+            })
+            .await
+            .unwrap();
+
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains("graded, score 8, submitted"));
+        assert!(summary.contains("- Attempt 1: submitted"));
+        assert!(summary.contains("- _1: 4 — Clear thesis"));
+        assert!(summary.contains(&format!("- {}", pseudonym("42", "salt"))));
+        assert!(summary.contains("- Prof Lee"));
+        assert!(!summary.contains("Jane Doe"));
+
+        let data = crate::tools::result_json(&result);
+        assert_eq!(data["submission_history"].as_array().unwrap().len(), 2);
+    }
+}
@@ -0,0 +1,193 @@
+use super::tool_result;
+use crate::datetime::to_local;
+use crate::error::CanvasError;
+use crate::models::{EnrollmentTerm, FlexibleId};
+use crate::server::CanvasServer;
+use chrono::{DateTime, Utc};
+use rmcp::model::CallToolResult;
+use rmcp::{schemars, tool, Error as McpError};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct ListEnrollmentTermsParams {
+    /// Fetch the terms again instead of using the list kept for this session
+    pub cache_bypass: Option<bool>,
+}
+
+/// Body of `GET /accounts/:id/terms`, which wraps the list in an object
+#[derive(Debug, Deserialize)]
+struct TermList {
+    enrollment_terms: Vec<EnrollmentTerm>,
+}
+
+/// Find the term called `name`: a case-insensitive exact match, or else the
+/// only term whose name contains it
+pub(crate) fn find_term<'a>(
+    terms: &'a [EnrollmentTerm],
+    name: &str,
+) -> Result<&'a EnrollmentTerm, CanvasError> {
+    let wanted = name.trim().to_lowercase();
+    let term_name = |t: &EnrollmentTerm| t.name.as_deref().unwrap_or_default().to_lowercase();
+
+    if let Some(term) = terms.iter().find(|t| term_name(t) == wanted) {
+        return Ok(term);
+    }
+    let partial: Vec<&EnrollmentTerm> = terms
+        .iter()
+        .filter(|t| !wanted.is_empty() && term_name(t).contains(&wanted))
+        .collect();
+    match partial.as_slice() {
+        [term] => Ok(term),
+        [] => Err(CanvasError::InvalidParameter(format!(
+            "No enrollment term named \"{}\" (available: {})",
+            name.trim(),
+            names(terms.iter())
+        ))),
+        several => Err(CanvasError::InvalidParameter(format!(
+            "\"{}\" matches several terms: {}",
+            name.trim(),
+            names(several.iter().copied())
+        ))),
+    }
+}
+
+fn names<'a>(terms: impl Iterator<Item = &'a EnrollmentTerm>) -> String {
+    let names: Vec<&str> = terms.filter_map(|t| t.name.as_deref()).collect();
+    if names.is_empty() {
+        "none".to_string()
+    } else {
+        names.join(", ")
+    }
+}
+
+/// Date a term starts or ends, e.g. "Aug 25, 2025"
+fn term_date(date: Option<DateTime<Utc>>, tz: &str) -> String {
+    date.map(|d| to_local(d, tz).format("%b %-d, %Y").to_string())
+        .unwrap_or_else(|| "?".to_string())
+}
+
+impl CanvasServer {
+    /// The admin account's enrollment terms, fetched on first use and kept
+    /// for the session unless `refresh` is set
+    pub(crate) async fn enrollment_terms(
+        &self,
+        tool: &str,
+        refresh: bool,
+    ) -> Result<Vec<EnrollmentTerm>, McpError> {
+        let account_id = self.admin_account(tool)?;
+        let mut cached = self.terms.lock().await;
+        if let Some(terms) = cached.as_ref().filter(|_| !refresh) {
+            return Ok(terms.clone());
+        }
+
+        let client = self.reader(Some(refresh));
+        let mut terms = Vec::new();
+        let mut next = Some(format!("/accounts/{}/terms?per_page=100", account_id));
+        let mut pages = 0;
+        while let Some(path) = next {
+            if pages >= client.max_pages() {
+                return Err(McpError::from(CanvasError::internal(format!(
+                    "Enrollment terms exceeded the maximum of {} pages",
+                    pages
+                ))));
+            }
+            let (page, next_url): (TermList, _) = client.get_page(&path).await?;
+            terms.extend(page.enrollment_terms);
+            next = next_url;
+            pages += 1;
+        }
+
+        *cached = Some(terms.clone());
+        Ok(terms)
+    }
+
+    /// Resolve a term name such as "Fall 2025" to its ID
+    pub(crate) async fn resolve_term(
+        &self,
+        tool: &str,
+        name: &str,
+    ) -> Result<FlexibleId, McpError> {
+        let terms = self.enrollment_terms(tool, false).await?;
+        Ok(find_term(&terms, name)?.id.clone())
+    }
+
+    #[tool(
+        description = "Admin only: list the enrollment terms (e.g. \"Fall 2025\") of the configured Canvas account (CANVAS_ACCOUNT_ID)"
+    )]
+    pub(crate) async fn list_enrollment_terms(
+        &self,
+        #[tool(aggr)] params: ListEnrollmentTermsParams,
+    ) -> Result<CallToolResult, McpError> {
+        let terms = self
+            .enrollment_terms(
+                "list_enrollment_terms",
+                params.cache_bypass.unwrap_or(false),
+            )
+            .await?;
+
+        let tz = self.timezone();
+        let mut summary = format!("{} enrollment term(s):", terms.len());
+        for term in &terms {
+            summary.push_str(&format!(
+                "\n- {} (ID {})",
+                term.name.as_deref().unwrap_or("Unnamed term"),
+                term.id
+            ));
+            if term.start_at.is_some() || term.end_at.is_some() {
+                summary.push_str(&format!(
+                    ": {} – {}",
+                    term_date(term.start_at, tz),
+                    term_date(term.end_at, tz)
+                ));
+            }
+        }
+
+        tool_result(summary, &terms)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::backend::{MockBackend, MockResponse};
+    use crate::config::CanvasConfig;
+    use reqwest::Method;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_terms_are_fetched_once_and_resolved_by_name() {
+        let backend = Arc::new(MockBackend::new().on(
+            Method::GET,
+            "/accounts/1/terms",
+            MockResponse::json(
+                r#"{"enrollment_terms": [
+                    {"id": 1, "name": "Default Term"},
+                    {"id": 5, "name": "Fall 2025", "start_at": "2025-08-25T06:00:00Z", "end_at": "2025-12-15T07:00:00Z"},
+                    {"id": 6, "name": "Fall 2025 Late Start"},
+                    {"id": 7, "name": "Spring 2026"}]}"#,
+            ),
+        ));
+        let mut config = CanvasConfig::new("token".to_string(), "https://canvas.test".to_string());
+        config.account_id = Some("1".to_string());
+        let canvas = CanvasServer::with_backend(Arc::new(config), backend.clone()).unwrap();
+
+        let result = canvas
+            .list_enrollment_terms(ListEnrollmentTermsParams { cache_bypass: None })
+            .await
+            .unwrap();
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert!(summary.contains("- Fall 2025 (ID 5): Aug 25, 2025 – Dec 15, 2025"));
+
+        assert_eq!(
+            canvas.resolve_term("test", "fall 2025").await.unwrap(),
+            FlexibleId::from(5)
+        );
+        assert_eq!(
+            canvas.resolve_term("test", "spring").await.unwrap(),
+            FlexibleId::from(7)
+        );
+        let error = canvas.resolve_term("test", "Fall").await.unwrap_err();
+        assert!(error.message.contains("matches several terms"));
+        assert_eq!(backend.requests().len(), 1);
+    }
+}
@@ -0,0 +1,118 @@
+use super::{due_label, tool_result};
+use crate::datetime::format_local;
+use crate::models::TodoItem;
+use crate::server::CanvasServer;
+use rmcp::model::CallToolResult;
+use rmcp::{tool, Error as McpError};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+/// To-do items for one course
+#[derive(Debug, Serialize)]
+struct CourseTodo {
+    course: String,
+    items: Vec<TodoRow>,
+}
+
+#[derive(Debug, Serialize)]
+struct TodoRow {
+    #[serde(flatten)]
+    item: TodoItem,
+    due_local: Option<String>,
+}
+
+impl CanvasServer {
+    #[tool(
+        description = "The current user's to-do list (work to submit, or submissions to grade), grouped by course with due dates in the configured timezone"
+    )]
+    pub(crate) async fn list_todo(&self) -> Result<CallToolResult, McpError> {
+        let items: Vec<TodoItem> = self.client.get_all("/users/self/todo").await?;
+        let total = items.len();
+
+        let tz = self.timezone();
+        let mut by_course: BTreeMap<String, Vec<TodoRow>> = BTreeMap::new();
+        for item in items {
+            let course = item
+                .context_name
+                .clone()
+                .or_else(|| item.course_id.as_ref().map(|id| format!("Course {}", id)))
+                .unwrap_or_else(|| "Other".to_string());
+            let due_local = item.due_at().map(|due| format_local(due, tz));
+            by_course
+                .entry(course)
+                .or_default()
+                .push(TodoRow { item, due_local });
+        }
+        // Soonest first; undated items last
+        for rows in by_course.values_mut() {
+            rows.sort_by_key(|row| (row.item.due_at().is_none(), row.item.due_at()));
+        }
+
+        let mut summary = format!("{} to-do item(s):", total);
+        for (course, rows) in &by_course {
+            summary.push_str(&format!("\n\n{}", course));
+            for row in rows {
+                let action = match row.item.todo_type.as_str() {
+                    "grading" => format!(
+                        "grade {} submission(s)",
+                        row.item.needs_grading_count.unwrap_or(0)
+                    ),
+                    _ => "submit".to_string(),
+                };
+                summary.push_str(&format!(
+                    "\n- {}: {}, {}",
+                    row.item.title(),
+                    action,
+                    due_label(row.item.due_at(), tz)
+                ));
+            }
+        }
+
+        let grouped: Vec<CourseTodo> = by_course
+            .into_iter()
+            .map(|(course, items)| CourseTodo { course, items })
+            .collect();
+        tool_result(summary, &grouped)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_list_todo_groups_by_course() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/users/self/todo?per_page=100")
+            .with_body(
+                r#"[{"type": "submitting", "course_id": 1, "context_name": "Biology",
+                     "assignment": {"id": 7, "name": "Essay 2", "due_at": "2025-10-10T06:59:00Z"}},
+                    {"type": "submitting", "course_id": 1, "context_name": "Biology",
+                     "assignment": {"id": 6, "name": "Essay 1", "due_at": "2025-10-04T06:59:00Z"}},
+                    {"type": "grading", "course_id": 2, "context_name": "Chemistry",
+                     "needs_grading_count": 3,
+                     "assignment": {"id": 9, "name": "Lab 1"}}]"#,
+            )
+            .create_async()
+            .await;
+
+        let mut config = CanvasConfig::new("token".to_string(), server.url());
+        config.timezone = Some("America/Los_Angeles".to_string());
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+
+        let result = canvas.list_todo().await.unwrap();
+        let summary = result.content[0].as_text().unwrap().text.clone();
+        assert_eq!(
+            summary,
+            "3 to-do item(s):\n\n\
+             Biology\n\
+             - Essay 1: submit, due Fri, Oct 3 at 11:59 PM\n\
+             - Essay 2: submit, due Thu, Oct 9 at 11:59 PM\n\n\
+             Chemistry\n\
+             - Lab 1: grade 3 submission(s), no due date"
+        );
+    }
+}
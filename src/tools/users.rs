@@ -0,0 +1,185 @@
+use super::tool_result;
+use crate::client::QueryBuilder;
+use crate::error::CanvasError;
+use crate::models::User;
+use crate::server::CanvasServer;
+use rmcp::model::CallToolResult;
+use rmcp::{schemars, tool, Error as McpError};
+use serde::Deserialize;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct RevealPseudonymParams {
+    /// Pseudonym shown in an anonymized response, e.g. "Student-3f9a1c2e"
+    pub pseudonym: String,
+}
+
+/// Most users `search_account_users` returns at once
+const MAX_SEARCH_RESULTS: u32 = 100;
+
+#[derive(Debug, Deserialize, schemars::JsonSchema)]
+pub struct SearchAccountUsersParams {
+    /// Name, login ID, email, or SIS ID to search for (at least 2 characters)
+    pub search_term: String,
+
+    /// Maximum users to return (default 25, at most 100)
+    pub limit: Option<u32>,
+
+    /// Replace names with pseudonyms (default false; this admin lookup
+    /// ignores ENABLE_DATA_ANONYMIZATION)
+    pub anonymize: Option<bool>,
+
+    /// Skip the response cache and fetch fresh data from Canvas
+    pub cache_bypass: Option<bool>,
+}
+
+impl CanvasServer {
+    #[tool(
+        description = "Admin only: search every user in the configured Canvas account (CANVAS_ACCOUNT_ID) by name, login, email, or SIS ID"
+    )]
+    pub(crate) async fn search_account_users(
+        &self,
+        #[tool(aggr)] params: SearchAccountUsersParams,
+    ) -> Result<CallToolResult, McpError> {
+        let account_id = self.admin_account("search_account_users")?;
+        let term = params.search_term.trim();
+        if term.chars().count() < 2 {
+            return Err(McpError::from(CanvasError::InvalidParameter(
+                "search_term must be at least 2 characters".to_string(),
+            )));
+        }
+        let limit = params.limit.unwrap_or(25).clamp(1, MAX_SEARCH_RESULTS);
+
+        let path = QueryBuilder::new()
+            .param("search_term", term)
+            .param("per_page", limit)
+            .to_path(&format!("/accounts/{}/users", account_id));
+        let (users, next): (Vec<User>, _) = self
+            .reader(params.cache_bypass)
+            .with_anonymization(params.anonymize.unwrap_or(false))
+            .get_page(&path)
+            .await
+            .map_err(|e| match e {
+                CanvasError::Auth(message) => CanvasError::auth(format!(
+                    "{}. Searching account {} requires an account admin token",
+                    message, account_id
+                )),
+                other => other,
+            })?;
+
+        let mut summary = format!("{} user(s) matching \"{}\"", users.len(), term);
+        if next.is_some() {
+            summary.push_str(" (more exist; refine the search or raise limit)");
+        }
+        for user in &users {
+            summary.push_str(&format!("\n- {} (user {})", user.name, user.id));
+            if let Some(login) = &user.login_id {
+                summary.push_str(&format!(", login {}", login));
+            }
+        }
+
+        tool_result(summary, &users)
+    }
+
+    #[tool(
+        description = "Look up the Canvas user ID behind a student pseudonym (only when ANONYMIZATION_REVERSIBLE is enabled)"
+    )]
+    pub(crate) async fn reveal_pseudonym(
+        &self,
+        #[tool(aggr)] params: RevealPseudonymParams,
+    ) -> Result<CallToolResult, McpError> {
+        let anonymizer = self
+            .client
+            .anonymizer()
+            .filter(|a| a.is_reversible())
+            .ok_or_else(|| {
+                McpError::from(CanvasError::config(
+                    "De-anonymization requires ENABLE_DATA_ANONYMIZATION=true and ANONYMIZATION_REVERSIBLE=true",
+                ))
+            })?;
+
+        match anonymizer.reverse(&params.pseudonym) {
+            Some(user_id) => tool_result(
+                format!("{} is Canvas user {}", params.pseudonym.trim(), user_id),
+                &serde_json::json!({"pseudonym": params.pseudonym.trim(), "user_id": user_id}),
+            ),
+            None => Err(McpError::from(CanvasError::not_found(format!(
+                "{} has not appeared in any response this session",
+                params.pseudonym.trim()
+            )))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::anonymize::pseudonym;
+    use crate::config::CanvasConfig;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_reveal_pseudonym_after_anonymized_response() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock("GET", "/api/v1/users/self")
+            .with_body(r#"{"id": 42, "name": "Jane Doe", "sortable_name": "Doe, Jane"}"#)
+            .create_async()
+            .await;
+
+        let mut config = CanvasConfig::new("token".to_string(), server.url());
+        config.enable_anonymization = true;
+        config.anonymization_reversible = true;
+        config.anonymization_salt = "salt".to_string();
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+
+        let user = canvas.client.get_current_user().await.unwrap();
+        let alias = pseudonym("42", "salt");
+        assert_eq!(user.name, alias);
+
+        let result = canvas
+            .reveal_pseudonym(RevealPseudonymParams { pseudonym: alias })
+            .await
+            .unwrap();
+        let text = result.content[0].as_text().unwrap().text.clone();
+        assert!(text.ends_with("Canvas user 42"));
+        assert_eq!(crate::tools::result_json(&result)["user_id"], "42");
+    }
+
+    #[tokio::test]
+    async fn test_search_account_users_ignores_global_anonymization() {
+        let mut server = mockito::Server::new_async().await;
+        server
+            .mock(
+                "GET",
+                "/api/v1/accounts/1/users?search_term=jane&per_page=25",
+            )
+            .with_body(r#"[{"id": 42, "name": "Jane Doe", "login_id": "jdoe"}]"#)
+            .expect(2)
+            .create_async()
+            .await;
+
+        let mut config = CanvasConfig::new("token".to_string(), server.url());
+        config.enable_anonymization = true;
+        config.anonymization_salt = "salt".to_string();
+        config.account_id = Some("1".to_string());
+        let canvas = CanvasServer::new(Arc::new(config)).unwrap();
+        let search = |anonymize| SearchAccountUsersParams {
+            search_term: " jane ".to_string(),
+            limit: None,
+            anonymize,
+            cache_bypass: None,
+        };
+
+        let result = canvas.search_account_users(search(None)).await.unwrap();
+        let text = result.content[0].as_text().unwrap().text.clone();
+        assert!(text.contains("- Jane Doe (user 42), login jdoe"));
+
+        let result = canvas
+            .search_account_users(search(Some(true)))
+            .await
+            .unwrap();
+        let text = result.content[0].as_text().unwrap().text.clone();
+        assert!(text.contains(&pseudonym("42", "salt")));
+        assert!(!text.contains("Jane Doe"));
+    }
+}